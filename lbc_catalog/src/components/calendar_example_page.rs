@@ -1,6 +1,8 @@
 use gloo_console::info;
+use lbc::components::date_picker::Date;
 use lbc::prelude::{
-    Block, Button, Calendar, Content, Control, Field, HeaderSize, Notification, Subtitle, Title,
+    Block, Button, Calendar, Content, Control, DatePicker, DateRange, DateRangePicker, Field,
+    HeaderSize, Notification, Subtitle, Title,
 };
 use leptos::ev::MouseEvent;
 use leptos::prelude::Callback;
@@ -25,12 +27,26 @@ pub fn CalendarPage() -> impl IntoView {
         set_selected_t.set(v);
     });
 
+    // Example 4: typed DatePicker with bounds and disabled weekends
+    let (picked, set_picked) = signal(Date::new(2025, 11, 24));
+    let on_pick = Callback::new(move |d: Option<Date>| set_picked.set(d));
+
+    // Example 5: range mode, as strings (Calendar) and typed (DateRangePicker)
+    let (report_start, set_report_start) = signal("2025-11-01".to_string());
+    let (report_end, set_report_end) = signal("2025-11-14".to_string());
+    let on_report = Callback::new(move |(start, end): (String, String)| {
+        set_report_start.set(start);
+        set_report_end.set(end);
+    });
+    let (range, set_range) = signal(None::<DateRange>);
+    let on_range = Callback::new(move |r: Option<DateRange>| set_range.set(r));
+
     view! {
         <Block>
             <Title size=HeaderSize::Is5>"Calendar"</Title>
 
             <Content>
-                <Subtitle size=HeaderSize::Is6>"bulma‑calendar integration"</Subtitle>
+                <Subtitle size=HeaderSize::Is6>"Native date picker (Calendar compatibility API)"</Subtitle>
 
                 <Field label="Appointment (date + time)">
                     <Control>
@@ -68,7 +84,7 @@ pub fn CalendarPage() -> impl IntoView {
                     "Clear Birthday"
                 </Button>
 
-               <Field label="Time)" classes="mt-4">
+               <Field label="Time" classes="mt-4">
                     <Control>
                         <Calendar
                             id="time".to_string()
@@ -82,8 +98,56 @@ pub fn CalendarPage() -> impl IntoView {
                 </Field>
                 <p class="help">"Selected (time): " {move || selected_t.get()}</p>
 
+                <Subtitle size=HeaderSize::Is6 classes="mt-5">"DatePicker (typed value)"</Subtitle>
+                <Field label="Delivery day (weekdays in November/December 2025)">
+                    <Control>
+                        <DatePicker
+                            value=picked
+                            update=on_pick
+                            min=Date::new(2025, 11, 1)
+                            max=Date::new(2025, 12, 31)
+                            is_disabled=Callback::new(|d: Date| d.weekday() >= 5)
+                            format="dd.MM.yyyy"
+                        />
+                    </Control>
+                </Field>
+                <p class="help">
+                    "Selected (Date): "
+                    {move || picked.get().map(|d| d.to_string()).unwrap_or_else(|| "(none)".to_string())}
+                </p>
+
+                <Subtitle size=HeaderSize::Is6 classes="mt-5">"Date ranges"</Subtitle>
+                <Field label="Report period (Calendar range mode)">
+                    <Control>
+                        <Calendar
+                            id="report-period".to_string()
+                            date=report_start
+                            date_end=report_end
+                            update=Callback::new(|_: String| {})
+                            update_range=on_report
+                        />
+                    </Control>
+                </Field>
+                <p class="help">
+                    "Selected (start, end): " {move || format!("({}, {})", report_start.get(), report_end.get())}
+                </p>
+                <Field label="Typed range with presets" classes="mt-4">
+                    <Control>
+                        <DateRangePicker value=range update=on_range />
+                    </Control>
+                </Field>
+                <p class="help">
+                    "Selected (DateRange): "
+                    {move || {
+                        range
+                            .get()
+                            .map(|r| format!("{} ({} days)", r, r.days()))
+                            .unwrap_or_else(|| "(none)".to_string())
+                    }}
+                </p>
+
                 <Notification classes="is-light mt-3">
-                    "Note: bulma-calendar JS and CSS must be loaded. We've added them to index.html for the catalog."
+                    "The picker is pure Rust/Leptos: no JS plugin or CDN asset is required."
                     <br/>
                     "To clear the calendar programmatically, set the date signal to a single space \" \"."
                 </Notification>
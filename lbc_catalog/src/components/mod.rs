@@ -10,4 +10,5 @@ pub mod modal_example_page;
 pub mod navbar_example_page;
 pub mod pagination_example_page;
 pub mod panel_example_page;
+pub mod steps_example_page;
 pub mod tabs_example_page;
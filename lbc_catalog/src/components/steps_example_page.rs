@@ -0,0 +1,127 @@
+use std::collections::BTreeSet;
+
+use lbc::components::steps::Step;
+use lbc::form::validation::{email, min_length, required};
+use lbc::prelude::{
+    Checkbox, Content, Control, Field, FormValues, HeaderSize, Input, InputType, Select,
+    SelectOption, Size, Steps, Subtitle, Title, Wizard, WizardStep, use_form,
+};
+use leptos::callback::Callback;
+use leptos::prelude::*;
+
+/// Example page showcasing the Steps indicator and an onboarding Wizard.
+#[component]
+pub fn StepsPage() -> impl IntoView {
+    let indicator = vec![
+        Step::new("Account").with_description("Email and password"),
+        Step::new("Profile"),
+        Step::new("Newsletter").with_optional(true),
+        Step::new("Done"),
+    ];
+
+    let form = use_form();
+    let (email_value, set_email) = form.bind("email");
+    let (password, set_password) = form.bind("password");
+    let (name, set_name) = form.bind("name");
+    let (team, set_team) = form.bind("team");
+    let (weekly, set_weekly) = form.bind_bool("weekly");
+    let (finished, set_finished) = signal(None::<FormValues>);
+
+    let teams = vec![
+        SelectOption::new("design".to_string(), "Design"),
+        SelectOption::new("engineering".to_string(), "Engineering"),
+        SelectOption::new("sales".to_string(), "Sales"),
+    ];
+
+    let steps = vec![
+        WizardStep::new("Account", move || {
+            view! {
+                <Field label="Email" name="email" validators=vec![required(), email()]>
+                    <Control>
+                        <Input
+                            name="email"
+                            value=email_value
+                            update=set_email
+                            r#type=InputType::Email
+                        />
+                    </Control>
+                </Field>
+                <Field label="Password" name="password" validators=vec![required(), min_length(8)]>
+                    <Control>
+                        <Input
+                            name="password"
+                            value=password
+                            update=set_password
+                            r#type=InputType::Password
+                        />
+                    </Control>
+                </Field>
+            }
+        })
+        .with_description("Sign-in details")
+        .with_fields(["email", "password"]),
+        WizardStep::new("Profile", move || {
+            let teams = teams.clone();
+            view! {
+                <Field label="Display name" name="name" validators=vec![required()]>
+                    <Control>
+                        <Input name="name" value=name update=set_name />
+                    </Control>
+                </Field>
+                <Field label="Team" name="team">
+                    <Control>
+                        <Select name="team" value=team update=set_team options=teams />
+                    </Control>
+                </Field>
+            }
+        })
+        .with_fields(["name"]),
+        WizardStep::new("Newsletter", move || {
+            view! {
+                <Field>
+                    <Control>
+                        <Checkbox name="weekly" checked=weekly update=set_weekly>
+                            " Send me the weekly digest"
+                        </Checkbox>
+                    </Control>
+                </Field>
+            }
+        })
+        .with_optional(true),
+        WizardStep::new("Done", move || {
+            view! {
+                <Content>
+                    <p>"Welcome, " {move || name.get()} "! Press Finish to create the account."</p>
+                </Content>
+            }
+        }),
+    ];
+
+    view! {
+        <div class="block">
+            <Title size=HeaderSize::Is5>"Steps"</Title>
+
+            <Content>
+                <Subtitle size=HeaderSize::Is6>"Indicator"</Subtitle>
+            </Content>
+            <Steps steps=indicator current=2 completed=BTreeSet::from([0, 1]) size=Size::Small />
+
+            <Content classes="mt-5">
+                <Subtitle size=HeaderSize::Is6>"Onboarding wizard"</Subtitle>
+                <p>
+                    "Next validates the fields of the current step, Newsletter can be skipped and "
+                    "completed steps can be revisited from the indicator."
+                </p>
+            </Content>
+            <Wizard
+                steps=steps
+                form=form
+                on_finish=Callback::new(move |values| set_finished.set(Some(values)))
+            />
+
+            <Content classes="mt-4">
+                <p>"Finished with: " <code>{move || format!("{:?}", finished.get())}</code></p>
+            </Content>
+        </div>
+    }
+}
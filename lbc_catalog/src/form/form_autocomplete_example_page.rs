@@ -1,8 +1,10 @@
 use lbc::prelude::{AutoComplete, Block, Content, HeaderSize, Subtitle, Title};
 use leptos::callback::Callback;
-use leptos::prelude::{ClassAttribute, ElementChild, Get, IntoView, Update, component, signal, view};
+use leptos::prelude::{
+    ClassAttribute, ElementChild, Get, IntoView, Update, component, signal, view,
+};
 
-/// Example page showcasing the AutoComplete component powered by Bulma TagsInput.
+/// Example page showcasing the native AutoComplete tags typeahead.
 #[component]
 pub fn FormAutoCompletePage() -> impl IntoView {
     // Track selected tags from callbacks.
@@ -34,16 +36,16 @@ pub fn FormAutoCompletePage() -> impl IntoView {
 
     view! {
         <Block>
-            <Title size=HeaderSize::Is5>"Form: AutoComplete (Bulma TagsInput)"</Title>
+            <Title size=HeaderSize::Is5>"Form: AutoComplete"</Title>
 
             <Content>
-                <Subtitle size=HeaderSize::Is6>"Static list (select + tags)"</Subtitle>
+                <Subtitle size=HeaderSize::Is6>"Static list (type to filter, Enter to add)"</Subtitle>
                 <AutoComplete
                     id="tags-static".to_string()
                     items=items.clone()
                     placeholder="Choose Tags"
-                    _on_update=on_add
-                    _on_remove=on_remove
+                    on_update=on_add
+                    on_remove=on_remove
                 />
 
                 <p class="help mt-3">
@@ -0,0 +1,107 @@
+use lbc::prelude::{Block, Combobox, Content, Field, HeaderSize, SelectOption, Subtitle, Title};
+use leptos::callback::Callback;
+use leptos::prelude::{ClassAttribute, ElementChild, Get, IntoView, Set, component, signal, view};
+
+/// Builds a long option list (countries grouped by region) to show filtering and scrolling.
+fn countries() -> Vec<SelectOption<String>> {
+    let regions: [(&str, &[&str]); 3] = [
+        (
+            "Europe",
+            &[
+                "Austria",
+                "Belgium",
+                "Denmark",
+                "Finland",
+                "France",
+                "Germany",
+                "Ireland",
+                "Italy",
+                "Netherlands",
+                "Norway",
+                "Poland",
+                "Portugal",
+                "Spain",
+                "Sweden",
+                "Switzerland",
+            ],
+        ),
+        (
+            "Americas",
+            &[
+                "Argentina",
+                "Brazil",
+                "Canada",
+                "Chile",
+                "Colombia",
+                "Mexico",
+                "Peru",
+                "United States",
+            ],
+        ),
+        (
+            "Asia-Pacific",
+            &[
+                "Australia",
+                "India",
+                "Indonesia",
+                "Japan",
+                "New Zealand",
+                "Singapore",
+                "South Korea",
+                "Vietnam",
+            ],
+        ),
+    ];
+    regions
+        .into_iter()
+        .flat_map(|(region, names)| {
+            SelectOption::group(region, names.iter().map(|name| (name.to_string(), *name)))
+        })
+        .collect()
+}
+
+/// Example page showcasing the searchable Combobox in single and multiple mode.
+#[component]
+pub fn FormComboboxPage() -> impl IntoView {
+    let (country, set_country) = signal(vec!["Germany".to_string()]);
+    let (visited, set_visited) = signal(Vec::<String>::new());
+
+    view! {
+        <Block>
+            <Title size=HeaderSize::Is5>"Form: Combobox"</Title>
+
+            <Content>
+                <Subtitle size=HeaderSize::Is6>"Single value"</Subtitle>
+                <Field label="Country" help="Type to filter; arrows, Home/End, Enter and Escape work">
+                    <Combobox
+                        id="country"
+                        options=countries()
+                        value=country
+                        update=Callback::new(move |v: Vec<String>| set_country.set(v))
+                        placeholder="Search countries"
+                    />
+                </Field>
+                <p class="help">"Selected: " {move || country.get().join(", ")}</p>
+
+                <Subtitle size=HeaderSize::Is6 classes="mt-5">"Multiple values"</Subtitle>
+                <Field label="Visited countries">
+                    <Combobox
+                        id="visited"
+                        options=countries()
+                        value=visited
+                        update=Callback::new(move |v: Vec<String>| set_visited.set(v))
+                        multiple=true
+                        placeholder="Add countries"
+                    />
+                </Field>
+                <p class="help">
+                    "Selected: "
+                    {move || {
+                        let list = visited.get();
+                        if list.is_empty() { "(none)".to_string() } else { list.join(", ") }
+                    }}
+                </p>
+            </Content>
+        </Block>
+    }
+}
@@ -0,0 +1,66 @@
+use lbc::prelude::{Block, Content, HeaderSize, LbcForm, Subtitle, Title};
+use leptos::callback::Callback;
+use leptos::prelude::*;
+
+/// A struct edited by the form generated with `#[derive(LbcForm)]`.
+#[derive(Clone, Debug, LbcForm)]
+#[lbc(component = "ContactEditor", submit = "Save contact")]
+struct Contact {
+    #[lbc(label = "Full name", required, min_length = 2)]
+    name: String,
+    #[lbc(widget = "email", placeholder = "name@example.com", required, email)]
+    email: String,
+    #[lbc(help = "Optional", min = 0, max = 150)]
+    age: Option<u32>,
+    #[lbc(options("Customer", "Partner", "Supplier"))]
+    kind: String,
+    #[lbc(widget = "textarea", max_length = 500)]
+    notes: String,
+    #[lbc(label = "Subscribe to the newsletter")]
+    newsletter: bool,
+    #[lbc(skip)]
+    id: u64,
+}
+
+/// Example page showcasing a form generated from a struct.
+#[component]
+pub fn FormDerivePage() -> impl IntoView {
+    let (saved, set_saved) = signal(None::<Contact>);
+    let (draft, set_draft) = signal(None::<Contact>);
+
+    let initial = Contact {
+        name: "Ada Lovelace".to_string(),
+        email: "ada@example.com".to_string(),
+        age: None,
+        kind: "Partner".to_string(),
+        notes: String::new(),
+        newsletter: true,
+        id: 42,
+    };
+
+    view! {
+        <Block>
+            <Title size=HeaderSize::Is5>"Form: #[derive(LbcForm)]"</Title>
+
+            <Content>
+                <Subtitle size=HeaderSize::Is6>"Generated ContactEditor"</Subtitle>
+                <p>
+                    "Fields, labels, widgets and validators come from the struct's "
+                    <code>"#[lbc(...)]"</code>
+                    " attributes. The edited struct is emitted on submit and on every valid change."
+                </p>
+            </Content>
+
+            <ContactEditor
+                initial=initial
+                on_submit=Callback::new(move |contact| set_saved.set(Some(contact)))
+                on_change=Callback::new(move |contact| set_draft.set(Some(contact)))
+            />
+
+            <Content classes="mt-4">
+                <p>"Draft: " <code>{move || format!("{:?}", draft.get())}</code></p>
+                <p>"Saved: " <code>{move || format!("{:?}", saved.get())}</code></p>
+            </Content>
+        </Block>
+    }
+}
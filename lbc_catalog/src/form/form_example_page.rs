@@ -1,11 +1,18 @@
-use lbc::prelude::{Block, Checkbox, Content, HeaderSize, Subtitle, Title};
+use lbc::prelude::{
+    Block, Checkbox, CheckboxGroup, Content, GroupLayout, HeaderSize, SelectOption, Subtitle, Title,
+};
 use leptos::callback::Callback;
 use leptos::prelude::*;
 
-/// Example page showcasing the Checkbox form component.
+/// Example page showcasing the Checkbox and CheckboxGroup form components.
 #[allow(non_snake_case)]
 pub fn FormCheckboxPage() -> impl IntoView {
     let (is_checked, set_is_checked) = signal(false);
+    let (permissions, set_permissions) = signal(vec!["read".to_string()]);
+    let permission_options = SelectOption::from_pairs(
+        [("read", "Read"), ("write", "Write"), ("delete", "Delete")]
+            .map(|(value, label)| (value.to_string(), label)),
+    );
 
     view! {
         <Block>
@@ -33,6 +40,31 @@ pub fn FormCheckboxPage() -> impl IntoView {
                 <Checkbox name="disabled_demo" checked=true disabled=true>
                     {"Cannot change this option"}
                 </Checkbox>
+
+                <div class="mt-4"></div>
+
+                <Subtitle size=HeaderSize::Is6>"Checkbox group with select all"</Subtitle>
+                <CheckboxGroup
+                    name="permissions"
+                    options=permission_options.clone()
+                    value=permissions
+                    update=Callback::new(move |next| set_permissions.set(next))
+                    select_all="All permissions"
+                />
+                <p class="mt-3">
+                    <strong>"Chosen: "</strong>
+                    {move || permissions.get().join(", ")}
+                </p>
+
+                <Subtitle size=HeaderSize::Is6>"Stacked and disabled"</Subtitle>
+                <CheckboxGroup
+                    name="permissions_readonly"
+                    options=permission_options
+                    value=permissions
+                    update=Callback::new(move |next| set_permissions.set(next))
+                    layout=GroupLayout::Stacked
+                    disabled=true
+                />
             </Content>
         </Block>
     }
@@ -1,15 +1,17 @@
-use lbc::prelude::{Block, Content, Control, Field, File, HeaderSize, Size, Subtitle, Title};
+use lbc::prelude::{
+    Block, Content, Control, Field, File, FileRejection, FileRejectionReason, HeaderSize, LbcFile,
+    Size, Subtitle, Title,
+};
 use leptos::callback::Callback;
-use leptos::prelude::{IntoView, Set, component, signal, view};
+use leptos::prelude::{ClassAttribute, ElementChild, Get, IntoView, Set, component, signal, view};
 
 #[component]
 pub fn FormFilePage() -> impl IntoView {
-    // In this catalog example we don't inspect real files; the File component
-    // uses a platform-neutral placeholder type internally, so Vec<()> is fine.
-    let (selected_files, set_selected_files) = signal(Vec::<()>::new());
+    // The selected files carry name, size and MIME type (plus the browser handle on wasm32).
+    let (selected_files, set_selected_files) = signal(Vec::<LbcFile>::new());
 
     // Controlled component: update selected files on change.
-    let on_update: Callback<Vec<()>> = Callback::new(move |files: Vec<()>| {
+    let on_update: Callback<Vec<LbcFile>> = Callback::new(move |files: Vec<LbcFile>| {
         set_selected_files.set(files);
     });
 
@@ -17,6 +19,31 @@ pub fn FormFilePage() -> impl IntoView {
     let on_update_1 = on_update.clone();
     let on_update_2 = on_update.clone();
 
+    // Drop zone example: accepted images get thumbnails, rejections are listed below.
+    let (dropped_files, set_dropped_files) = signal(Vec::<LbcFile>::new());
+    let (rejections, set_rejections) = signal(Vec::<String>::new());
+    let on_drop_update = Callback::new(move |files: Vec<LbcFile>| {
+        set_dropped_files.set(files);
+    });
+    let on_reject = Callback::new(move |rejected: Vec<FileRejection>| {
+        let messages = rejected
+            .into_iter()
+            .map(|rejection| {
+                let reason = match rejection.reason {
+                    FileRejectionReason::InvalidType => "not an image".to_string(),
+                    FileRejectionReason::TooLarge { max_size } => {
+                        format!("larger than {} bytes", max_size)
+                    }
+                    FileRejectionReason::TooMany { max_files } => {
+                        format!("more than {} files", max_files)
+                    }
+                };
+                format!("{}: {}", rejection.file.name(), reason)
+            })
+            .collect();
+        set_rejections.set(messages);
+    });
+
     view! {
         <Block>
             <Title size=HeaderSize::Is5>"Form: File"</Title>
@@ -27,8 +54,8 @@ pub fn FormFilePage() -> impl IntoView {
                     <Control>
                         <File
                             name="upload"
-                            _files=selected_files
-                            _update=on_update_1
+                            files=selected_files
+                            update=on_update_1
                             selector_label="Choose a file..."
                             has_name="No file selected"
                         />
@@ -42,8 +69,8 @@ pub fn FormFilePage() -> impl IntoView {
                     <Control>
                         <File
                             name="upload2"
-                            _files=selected_files
-                            _update=on_update_2
+                            files=selected_files
+                            update=on_update_2
                             boxed=true
                             fullwidth=true
                             right=true
@@ -55,6 +82,37 @@ pub fn FormFilePage() -> impl IntoView {
                     </Control>
                 </Field>
             </Content>
+
+            <Content>
+                <Subtitle size=HeaderSize::Is6>"Drop zone with previews and validation"</Subtitle>
+                <Field help="Up to 3 images, 2 MB each">
+                    <Control>
+                        <File
+                            name="upload3"
+                            files=dropped_files
+                            update=on_drop_update
+                            boxed=true
+                            multiple=true
+                            drop_zone=true
+                            previews=true
+                            accept="image/*"
+                            max_size=2_097_152
+                            max_files=3
+                            on_reject=on_reject
+                            selector_label="Drop images here..."
+                        />
+                    </Control>
+                </Field>
+                <ul>
+                    {move || {
+                        rejections
+                            .get()
+                            .into_iter()
+                            .map(|message| view! { <li class="has-text-danger">{message}</li> })
+                            .collect::<Vec<_>>()
+                    }}
+                </ul>
+            </Content>
         </Block>
     }
 }
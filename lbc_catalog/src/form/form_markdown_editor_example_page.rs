@@ -0,0 +1,54 @@
+use lbc::prelude::{Block, Content, Field, HeaderSize, MarkdownEditor, Subtitle, Title};
+use leptos::callback::Callback;
+use leptos::prelude::*;
+
+const SAMPLE: &str = "## Release notes\n\n\
+    We shipped **three** improvements:\n\n\
+    - Faster search\n\
+    - A new _markdown_ editor\n\
+    - Fixed `Tab` handling\n\n\
+    Read the [changelog](https://example.com/changelog).\n\n\
+    <script>alert('raw HTML is shown as text')</script>\n";
+
+/// Example page showcasing the MarkdownEditor form component.
+#[component]
+pub fn FormMarkdownEditorPage() -> impl IntoView {
+    let (notes, set_notes) = signal(SAMPLE.to_string());
+    let (draft, set_draft) = signal(String::new());
+
+    view! {
+        <Block>
+            <Title size=HeaderSize::Is5>"Form: MarkdownEditor"</Title>
+
+            <Content>
+                <Subtitle size=HeaderSize::Is6>"Write and preview"</Subtitle>
+                <p>
+                    "Select text and use the toolbar or Ctrl/Cmd+B, I, K, E and Shift+8. \
+                     The preview is sanitized: raw HTML is escaped and unsafe links are dropped."
+                </p>
+            </Content>
+            <Field label="Release notes">
+                <MarkdownEditor
+                    name="notes"
+                    value=notes
+                    update=Callback::new(move |next| set_notes.set(next))
+                />
+            </Field>
+            <p class="help">
+                {move || format!("{} characters of markdown", notes.get().chars().count())}
+            </p>
+
+            <Content>
+                <Subtitle size=HeaderSize::Is6>"Empty, with placeholder"</Subtitle>
+            </Content>
+            <MarkdownEditor
+                name="draft"
+                value=draft
+                rows=4
+                placeholder="Write a comment…"
+                empty_preview="Write something first"
+                update=Callback::new(move |next| set_draft.set(next))
+            />
+        </Block>
+    }
+}
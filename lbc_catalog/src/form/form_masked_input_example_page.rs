@@ -0,0 +1,63 @@
+use lbc::prelude::{
+    Block, Content, Control, Field, HeaderSize, Mask, MaskedInput, MaskedValue, Subtitle, Title,
+};
+use leptos::callback::Callback;
+use leptos::prelude::*;
+
+/// One masked field with a readout of its masked and unmasked value.
+#[component]
+fn MaskedExample(
+    label: &'static str,
+    name: &'static str,
+    mask: &'static str,
+    #[prop(optional)] autocomplete: Option<&'static str>,
+) -> impl IntoView {
+    let (value, set_value) = signal(MaskedValue::default());
+
+    view! {
+        <Field label=label help=format!("Mask: {}", mask)>
+            <Control>
+                <MaskedInput
+                    name=name
+                    value=Signal::derive(move || value.get().masked)
+                    update=Callback::new(move |next| set_value.set(next))
+                    mask=mask
+                    autocomplete=autocomplete.unwrap_or("off")
+                />
+            </Control>
+        </Field>
+        <p class="help mb-4">
+            {move || {
+                let value = value.get();
+                format!(
+                    "masked: '{}' | unmasked: '{}' | complete: {}",
+                    value.masked,
+                    value.unmasked,
+                    value.complete,
+                )
+            }}
+        </p>
+    }
+}
+
+/// Example page showcasing the MaskedInput form component.
+#[component]
+pub fn FormMaskedInputPage() -> impl IntoView {
+    view! {
+        <Block>
+            <Title size=HeaderSize::Is5>"Form: MaskedInput"</Title>
+
+            <Content>
+                <Subtitle size=HeaderSize::Is6>"Built-in masks"</Subtitle>
+                <MaskedExample label="Phone" name="phone" mask=Mask::PHONE_US autocomplete="tel" />
+                <MaskedExample label="Card number" name="card" mask=Mask::CARD autocomplete="cc-number" />
+                <MaskedExample label="Expiry" name="expiry" mask=Mask::CARD_EXPIRY autocomplete="cc-exp" />
+                <MaskedExample label="Date" name="date" mask=Mask::DATE_ISO />
+                <MaskedExample label="Time" name="time" mask=Mask::TIME />
+
+                <Subtitle size=HeaderSize::Is6>"Custom pattern"</Subtitle>
+                <MaskedExample label="License plate" name="plate" mask="aaa-9999" />
+            </Content>
+        </Block>
+    }
+}
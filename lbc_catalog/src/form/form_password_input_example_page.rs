@@ -0,0 +1,68 @@
+use lbc::prelude::{
+    Block, Content, Field, HeaderSize, PasswordInput, PasswordStrength, StrengthLevel, Subtitle,
+    Title,
+};
+use leptos::callback::Callback;
+use leptos::prelude::*;
+
+/// Requires twelve characters; anything shorter is weak.
+fn length_policy(password: &str) -> PasswordStrength {
+    let length = password.chars().count();
+    if length >= 12 {
+        PasswordStrength::new(StrengthLevel::Strong, 1.0)
+    } else if length >= 8 {
+        PasswordStrength::new(StrengthLevel::Fair, length as f32 / 12.0)
+            .with_feedback("Almost there.")
+    } else {
+        PasswordStrength::new(StrengthLevel::Weak, length as f32 / 12.0)
+            .with_feedback("Use at least 12 characters.")
+    }
+}
+
+/// Example page showcasing the PasswordInput form component.
+#[component]
+pub fn FormPasswordInputPage() -> impl IntoView {
+    let (signup, set_signup) = signal(String::new());
+    let (login, set_login) = signal(String::new());
+    let (policy, set_policy) = signal(String::new());
+
+    view! {
+        <Block>
+            <Title size=HeaderSize::Is5>"Form: PasswordInput"</Title>
+
+            <Content>
+                <Subtitle size=HeaderSize::Is6>"Signup (entropy estimator)"</Subtitle>
+                <Field label="Choose a password" name="signup_password">
+                    <PasswordInput
+                        name="signup_password"
+                        value=signup
+                        update=Callback::new(move |next| set_signup.set(next))
+                        autocomplete="new-password"
+                        test_attr="password-signup"
+                    />
+                </Field>
+
+                <Subtitle size=HeaderSize::Is6>"Login (no meter)"</Subtitle>
+                <Field label="Password" name="login_password">
+                    <PasswordInput
+                        name="login_password"
+                        value=login
+                        update=Callback::new(move |next| set_login.set(next))
+                        autocomplete="current-password"
+                        show_strength=false
+                    />
+                </Field>
+
+                <Subtitle size=HeaderSize::Is6>"Custom estimator"</Subtitle>
+                <Field label="Passphrase" name="policy_password">
+                    <PasswordInput
+                        name="policy_password"
+                        value=policy
+                        update=Callback::new(move |next| set_policy.set(next))
+                        estimator=length_policy
+                    />
+                </Field>
+            </Content>
+        </Block>
+    }
+}
@@ -1,14 +1,34 @@
-use lbc::prelude::{Block, Content, Control, Field, HeaderSize, Radio, Subtitle, Title};
+use lbc::prelude::{
+    Block, Content, Control, Field, GroupLayout, HeaderSize, Radio, RadioGroup, SelectOption,
+    Subtitle, Title,
+};
 use leptos::callback::Callback;
 use leptos::prelude::{
     ClassAttribute, ElementChild, Get, GetUntracked, IntoView, Set, component, signal, view,
 };
 
-/// Example page showcasing the Radio form component.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Plan {
+    Free,
+    Pro,
+    Enterprise,
+}
+
+/// Builds the plan options; each `RadioGroup` takes its own copy.
+fn plan_options() -> Vec<SelectOption<Plan>> {
+    let mut plans = SelectOption::from_pairs([(Plan::Free, "Free"), (Plan::Pro, "Pro")]);
+    plans.push(
+        SelectOption::new(Plan::Enterprise, "Enterprise (contact sales)").with_disabled(true),
+    );
+    plans
+}
+
+/// Example page showcasing the Radio and RadioGroup form components.
 #[component]
 #[allow(non_snake_case)]
 pub fn FormRadioPage() -> impl IntoView {
     let (selected, set_selected) = signal(String::from("b"));
+    let (plan, set_plan) = signal(Plan::Free);
 
     let update = Callback::new(move |v: String| set_selected.set(v));
     // Clone update for each radio to avoid moving it into multiple closures
@@ -78,6 +98,28 @@ pub fn FormRadioPage() -> impl IntoView {
                         </Radio>
                     </Control>
                 </Field>
+
+                <Subtitle size=HeaderSize::Is6>"Typed RadioGroup"</Subtitle>
+                <Field label="Plan" help="Use the arrow keys to move between enabled options">
+                    <RadioGroup
+                        name="plan"
+                        label="Plan"
+                        options=plan_options()
+                        value=plan
+                        update=Callback::new(move |next| set_plan.set(next))
+                    />
+                </Field>
+                <p class="help">"Selected: " {move || format!("{:?}", plan.get())}</p>
+
+                <Subtitle size=HeaderSize::Is6>"Vertical RadioGroup"</Subtitle>
+                <RadioGroup
+                    name="plan-vertical"
+                    label="Plan (vertical)"
+                    options=plan_options()
+                    value=plan
+                    update=Callback::new(move |next| set_plan.set(next))
+                    layout=GroupLayout::Stacked
+                />
             </Content>
         </Block>
     }
@@ -0,0 +1,78 @@
+use lbc::prelude::{Block, Content, HeaderSize, SchemaForm, Subtitle, Title};
+use leptos::callback::Callback;
+use leptos::prelude::*;
+use serde_json::Value;
+
+/// A schema as a backend would send it.
+const SCHEMA: &str = r#"{
+    "type": "object",
+    "required": ["name", "email", "plan"],
+    "properties": {
+        "name": { "type": "string", "title": "Company name", "minLength": 2 },
+        "email": { "type": "string", "format": "email", "title": "Billing email" },
+        "plan": { "enum": ["free", "team", "enterprise"], "default": "team" },
+        "seats": { "type": "integer", "minimum": 1, "maximum": 500, "default": 5 },
+        "invoices_by_mail": { "type": "boolean", "title": "Send invoices by mail" },
+        "address": {
+            "type": "object",
+            "description": "Printed on invoices",
+            "required": ["city"],
+            "properties": {
+                "street": { "type": "string" },
+                "city": { "type": "string" },
+                "zip": { "type": "string", "title": "ZIP", "pattern": "^[0-9]{5}$" }
+            }
+        },
+        "contacts": {
+            "type": "array",
+            "title": "Technical contacts",
+            "maxItems": 3,
+            "items": {
+                "type": "object",
+                "title": "Contact",
+                "required": ["name"],
+                "properties": {
+                    "name": { "type": "string" },
+                    "phone": { "type": "string", "title": "Phone" }
+                }
+            }
+        }
+    }
+}"#;
+
+/// Example page showcasing a form rendered from a JSON Schema.
+#[component]
+pub fn FormSchemaPage() -> impl IntoView {
+    let (submitted, set_submitted) = signal(None::<Value>);
+    let (draft, set_draft) = signal(None::<Value>);
+    let schema: Value = serde_json::from_str(SCHEMA).expect("valid schema");
+
+    view! {
+        <Block>
+            <Title size=HeaderSize::Is5>"Form: SchemaForm"</Title>
+
+            <Content>
+                <Subtitle size=HeaderSize::Is6>"Rendered from a JSON Schema"</Subtitle>
+                <p>
+                    "Controls, labels and validators come from the schema; nested objects become "
+                    "horizontal sections and arrays repeatable groups. The value is emitted as JSON."
+                </p>
+            </Content>
+
+            <SchemaForm
+                schema=schema
+                submit_label="Create account"
+                on_submit=Callback::new(move |value| set_submitted.set(Some(value)))
+                on_change=Callback::new(move |value| set_draft.set(Some(value)))
+            />
+
+            <Content classes="mt-4">
+                <p>"Draft: " <code>{move || draft.get().map(|value| value.to_string())}</code></p>
+                <p>
+                    "Submitted: "
+                    <code>{move || submitted.get().map(|value| value.to_string())}</code>
+                </p>
+            </Content>
+        </Block>
+    }
+}
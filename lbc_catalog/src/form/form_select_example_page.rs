@@ -1,11 +1,19 @@
 use lbc::prelude::{
-    Block, Content, Control, Field, HeaderSize, MultiSelect, Select, Subtitle, Title,
+    Block, Content, Control, Field, HeaderSize, MultiSelect, Select, SelectOption, Subtitle, Title,
 };
 use leptos::callback::Callback;
 use leptos::prelude::{
     ClassAttribute, ElementChild, Get, GetUntracked, IntoView, Set, component, signal, view,
 };
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Fruit {
+    Apple,
+    Pear,
+    Carrot,
+    Potato,
+}
+
 /// Example page showcasing the Select and MultiSelect form components.
 #[component]
 pub fn FormSelectPage() -> impl IntoView {
@@ -13,13 +21,26 @@ pub fn FormSelectPage() -> impl IntoView {
     let (selected, set_selected) = signal(String::from("b"));
     let update_select = Callback::new(move |v: String| set_selected.set(v));
 
+    // Typed select with optgroups and a placeholder
+    let (fruit, set_fruit) = signal(None::<Fruit>);
+    let mut produce = SelectOption::group(
+        "Fruit",
+        [(Some(Fruit::Apple), "Apple"), (Some(Fruit::Pear), "Pear")],
+    );
+    produce.extend(SelectOption::group(
+        "Vegetables",
+        [
+            (Some(Fruit::Carrot), "Carrot"),
+            (Some(Fruit::Potato), "Potato"),
+        ],
+    ));
+
     // Multi-select values
     let (selected_list, set_selected_list) = signal(vec!["a".to_string(), "c".to_string()]);
     let update_multi = Callback::new(move |v: Vec<String>| set_selected_list.set(v));
 
     // Snapshot values for passing into props (avoid reading signals in non-tracking context).
     let selected_value = selected.get_untracked();
-    let selected_list_value = selected_list.get_untracked();
 
     view! {
         <Block>
@@ -29,31 +50,55 @@ pub fn FormSelectPage() -> impl IntoView {
                 <Subtitle size=HeaderSize::Is6>"Basic Select"</Subtitle>
                 <Field label="Favorite letter" help="Choose one option">
                     <Control>
-                        <Select name="letters" value=selected_value update=update_select.clone()>
-                            <option value="a">"A"</option>
-                            <option value="b">"B"</option>
-                            <option value="c">"C"</option>
-                        </Select>
+                        <Select
+                            name="letters"
+                            value=selected_value
+                            update=update_select.clone()
+                            options=SelectOption::from_pairs([
+                                ("a".to_string(), "A"),
+                                ("b".to_string(), "B"),
+                                ("c".to_string(), "C"),
+                            ])
+                        />
                     </Control>
                 </Field>
                 <p class="help">"Selected: " {move || selected.get()}</p>
 
+                <Subtitle size=HeaderSize::Is6>"Typed Select with optgroups"</Subtitle>
+                <Field label="Produce" help="Emits Option<Fruit>, not a string">
+                    <Control>
+                        <Select
+                            name="produce"
+                            value=fruit
+                            update=Callback::new(move |v: Option<Fruit>| set_fruit.set(v))
+                            options=produce
+                            placeholder="Choose produce"
+                        />
+                    </Control>
+                </Field>
+                <p class="help">"Selected: " {move || format!("{:?}", fruit.get())}</p>
+
                 <Subtitle size=HeaderSize::Is6>"Multi Select"</Subtitle>
-                <Field label="Pick several" help="You can select multiple options">
+                <Field label="Pick several" help="Chosen values show as tags; remove them with the x">
                     <Control>
                         <MultiSelect
                             name="letters-multi"
-                            value=selected_list_value
-                            list_size=6
+                            value=selected_list
                             update=update_multi.clone()
-                        >
-                            <option value="a">"Alpha"</option>
-                            <option value="b">"Bravo"</option>
-                            <option value="c">"Charlie"</option>
-                            <option value="d">"Delta"</option>
-                            <option value="e">"Echo"</option>
-                            <option value="f">"Foxtrot"</option>
-                        </MultiSelect>
+                            options=SelectOption::from_pairs(
+                                [
+                                    ("a", "Alpha"),
+                                    ("b", "Bravo"),
+                                    ("c", "Charlie"),
+                                    ("d", "Delta"),
+                                    ("e", "Echo"),
+                                    ("f", "Foxtrot"),
+                                ]
+                                    .map(|(value, label)| (value.to_string(), label)),
+                            )
+                            placeholder="Type to filter..."
+                            list_size=6
+                        />
                     </Control>
                 </Field>
                 <p class="help">
@@ -0,0 +1,82 @@
+use lbc::prelude::{
+    Block, Content, Field, HeaderSize, RangeSlider, Size, Slider, SliderColor, SliderOutput,
+    Subtitle, Title,
+};
+use leptos::callback::Callback;
+use leptos::prelude::*;
+
+/// Example page showcasing the Slider and RangeSlider form components.
+#[component]
+pub fn FormSliderPage() -> impl IntoView {
+    let (volume, set_volume) = signal(40.0);
+    let (opacity, set_opacity) = signal(0.5);
+    let (price, set_price) = signal((20.0, 80.0));
+
+    view! {
+        <Block>
+            <Title size=HeaderSize::Is5>"Form: Slider"</Title>
+
+            <Content>
+                <Subtitle size=HeaderSize::Is6>"Basic Slider with output"</Subtitle>
+                <Field label="Volume">
+                    <Slider
+                        name="volume"
+                        value=volume
+                        update=Callback::new(move |v| set_volume.set(v))
+                        output=SliderOutput::Output
+                        color=SliderColor::Primary
+                        circle=true
+                    />
+                </Field>
+
+                <Subtitle size=HeaderSize::Is6>"Fractional steps, ticks and tooltip"</Subtitle>
+                <Field label="Opacity">
+                    <Slider
+                        name="opacity"
+                        value=opacity
+                        update=Callback::new(move |v| set_opacity.set(v))
+                        min=0.0
+                        max=1.0
+                        step=0.05
+                        ticks=0.25
+                        output=SliderOutput::Tooltip
+                        color=SliderColor::Info
+                        size=Size::Small
+                        fullwidth=true
+                    />
+                </Field>
+                <p class="help">"Opacity: " {move || opacity.get().to_string()}</p>
+
+                <Subtitle size=HeaderSize::Is6>"Range (two thumbs)"</Subtitle>
+                <Field label="Price">
+                    <RangeSlider
+                        name="price"
+                        value=price
+                        update=Callback::new(move |v| set_price.set(v))
+                        max=200.0
+                        step=5.0
+                        ticks=50.0
+                        output=SliderOutput::Output
+                        color=SliderColor::Success
+                        fullwidth=true
+                    />
+                </Field>
+                <p class="help">
+                    {move || {
+                        let (low, high) = price.get();
+                        format!("Between ${} and ${}", low, high)
+                    }}
+                </p>
+
+                <Subtitle size=HeaderSize::Is6>"Disabled"</Subtitle>
+                <Slider
+                    name="locked"
+                    value=70.0
+                    update=Callback::new(|_| {})
+                    label="Locked setting"
+                    disabled=true
+                />
+            </Content>
+        </Block>
+    }
+}
@@ -0,0 +1,77 @@
+use lbc::prelude::{Block, Content, HeaderSize, Size, Subtitle, Switch, SwitchColor, Title};
+use leptos::callback::Callback;
+use leptos::prelude::*;
+
+/// Example page showcasing the Switch form component.
+#[component]
+pub fn FormSwitchPage() -> impl IntoView {
+    let (notifications, set_notifications) = signal(true);
+    let (dark_mode, set_dark_mode) = signal(false);
+
+    view! {
+        <Block>
+            <Title size=HeaderSize::Is5>"Form: Switch"</Title>
+
+            <Content>
+                <Subtitle size=HeaderSize::Is6>"Basic Switch"</Subtitle>
+                <Switch
+                    name="notifications"
+                    checked=notifications
+                    update=Callback::new(move |next| set_notifications.set(next))
+                >
+                    "Email notifications"
+                </Switch>
+                <p class="help">
+                    "Notifications: "
+                    {move || if notifications.get() { "on" } else { "off" }}
+                </p>
+
+                <Subtitle size=HeaderSize::Is6>"Colors and styles"</Subtitle>
+                <Switch
+                    name="dark_mode"
+                    checked=dark_mode
+                    update=Callback::new(move |next| set_dark_mode.set(next))
+                    color=SwitchColor::Success
+                    rounded=true
+                >
+                    "Rounded success"
+                </Switch>
+                <Switch
+                    name="dark_mode_outlined"
+                    checked=dark_mode
+                    update=Callback::new(move |next| set_dark_mode.set(next))
+                    color=SwitchColor::Info
+                    rounded=true
+                    outlined=true
+                >
+                    "Rounded outlined info"
+                </Switch>
+                <Switch
+                    name="dark_mode_thin"
+                    checked=dark_mode
+                    update=Callback::new(move |next| set_dark_mode.set(next))
+                    color=SwitchColor::Danger
+                    thin=true
+                >
+                    "Thin danger"
+                </Switch>
+
+                <Subtitle size=HeaderSize::Is6>"Sizes"</Subtitle>
+                <Switch name="small" checked=true size=Size::Small color=SwitchColor::Primary>
+                    "Small"
+                </Switch>
+                <Switch name="medium" checked=true size=Size::Medium color=SwitchColor::Primary>
+                    "Medium"
+                </Switch>
+                <Switch name="large" checked=true size=Size::Large color=SwitchColor::Primary>
+                    "Large"
+                </Switch>
+
+                <Subtitle size=HeaderSize::Is6>"Disabled"</Subtitle>
+                <Switch name="locked" checked=true disabled=true>
+                    "Managed by your administrator"
+                </Switch>
+            </Content>
+        </Block>
+    }
+}
@@ -1,6 +1,10 @@
-use lbc::prelude::{Block, Content, Control, Field, HeaderSize, Size, Subtitle, TextArea, Title};
+use lbc::prelude::{
+    Block, Content, Control, Field, HeaderSize, PhraseCompletions, Size, Subtitle, TextArea, Title,
+};
 use leptos::callback::Callback;
-use leptos::prelude::{ClassAttribute, ElementChild, Get, IntoView, Set, component, signal, view};
+use leptos::prelude::{
+    ClassAttribute, ElementChild, Get, IntoView, Set, Update, component, signal, view,
+};
 
 /// Example page showcasing the TextArea form component.
 #[component]
@@ -8,10 +12,22 @@ pub fn FormTextAreaPage() -> impl IntoView {
     let (notes, set_notes) = signal(String::new());
     let (bio, set_bio) = signal(String::from("Once upon a time..."));
     let (ai_text, set_ai_text) = signal(String::new());
+    let (summary, set_summary) = signal(String::new());
 
     let update_notes = Callback::new(move |v: String| set_notes.set(v));
     let update_bio = Callback::new(move |v: String| set_bio.set(v));
     let update_ai = Callback::new(move |v: String| set_ai_text.set(v));
+    let update_summary = Callback::new(move |v: String| set_summary.set(v));
+    let (accepted, set_accepted) = signal(0u32);
+    let (rejected, set_rejected) = signal(0u32);
+    let on_accept = Callback::new(move |_suggestion: String| set_accepted.update(|n| *n += 1));
+    let on_reject = Callback::new(move |_suggestion: String| set_rejected.update(|n| *n += 1));
+    let phrases = PhraseCompletions::new([
+        "Thank you for reaching out.",
+        "Thank you for your patience.",
+        "Please let me know if you have any questions.",
+        "Kind regards,",
+    ]);
 
     view! {
         <Block>
@@ -48,19 +64,41 @@ pub fn FormTextAreaPage() -> impl IntoView {
                     </Control>
                 </Field>
 
-                <Subtitle size=HeaderSize::Is6>"With GenAI ribbon"</Subtitle>
-                <Field>
+                <Subtitle size=HeaderSize::Is6>"Autosize with character counter"</Subtitle>
+                <Field label="Summary" help="Grows from 2 to 8 rows">
+                    <Control>
+                        <TextArea
+                            name="summary"
+                            value=summary
+                            autosize=true
+                            min_rows=2
+                            max_rows=8
+                            maxlength=140
+                            placeholder="Keep it short…"
+                            update=update_summary
+                        />
+                    </Control>
+                </Field>
+
+                <Subtitle size=HeaderSize::Is6>"GenAI mode with inline completions"</Subtitle>
+                <Field help="Type \"Thank you\" or \"Kind\"; Tab accepts the grey suggestion, Escape dismisses it">
                     <Control>
                         <TextArea
                             name="ai"
                             value=ai_text
                             rows=3
                             is_genai=true
-                            placeholder="Generated text…"
+                            completion=phrases
+                            on_accept=on_accept
+                            on_reject=on_reject
+                            placeholder="Write a reply…"
                             update=update_ai
                         />
                     </Control>
                 </Field>
+                <p class="help">
+                    "Accepted: " {move || accepted.get()} ", dismissed: " {move || rejected.get()}
+                </p>
             </Content>
         </Block>
     }
@@ -1,9 +1,17 @@
 pub mod form_autocomplete_example_page;
+pub mod form_combobox_example_page;
 pub mod form_control_example_page;
+pub mod form_derive_example_page;
 pub mod form_example_page;
 pub mod form_field_example_page;
 pub mod form_file_example_page;
 pub mod form_input_example_page;
+pub mod form_markdown_editor_example_page;
+pub mod form_masked_input_example_page;
+pub mod form_password_input_example_page;
 pub mod form_radio_example_page;
+pub mod form_schema_example_page;
 pub mod form_select_example_page;
+pub mod form_slider_example_page;
+pub mod form_switch_example_page;
 pub mod form_text_area_example_page;
@@ -30,6 +30,7 @@ use components::modal_example_page::ModalPage;
 use components::navbar_example_page::NavbarPage;
 use components::pagination_example_page::PaginationPage;
 use components::panel_example_page::PanelPage;
+use components::steps_example_page::StepsPage;
 use components::tabs_example_page::TabsPage;
 use container_example_page::ContainerPage;
 
@@ -45,14 +46,22 @@ use elements::{
 };
 use footer_example_page::FooterPage;
 use form::form_autocomplete_example_page::FormAutoCompletePage;
+use form::form_combobox_example_page::FormComboboxPage;
 use form::form_control_example_page::FormControlPage;
+use form::form_derive_example_page::FormDerivePage;
 use form::form_example_page::FormCheckboxPage;
 use form::form_field_example_page::FormFieldPage;
 use form::form_file_example_page::FormFilePage;
 use form::form_input_example_page::FormInputPage;
+use form::form_markdown_editor_example_page::FormMarkdownEditorPage;
+use form::form_masked_input_example_page::FormMaskedInputPage;
 use form::form_radio_example_page::FormRadioPage;
+use form::form_schema_example_page::FormSchemaPage;
 use form::form_select_example_page::FormSelectPage;
+use form::form_slider_example_page::FormSliderPage;
+use form::form_switch_example_page::FormSwitchPage;
 use form::form_text_area_example_page::FormTextAreaPage;
+use form::form_password_input_example_page::FormPasswordInputPage;
 use hero_example_page::HeroPage;
 use level_example_page::LevelPage;
 use media_example_page::MediaPage;
@@ -96,6 +105,7 @@ fn App() -> impl IntoView {
                         <Route path=path!("components/calendar") view=CalendarPage />
                         <Route path=path!("components/breadcrumb") view=BreadcrumbPage />
                         <Route path=path!("components/accordion") view=AccordionPage />
+                        <Route path=path!("components/steps") view=StepsPage />
 
                         <Route path=path!("form/checkbox") view=FormCheckboxPage />
                         <Route path=path!("form/field") view=FormFieldPage />
@@ -106,6 +116,14 @@ fn App() -> impl IntoView {
                         <Route path=path!("form/select") view=FormSelectPage />
                         <Route path=path!("form/textarea") view=FormTextAreaPage />
                         <Route path=path!("form/autocomplete") view=FormAutoCompletePage />
+                        <Route path=path!("form/combobox") view=FormComboboxPage />
+                        <Route path=path!("form/switch") view=FormSwitchPage />
+                        <Route path=path!("form/slider") view=FormSliderPage />
+                        <Route path=path!("form/masked-input") view=FormMaskedInputPage />
+                        <Route path=path!("form/markdown_editor") view=FormMarkdownEditorPage />
+                        <Route path=path!("form/derive") view=FormDerivePage />
+                        <Route path=path!("form/schema") view=FormSchemaPage />
+                        <Route path=path!("form/password-input") view=FormPasswordInputPage />
 
                         <Route path=path!("layout/columns") view=ColumnsPage />
                         <Route path=path!("layout/container") view=ContainerPage />
@@ -158,6 +176,14 @@ fn Nav() -> impl IntoView {
                 <a class="button is-link is-light" href="/form/select">"Select"</a>
                 <a class="button is-link is-light" href="/form/textarea">"TextArea"</a>
                 <a class="button is-link is-light" href="/form/autocomplete">"AutoComplete"</a>
+                <a class="button is-link is-light" href="/form/combobox">"Combobox"</a>
+                <a class="button is-link is-light" href="/form/switch">"Switch"</a>
+                <a class="button is-link is-light" href="/form/slider">"Slider"</a>
+                <a class="button is-link is-light" href="/form/masked-input">"MaskedInput"</a>
+                <a class="button is-link is-light" href="/form/markdown_editor">"MarkdownEditor"</a>
+                <a class="button is-link is-light" href="/form/derive">"Derived form"</a>
+                <a class="button is-link is-light" href="/form/schema">"SchemaForm"</a>
+                <a class="button is-link is-light" href="/form/password-input">"PasswordInput"</a>
             </div>
 
             <h4 class="title is-6">"Components"</h4>
@@ -174,6 +200,7 @@ fn Nav() -> impl IntoView {
                 <a class="button is-link is-light" href="/components/navbar">"Navbar"</a>
                 <a class="button is-link is-light" href="/components/modal">"Modal"</a>
                 <a class="button is-link is-light" href="/components/message">"Message"</a>
+                <a class="button is-link is-light" href="/components/steps">"Steps"</a>
             </div>
 
             <h4 class="title is-6">"Layout"</h4>
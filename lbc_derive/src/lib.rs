@@ -0,0 +1,536 @@
+//! Derive macros for LBC (Leptos Bulma Components).
+//!
+//! `#[derive(LbcForm)]` implements `lbc::form::model::LbcForm` for a struct with named fields
+//! and generates a Leptos component, `{Struct}Form` by default, that edits it with LBC form
+//! components. See the `lbc::form::model` module for the attribute reference.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    Data, DeriveInput, Expr, Fields, GenericArgument, Ident, LitStr, Path, PathArguments, Token,
+    Type, parse_macro_input,
+};
+
+/// Derives `lbc::form::model::LbcForm` and a form component for a struct.
+///
+/// ```ignore
+/// #[derive(Clone, LbcForm)]
+/// #[lbc(component = "ProfileEditor", submit = "Save")]
+/// struct Profile {
+///     #[lbc(label = "Full name", required, min_length = 2)]
+///     name: String,
+///     #[lbc(widget = "email", help = "We never share it", email)]
+///     email: String,
+///     #[lbc(min = 0, max = 150)]
+///     age: Option<u32>,
+///     #[lbc(widget = "select", options("admin", "editor", "viewer"))]
+///     role: String,
+///     newsletter: bool,
+///     #[lbc(skip)]
+///     id: u64,
+/// }
+/// ```
+#[proc_macro_derive(LbcForm, attributes(lbc))]
+pub fn derive_lbc_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The control used for a field.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Widget {
+    Input(&'static str),
+    TextArea,
+    Checkbox,
+    Switch,
+    Select,
+}
+
+impl Widget {
+    fn parse(name: &LitStr) -> syn::Result<Self> {
+        Ok(match name.value().as_str() {
+            "text" => Widget::Input("Text"),
+            "email" => Widget::Input("Email"),
+            "password" => Widget::Input("Password"),
+            "tel" => Widget::Input("Tel"),
+            "url" => Widget::Input("Url"),
+            "search" => Widget::Input("Search"),
+            "number" => Widget::Input("Number"),
+            "date" => Widget::Input("Date"),
+            "time" => Widget::Input("Time"),
+            "datetime" => Widget::Input("DateTimeLocal"),
+            "color" => Widget::Input("Color"),
+            "textarea" => Widget::TextArea,
+            "checkbox" => Widget::Checkbox,
+            "switch" => Widget::Switch,
+            "select" => Widget::Select,
+            other => {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!(
+                        "unknown widget `{}`; expected one of text, email, password, tel, url, \
+                         search, number, date, time, datetime, color, textarea, checkbox, \
+                         switch, select",
+                        other
+                    ),
+                ));
+            }
+        })
+    }
+}
+
+/// How a field's Rust type maps to the form's string value.
+enum Kind {
+    Text,
+    Bool,
+    Optional(Type),
+    Parsed(Type),
+}
+
+impl Kind {
+    fn of(ty: &Type) -> Self {
+        let Type::Path(path) = ty else {
+            return Kind::Parsed(ty.clone());
+        };
+        let Some(last) = path.path.segments.last() else {
+            return Kind::Parsed(ty.clone());
+        };
+        match last.ident.to_string().as_str() {
+            "String" => Kind::Text,
+            "bool" => Kind::Bool,
+            "Option" => match &last.arguments {
+                PathArguments::AngleBracketed(args) => match args.args.first() {
+                    Some(GenericArgument::Type(inner)) => Kind::Optional(inner.clone()),
+                    _ => Kind::Parsed(ty.clone()),
+                },
+                _ => Kind::Parsed(ty.clone()),
+            },
+            _ => Kind::Parsed(ty.clone()),
+        }
+    }
+
+    fn is_numeric(&self) -> bool {
+        let ty = match self {
+            Kind::Optional(ty) | Kind::Parsed(ty) => ty,
+            _ => return false,
+        };
+        let Type::Path(path) = ty else {
+            return false;
+        };
+        path.path.get_ident().is_some_and(|ident| {
+            matches!(
+                ident.to_string().as_str(),
+                "u8" | "u16"
+                    | "u32"
+                    | "u64"
+                    | "u128"
+                    | "usize"
+                    | "i8"
+                    | "i16"
+                    | "i32"
+                    | "i64"
+                    | "i128"
+                    | "isize"
+                    | "f32"
+                    | "f64"
+            )
+        })
+    }
+}
+
+/// Parsed `#[lbc(...)]` attributes of one field.
+#[derive(Default)]
+struct FieldAttrs {
+    skip: bool,
+    label: Option<LitStr>,
+    help: Option<LitStr>,
+    placeholder: Option<LitStr>,
+    widget: Option<Widget>,
+    options: Vec<LitStr>,
+    options_fn: Option<Path>,
+    validators: Vec<TokenStream2>,
+    min: Option<Expr>,
+    max: Option<Expr>,
+}
+
+impl FieldAttrs {
+    fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let mut attrs = FieldAttrs::default();
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("lbc"))
+        {
+            attr.parse_nested_meta(|meta| {
+                let key = meta
+                    .path
+                    .get_ident()
+                    .map(Ident::to_string)
+                    .unwrap_or_default();
+                match key.as_str() {
+                    "skip" => attrs.skip = true,
+                    "label" => attrs.label = Some(meta.value()?.parse()?),
+                    "help" => attrs.help = Some(meta.value()?.parse()?),
+                    "placeholder" => attrs.placeholder = Some(meta.value()?.parse()?),
+                    "widget" => attrs.widget = Some(Widget::parse(&meta.value()?.parse()?)?),
+                    "options" => {
+                        let content;
+                        syn::parenthesized!(content in meta.input);
+                        let list = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+                        attrs.options.extend(list);
+                    }
+                    "options_fn" => attrs.options_fn = Some(meta.value()?.parse()?),
+                    "required" => attrs
+                        .validators
+                        .push(quote!(::lbc::form::validation::required())),
+                    "email" => attrs
+                        .validators
+                        .push(quote!(::lbc::form::validation::email())),
+                    "min_length" => {
+                        let min: Expr = meta.value()?.parse()?;
+                        attrs
+                            .validators
+                            .push(quote!(::lbc::form::validation::min_length(#min)));
+                    }
+                    "max_length" => {
+                        let max: Expr = meta.value()?.parse()?;
+                        attrs
+                            .validators
+                            .push(quote!(::lbc::form::validation::max_length(#max)));
+                    }
+                    "pattern" => {
+                        let pattern: LitStr = meta.value()?.parse()?;
+                        if let Err(err) = regex::Regex::new(&pattern.value()) {
+                            return Err(syn::Error::new(
+                                pattern.span(),
+                                format!("invalid regex in `pattern`: {}", err),
+                            ));
+                        }
+                        attrs
+                            .validators
+                            .push(quote!(::lbc::form::model::pattern_validator(#pattern)));
+                    }
+                    "validate" => {
+                        let check: Path = meta.value()?.parse()?;
+                        attrs
+                            .validators
+                            .push(quote!(::lbc::form::validation::custom(#check)));
+                    }
+                    "min" => attrs.min = Some(meta.value()?.parse()?),
+                    "max" => attrs.max = Some(meta.value()?.parse()?),
+                    _ => return Err(meta.error("unknown `lbc` field attribute")),
+                }
+                Ok(())
+            })?;
+        }
+        if attrs.min.is_some() || attrs.max.is_some() {
+            let min = option_f64(&attrs.min);
+            let max = option_f64(&attrs.max);
+            attrs
+                .validators
+                .push(quote!(::lbc::form::validation::range(#min, #max)));
+        }
+        Ok(attrs)
+    }
+}
+
+fn option_f64(value: &Option<Expr>) -> TokenStream2 {
+    match value {
+        Some(value) => quote!(::core::option::Option::Some((#value) as f64)),
+        None => quote!(::core::option::Option::None),
+    }
+}
+
+/// Parsed `#[lbc(...)]` attributes of the struct.
+struct ContainerAttrs {
+    component: Ident,
+    submit: LitStr,
+}
+
+impl ContainerAttrs {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut component = format_ident!("{}Form", input.ident);
+        let mut submit = LitStr::new("Submit", Span::call_site());
+        for attr in input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("lbc"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("component") {
+                    let name: LitStr = meta.value()?.parse()?;
+                    component = name.parse()?;
+                } else if meta.path.is_ident("submit") {
+                    submit = meta.value()?.parse()?;
+                } else {
+                    return Err(meta.error("unknown `lbc` struct attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(Self { component, submit })
+    }
+}
+
+/// "first_name" -> "First name".
+fn humanize(name: &str) -> String {
+    let words = name.trim_start_matches("r#").replace('_', " ");
+    let mut chars = words.trim().chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "LbcForm does not support generic structs",
+        ));
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "LbcForm can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "LbcForm requires a struct with named fields",
+        ));
+    };
+
+    let container = ContainerAttrs::parse(&input)?;
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let component = &container.component;
+    let submit = &container.submit;
+
+    let mut to_values = Vec::new();
+    let mut from_values = Vec::new();
+    let mut controls = Vec::new();
+
+    for field in &fields.named {
+        let attrs = FieldAttrs::parse(field)?;
+        if attrs.skip {
+            continue;
+        }
+        let member = field.ident.as_ref().expect("named field");
+        let name = member.to_string().trim_start_matches("r#").to_string();
+        let kind = Kind::of(&field.ty);
+
+        to_values.push(match &kind {
+            Kind::Optional(_) => quote! {
+                values.insert(
+                    #name.to_string(),
+                    self.#member.as_ref().map(|value| value.to_string()).unwrap_or_default(),
+                );
+            },
+            _ => quote! {
+                values.insert(#name.to_string(), self.#member.to_string());
+            },
+        });
+
+        let parse = match &kind {
+            Kind::Text => quote!(::core::result::Result::<_, ::std::string::String>::Ok(
+                raw.clone()
+            )),
+            Kind::Bool => quote!(::lbc::form::model::parse_form_value::<bool>(raw)),
+            Kind::Optional(inner) => {
+                quote!(::lbc::form::model::parse_optional_form_value::<#inner>(raw))
+            }
+            Kind::Parsed(ty) => quote!(::lbc::form::model::parse_form_value::<#ty>(raw)),
+        };
+        from_values.push(quote! {
+            if let ::core::option::Option::Some(raw) = values.get(#name) {
+                match #parse {
+                    ::core::result::Result::Ok(value) => next.#member = value,
+                    ::core::result::Result::Err(error) => {
+                        errors.insert(#name.to_string(), error);
+                    }
+                }
+            }
+        });
+
+        let mut validators = attrs.validators.clone();
+        match &kind {
+            Kind::Optional(ty) | Kind::Parsed(ty) => {
+                validators.push(quote!(::lbc::form::model::parses::<#ty>()));
+            }
+            Kind::Text | Kind::Bool => {}
+        }
+
+        let widget = attrs.widget.unwrap_or(match &kind {
+            Kind::Bool => Widget::Checkbox,
+            _ if !attrs.options.is_empty() || attrs.options_fn.is_some() => Widget::Select,
+            _ if kind.is_numeric() => Widget::Input("Number"),
+            _ => Widget::Input("Text"),
+        });
+        let label = attrs
+            .label
+            .clone()
+            .unwrap_or_else(|| LitStr::new(&humanize(&name), member.span()));
+        let help = attrs.help.as_ref().map(|help| quote!(help=#help));
+        let placeholder = attrs
+            .placeholder
+            .as_ref()
+            .map(|placeholder| quote!(placeholder=#placeholder));
+
+        let control = match widget {
+            Widget::Checkbox | Widget::Switch => {
+                let toggle = if widget == Widget::Switch {
+                    quote!(Switch)
+                } else {
+                    quote!(Checkbox)
+                };
+                quote! {{
+                    let (checked, update) = form.bind_bool(#name);
+                    view! {
+                        <Field name=#name #help validators=vec![#(#validators),*]>
+                            <Control>
+                                <#toggle name=#name checked=checked update=update>
+                                    #label
+                                </#toggle>
+                            </Control>
+                        </Field>
+                    }
+                    .into_any()
+                }}
+            }
+            Widget::TextArea => quote! {{
+                let (value, update) = form.bind(#name);
+                view! {
+                    <Field label=#label name=#name #help validators=vec![#(#validators),*]>
+                        <Control>
+                            <TextArea name=#name value=value update=update #placeholder />
+                        </Control>
+                    </Field>
+                }
+                .into_any()
+            }},
+            Widget::Select => {
+                let options = match &attrs.options_fn {
+                    Some(path) => quote!(#path()),
+                    None => {
+                        let options = &attrs.options;
+                        quote!(vec![#(SelectOption::new(#options.to_string(), #options)),*])
+                    }
+                };
+                let placeholder = attrs
+                    .placeholder
+                    .as_ref()
+                    .map(|placeholder| quote!(placeholder=#placeholder));
+                quote! {{
+                    let (value, update) = form.bind(#name);
+                    let options: ::std::vec::Vec<SelectOption<::std::string::String>> = #options;
+                    view! {
+                        <Field label=#label name=#name #help validators=vec![#(#validators),*]>
+                            <Control>
+                                <Select name=#name value=value update=update options=options #placeholder />
+                            </Control>
+                        </Field>
+                    }
+                    .into_any()
+                }}
+            }
+            Widget::Input(input_type) => {
+                let input_type = format_ident!("{}", input_type);
+                quote! {{
+                    let (value, update) = form.bind(#name);
+                    view! {
+                        <Field label=#label name=#name #help validators=vec![#(#validators),*]>
+                            <Control>
+                                <Input
+                                    name=#name
+                                    value=value
+                                    update=update
+                                    r#type=InputType::#input_type
+                                    #placeholder
+                                />
+                            </Control>
+                        </Field>
+                    }
+                    .into_any()
+                }}
+            }
+        };
+        controls.push(control);
+    }
+
+    let component_doc = format!(
+        " A form editing a [`{}`], generated by `#[derive(LbcForm)]`.",
+        ident
+    );
+
+    Ok(quote! {
+        impl ::lbc::form::model::LbcForm for #ident {
+            fn to_form_values(&self) -> ::lbc::form::FormValues {
+                let mut values = ::lbc::form::FormValues::new();
+                #(#to_values)*
+                values
+            }
+
+            fn with_form_values(
+                &self,
+                values: &::lbc::form::FormValues,
+            ) -> ::core::result::Result<Self, ::lbc::form::model::FieldErrors> {
+                let mut next = ::core::clone::Clone::clone(self);
+                let mut errors = ::lbc::form::model::FieldErrors::new();
+                #(#from_values)*
+                if errors.is_empty() {
+                    ::core::result::Result::Ok(next)
+                } else {
+                    ::core::result::Result::Err(errors)
+                }
+            }
+        }
+
+        #[doc = #component_doc]
+        #[::leptos::component]
+        #vis fn #component(
+            /// The value the form starts with; fields marked `skip` keep this value.
+            initial: #ident,
+
+            /// Called with the edited value when the form is submitted without errors.
+            on_submit: ::leptos::callback::Callback<#ident>,
+
+            /// Called with the edited value after each change that leaves every field parseable.
+            #[prop(optional)]
+            on_change: Option<::leptos::callback::Callback<#ident>>,
+
+            /// The state container; a new one is created when omitted.
+            // Plain `Option`: the component macro only strips options it can recognize by name.
+            #[prop(optional)]
+            form: Option<::lbc::form::FormContext>,
+        ) -> impl ::leptos::prelude::IntoView {
+            use ::lbc::form::prelude::*;
+            use ::leptos::prelude::*;
+
+            let form = form.unwrap_or_default();
+            let base = ::lbc::form::model::bind_model(form, initial, on_change);
+            let submit = ::leptos::callback::Callback::new(move |values: ::lbc::form::FormValues| {
+                if let ::core::option::Option::Some(value) = base.submit(&values) {
+                    on_submit.run(value);
+                }
+            });
+
+            view! {
+                <Form form=form on_submit=submit>
+                    #({#controls})*
+                    <div class="field">
+                        <div class="control">
+                            <button type="submit" class="button is-primary">#submit</button>
+                        </div>
+                    </div>
+                </Form>
+            }
+        }
+    })
+}
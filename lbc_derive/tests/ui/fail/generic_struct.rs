@@ -0,0 +1,8 @@
+use lbc::prelude::*;
+
+#[derive(Clone, LbcForm)]
+struct Wrapper<T> {
+    value: T,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use lbc::prelude::*;
+
+#[derive(Clone, LbcForm)]
+struct Signup {
+    #[lbc(pattern = "^[a-z+$")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use lbc::prelude::*;
+
+#[derive(Clone, LbcForm)]
+struct Signup {
+    #[lbc(requried)]
+    name: String,
+}
+
+fn main() {}
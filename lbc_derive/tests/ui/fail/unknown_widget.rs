@@ -0,0 +1,9 @@
+use lbc::prelude::*;
+
+#[derive(Clone, LbcForm)]
+struct Signup {
+    #[lbc(widget = "slider")]
+    volume: String,
+}
+
+fn main() {}
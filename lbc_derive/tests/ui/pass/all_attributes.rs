@@ -0,0 +1,29 @@
+use lbc::prelude::*;
+
+fn no_digits(value: &str) -> Result<(), String> {
+    if value.chars().any(|c| c.is_ascii_digit()) {
+        Err("No digits, please.".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Clone, LbcForm)]
+#[lbc(component = "SignupForm", submit = "Sign up")]
+struct Signup {
+    #[lbc(label = "User name", required, min_length = 3, max_length = 20, validate = no_digits)]
+    name: String,
+    #[lbc(widget = "email", help = "We never share it", placeholder = "you@example.com", email)]
+    email: String,
+    #[lbc(widget = "password", pattern = "^[[:alnum:]!@#]{8,}$")]
+    password: String,
+    #[lbc(min = 13, max = 120)]
+    age: Option<u8>,
+    #[lbc(options("free", "pro"))]
+    plan: String,
+    newsletter: bool,
+    #[lbc(skip)]
+    id: u64,
+}
+
+fn main() {}
@@ -1,58 +1,117 @@
 /*!
-Calendar component: a thin Leptos wrapper around the bulma-calendar JS date/time picker.
+Calendar component: a string-based compatibility wrapper around the native [`DatePicker`].
 
 Summary
-- Enhances a plain <input> with bulmaCalendar for date and time selection.
-- Emits changes through a Rust callback whenever the user selects, validates, or clears.
-- Requires bulmaCalendar JS and CSS to be loaded globally (available as `bulmaCalendar`).
+- Keeps the original `Calendar` API (formatted strings in, formatted strings out).
+- Renders the native Rust date picker; no JS plugin or CDN asset is required.
+- New code should prefer [`DatePicker`], which works with typed [`Date`]/[`Time`] values.
 
 Value format
-- The emitted string follows the configured `date_format` and `time_format` patterns understood by bulmaCalendar.
-- Clearing the picker emits an empty string.
+- The emitted string follows the configured `date_format` and `time_format` patterns
+  (defaults "yyyy-MM-dd" and "HH:mm"). Clearing the picker emits an empty string.
+- Picker type: an explicit `calendar_type` ("date", "datetime", "time" or "range") wins;
+  otherwise "range" when `update_range` is set, else "datetime" as before. Pass
+  `calendar_type="date"` for a date-only picker.
+
+Range mode
+- `date` holds the start and `date_end` the end (a single "start - end" string in `date` also works).
+- `update_range` receives the `(start, end)` pair and `update` the joined "start - end" text.
+- A start after the end is rejected: such input shows no selection.
 
 Programmatic control
 - To update the picker value from the outside, update the `date` signal.
-- To clear the picker from the outside, set the `date` signal to a single space `" "`.
-
-Required static assets
-- CSS (add in <head>):
-  https://cdn.jsdelivr.net/npm/bulma-calendar@7.1.1/dist/css/bulma-calendar.min.css
-- JS (load before WASM bootstrap so `bulmaCalendar` exists):
-  https://cdn.jsdelivr.net/npm/bulma-calendar@7.1.1/dist/js/bulma-calendar.min.js
+- To clear the picker from the outside, set the `date` signal to a single space `" "`
+  (or an empty string).
 */
 
-use leptos::html;
 use leptos::prelude::Callback;
 use leptos::prelude::{
-    Callable, ClassAttribute, CustomAttribute, Get, GetUntracked, GlobalAttributes, IntoView,
-    NodeRef, NodeRefAttribute, Signal, component, view,
+    AddAnyAttr, Callable, ClassAttribute, CustomAttribute, Effect, Get, GetUntracked,
+    GlobalAttributes, IntoAny, IntoView, Memo, OnAttribute, PropAttribute, RwSignal, Set, Signal,
+    component, event_target_value, view,
 };
-#[cfg(target_arch = "wasm32")]
-use leptos::wasm_bindgen::closure::Closure;
-#[cfg(target_arch = "wasm32")]
-use leptos::wasm_bindgen::{JsCast, JsValue};
-#[cfg(target_arch = "wasm32")]
-use leptos::web_sys::Element;
 
+use crate::components::date_picker::{
+    Date, DatePicker, Time, format_date, format_parts, parse_date, parse_parts, parse_time,
+};
+use crate::components::date_range_picker::{DateRange, DateRangePicker, format_range, parse_range};
 use crate::util::TestAttr;
 
-/// A date/time input enhanced by bulma-calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CalendarKind {
+    Date,
+    DateTime,
+    Time,
+    Range,
+}
+
+fn calendar_kind(calendar_type: &str, with_range: bool) -> CalendarKind {
+    match calendar_type.trim() {
+        "date" => CalendarKind::Date,
+        "datetime" => CalendarKind::DateTime,
+        "time" => CalendarKind::Time,
+        "range" => CalendarKind::Range,
+        _ if with_range => CalendarKind::Range,
+        _ => CalendarKind::DateTime,
+    }
+}
+
+fn or_default(pattern: String, default: &str) -> String {
+    if pattern.trim().is_empty() {
+        default.to_string()
+    } else {
+        pattern
+    }
+}
+
+/// Parses the `date` string for the given picker type; blank or unparseable text is empty.
+fn parse_value(
+    raw: &str,
+    kind: CalendarKind,
+    date_format: &str,
+    time_format: &str,
+) -> (Option<Date>, Option<Time>) {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return (None, None);
+    }
+    match kind {
+        CalendarKind::Date | CalendarKind::Range => (parse_date(raw, date_format), None),
+        CalendarKind::Time => (None, parse_time(raw, time_format)),
+        CalendarKind::DateTime => parse_parts(raw, &format!("{} {}", date_format, time_format))
+            .unwrap_or_else(|| (parse_date(raw, date_format), None)),
+    }
+}
+
+/// Parses the range from `start`/`end`, or from "start - end" in `start` alone.
+fn parse_range_value(start: &str, end: &str, date_format: &str) -> Option<DateRange> {
+    let (start, end) = (start.trim(), end.trim());
+    if end.is_empty() {
+        return parse_range(start, date_format).ok();
+    }
+    DateRange::new(
+        parse_date(start, date_format)?,
+        parse_date(end, date_format)?,
+    )
+}
+
+/// A date/time input backed by the native [`DatePicker`].
 ///
-/// Controlled outward via `update` callback. The underlying input is driven by the JS plugin.
+/// Controlled outward via `update` callback, which receives the formatted value.
 #[component]
 pub fn Calendar(
-    /// Unique DOM id for the input (used to attach/detach the JS widget).
+    /// Unique DOM id for the input.
     id: String,
 
-    /// Date format understood by bulmaCalendar. Defaults to "yyyy-MM-dd" when empty.
+    /// Date format pattern (e.g. "yyyy-MM-dd", "dd.MM.yyyy"). Defaults to "yyyy-MM-dd" when empty.
     #[prop(optional, into)]
     date_format: Signal<String>,
 
-    /// Time format understood by bulmaCalendar. Defaults to "HH:mm" when empty.
+    /// Time format pattern (e.g. "HH:mm"). Defaults to "HH:mm" when empty.
     #[prop(optional, into)]
     time_format: Signal<String>,
 
-    /// Optional initial value to seed the widget with.
+    /// Current value, formatted with `date_format`/`time_format`.
     #[prop(optional, into)]
     date: Signal<String>,
 
@@ -70,220 +129,167 @@ pub fn Calendar(
     #[prop(optional, into)]
     test_attr: Option<TestAttr>,
 
-    #[prop(optional, into)] calendar_type: Signal<String>,
+    /// Picker type: "date", "datetime", "time" or "range". When empty: "range" if
+    /// `update_range` is set, else "datetime".
+    #[prop(optional, into)]
+    calendar_type: Signal<String>,
+
+    /// Range mode: end of the range, formatted with `date_format` (`date` is the start).
+    #[prop(optional, into)]
+    date_end: Signal<String>,
+
+    /// Range mode: receives the formatted `(start, end)` pair; `("", "")` on clear.
+    #[prop(optional)]
+    update_range: Option<Callback<(String, String)>>,
 ) -> impl IntoView {
-    let input_ref: NodeRef<html::Input> = NodeRef::new();
-
-    // Compute Bulma "input" base class plus extras.
-    let class = {
-        let classes = classes.clone();
-        move || {
-            let extra = classes.get();
-            if extra.trim().is_empty() {
-                "input".to_string()
-            } else {
-                format!("input {}", extra)
-            }
+    let with_range = update_range.is_some();
+    let kind = Memo::new(move |_| calendar_kind(&calendar_type.get(), with_range));
+    let date_pattern = move || or_default(date_format.get(), "yyyy-MM-dd");
+    let time_pattern = move || or_default(time_format.get(), "HH:mm");
+
+    let parsed =
+        Memo::new(move |_| parse_value(&date.get(), kind.get(), &date_pattern(), &time_pattern()));
+    let picked_date = RwSignal::new(parsed.get_untracked().0);
+    let picked_time = RwSignal::new(parsed.get_untracked().1);
+    let parsed_range =
+        Memo::new(move |_| parse_range_value(&date.get(), &date_end.get(), &date_pattern()));
+    let picked_range = RwSignal::new(parsed_range.get_untracked());
+
+    // Follow external changes; a single space clears and is reported like a user clear.
+    Effect::new(move |previous: Option<()>| {
+        let (date_value, time_value) = parsed.get();
+        picked_date.set(date_value);
+        picked_time.set(time_value);
+        picked_range.set(parsed_range.get());
+        if previous.is_some() && date.get_untracked() == " " {
+            update.run(String::new());
         }
-    };
+    });
 
-    // Keep simple initial value for SSR; runtime is controlled by JS widget.
-    let initial_value = date.get_untracked();
-    let _date_format_sig = date_format.clone();
-    // Validate date_format strictly: allow only exact "yyyy-MM-dd" (lowercase), or empty to use default.
-    {
-        let df_now = _date_format_sig.get_untracked();
-        let df_trim = df_now.trim();
-        assert!(
-            df_trim.is_empty() || df_trim == "yyyy-MM-dd",
-            "Calendar date_format must be exactly 'yyyy-MM-dd' (lowercase yyyy-MM-dd). Got '{}'",
-            df_now
-        );
-    }
-    let _time_format_sig = time_format.clone();
-    let _id_for_cleanup = id.clone();
-    let _id_for_effect = id.clone();
-    let _date_sig = date.clone();
-    let _calendar_type_sig = if calendar_type.get().trim().is_empty() {
-        Signal::from("datetime")
-    } else {
-        calendar_type.clone()
+    let emit = move |date_value: Option<Date>, time_value: Option<Time>| {
+        let text = match kind.get_untracked() {
+            CalendarKind::Date | CalendarKind::Range => date_value
+                .map(|d| format_parts(&date_pattern(), Some(d), None))
+                .unwrap_or_default(),
+            CalendarKind::Time => time_value
+                .map(|t| format_parts(&time_pattern(), None, Some(t)))
+                .unwrap_or_default(),
+            CalendarKind::DateTime => date_value
+                .map(|d| {
+                    let pattern = format!("{} {}", date_pattern(), time_pattern());
+                    format_parts(&pattern, Some(d), Some(time_value.unwrap_or_default()))
+                })
+                .unwrap_or_default(),
+        };
+        update.run(text);
     };
-    #[cfg(target_arch = "wasm32")]
-    let initial_for_js = initial_value.clone();
-    #[cfg(not(target_arch = "wasm32"))]
-    let _ = &update;
-
-    // Attach the JS widget once the node is mounted.
-    #[cfg(target_arch = "wasm32")]
-    {
-        // Use an Effect to run after the initial render, when the node ref is available.
-        leptos::prelude::Effect::new(move |_| {
-            if let Some(input) = input_ref.get() {
-                // Convert HtmlInputElement => Element for the JS bridge.
-                let element: Element = input.unchecked_into();
-
-                // Bridge from JS -> Rust, forward value through provided callback.
-                let cb = {
-                    let update = update.clone();
-                    Closure::wrap(Box::new(move |date: JsValue| {
-                        let s = date.as_string().unwrap_or_default();
-                        update.run(s);
-                    }) as Box<dyn FnMut(JsValue)>)
-                };
-
-                // Resolve effective formats with sane defaults.
-                let df = {
-                    let s = _date_format_sig.get();
-                    if s.trim().is_empty() {
-                        "yyyy-MM-dd".to_string()
-                    } else {
-                        s
-                    }
-                };
-                let tf = {
-                    let s = _time_format_sig.get();
-                    if s.trim().is_empty() {
-                        "HH:mm".to_string()
-                    } else {
-                        s
-                    }
-                };
-
-                // Determine picker type based on whether time format is provided.
-                let picker_type = _calendar_type_sig.get_untracked();
-
-                // Seed initial value, attach plugin, and keep the closure alive.
-                setup_date_picker(
-                    &element,
-                    cb.as_ref(),
-                    &JsValue::from(initial_for_js.clone()),
-                    &JsValue::from(df),
-                    &JsValue::from(tf),
-                    &JsValue::from(picker_type),
-                );
-                cb.forget();
-            }
-        });
-
-        // Watch for changes in the date signal to update or clear the picker.
-        leptos::prelude::Effect::new(move |_| {
-            let current_date = _date_sig.get();
-            if current_date == " " {
-                clear_date(&JsValue::from(_id_for_effect.as_str()));
-            } else {
-                update_value(
-                    &JsValue::from(_id_for_effect.as_str()),
-                    &JsValue::from(current_date),
-                );
-            }
-        });
-    }
 
-    // Detach JS state on unmount.
-    #[cfg(target_arch = "wasm32")]
-    leptos::prelude::on_cleanup(move || {
-        detach_date_picker(&JsValue::from(_id_for_cleanup.as_str()));
+    let on_date = Callback::new(move |value: Option<Date>| {
+        picked_date.set(value);
+        emit(value, picked_time.get_untracked());
+    });
+    let on_range = Callback::new(move |value: Option<DateRange>| {
+        picked_range.set(value);
+        let pattern = date_pattern();
+        let pair = value
+            .map(|range| {
+                (
+                    format_date(range.start(), &pattern),
+                    format_date(range.end(), &pattern),
+                )
+            })
+            .unwrap_or_default();
+        if let Some(update_range) = update_range {
+            update_range.run(pair);
+        }
+        update.run(
+            value
+                .map(|range| format_range(range, &pattern))
+                .unwrap_or_default(),
+        );
+    });
+    let on_time = Callback::new(move |value: Option<Time>| {
+        picked_time.set(value);
+        if let Some(date_value) = picked_date.get_untracked() {
+            emit(Some(date_value), value);
+        }
     });
 
-    // Derive specific optional attributes that our macro can render.
     let (data_testid, data_cy) = match &test_attr {
         Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
         Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
         _ => (None, None),
     };
 
-    // For SSR/tests: render a reasonable input type.
-    // Existing tests expect:
-    // - type="date" when time_format is empty
-    // - type="datetime" when time_format is provided
-    let input_type = {
-        let tf = _time_format_sig.clone();
-        move || {
-            if tf.get().trim().is_empty() {
-                "date".to_string()
-            } else {
-                "datetime".to_string()
+    match kind.get_untracked() {
+        CalendarKind::Time => {
+            let class = move || {
+                let extra = classes.get();
+                if extra.trim().is_empty() {
+                    "input".to_string()
+                } else {
+                    format!("input {}", extra)
+                }
+            };
+            let time_text = move || picked_time.get().map(|t| t.to_string()).unwrap_or_default();
+            view! {
+                <input
+                    id=id
+                    class=class
+                    type="time"
+                    value=time_text()
+                    prop:value=time_text
+                    on:change=move |ev| {
+                        let value = event_target_value(&ev).parse::<Time>().ok();
+                        picked_time.set(value);
+                        emit(None, value);
+                    }
+                    attr:data-testid=data_testid
+                    attr:data-cy=data_cy
+                />
             }
+            .into_any()
         }
-    };
-
-    view! {
-        <input
-            id=id.clone()
-            class=move || class()
-            type=input_type
-            value=initial_value
-            node_ref=input_ref
-            attr:data-testid=move || data_testid.clone()
-            attr:data-cy=move || data_cy.clone()
-        />
-    }
-}
-
-// JS bridge that attaches bulmaCalendar to the provided element and wires a change callback.
-#[cfg(target_arch = "wasm32")]
-#[leptos::wasm_bindgen::prelude::wasm_bindgen(inline_js = r#"
-let init = new Map();
-export function setup_date_picker(element, callback, initial_date, date_format, time_format, picker_type) {
-    if (!init.has(element.id)) {
-        let calendarInstances = bulmaCalendar.attach(element, {
-            type: picker_type || (String(time_format || '').trim() ? 'datetime' : 'date'),
-            color: 'info',
-            lang: 'en',
-            dateFormat: date_format,
-            timeFormat: time_format,
-            showTodayButton: false
-        });
-        init.set(element.id, calendarInstances[0]);
-        let calendarInstance = calendarInstances[0];
-        calendarInstance.on('select', function(datepicker) {
-            callback(datepicker.data.value());
-        });
-        calendarInstance.on('clear', function(_datepicker) {
-            callback('');
-        });
-        calendarInstance.on('validate', function(datepicker) {
-            callback(datepicker.data.value());
-            calendarInstance.hide();
-        });
-    }
-    if (initial_date) {
-        init.get(element.id).value(initial_date);
-    }
-}
-export function detach_date_picker(id) {
-    init.delete(id);
-}
-export function clear_date(id) {
-    if (init.has(id)) {
-        init.get(id).clear();
-    }
-}
-export function update_value(id, value) {
-    if (init.has(id)) {
-        init.get(id).value(value);
+        CalendarKind::Date => view! {
+            <DatePicker
+                id=id
+                value=Signal::derive(move || picked_date.get())
+                update=on_date
+                format=Signal::derive(date_pattern)
+                classes=classes
+                attr:data-testid=data_testid
+                attr:data-cy=data_cy
+            />
+        }
+        .into_any(),
+        CalendarKind::DateTime => view! {
+            <DatePicker
+                id=id
+                value=Signal::derive(move || picked_date.get())
+                update=on_date
+                time=Signal::derive(move || picked_time.get())
+                update_time=on_time
+                format=Signal::derive(move || format!("{} {}", date_pattern(), time_pattern()))
+                classes=classes
+                attr:data-testid=data_testid
+                attr:data-cy=data_cy
+            />
+        }
+        .into_any(),
+        CalendarKind::Range => view! {
+            <DateRangePicker
+                id=id
+                value=Signal::derive(move || picked_range.get())
+                update=on_range
+                format=Signal::derive(date_pattern)
+                classes=classes
+                attr:data-testid=data_testid
+                attr:data-cy=data_cy
+            />
+        }
+        .into_any(),
     }
 }
-"#)]
-#[cfg(target_arch = "wasm32")]
-#[allow(improper_ctypes, improper_ctypes_definitions)]
-extern "C" {
-    fn setup_date_picker(
-        element: &Element,
-        callback: &JsValue,
-        initial_date: &JsValue,
-        date_format: &JsValue,
-        time_format: &JsValue,
-        picker_type: &JsValue,
-    );
-
-    fn detach_date_picker(id: &JsValue);
-
-    fn clear_date(id: &JsValue);
-
-    fn update_value(id: &JsValue, value: &JsValue);
-}
 
 #[cfg(test)]
 mod tests {
@@ -312,7 +318,12 @@ mod tests {
     #[test]
     fn calendar_initial_value_and_extra_classes() {
         let html = view! {
-            <Calendar id="d".to_string() date="2025-01-01 10:00" classes="is-small" update=noop() />
+            <Calendar
+                id="d".to_string()
+                date="2025-01-01 10:00"
+                classes="is-small"
+                update=noop()
+            />
         }
         .to_html();
         assert!(
@@ -329,25 +340,31 @@ mod tests {
     }
 
     #[test]
-    fn calendar_date_only_sets_input_type_date() {
+    fn calendar_date_only_renders_date_picker_without_time() {
         let html = view! {
             <Calendar
                 id="only-date".to_string()
                 date="2025-02-03"
                 date_format="yyyy-MM-dd"
+                calendar_type="date"
                 update=noop()
             />
         }
         .to_html();
         assert!(
-            html.contains(r#"type="date""#),
-            "expected input type=date when time_format is empty; got: {}",
+            html.contains("date-picker") && html.contains(r#"value="2025-02-03""#),
+            "expected native date picker with value; got: {}",
+            html
+        );
+        assert!(
+            !html.contains(r#"type="time""#),
+            "expected no time input for calendar_type=\"date\"; got: {}",
             html
         );
     }
 
     #[test]
-    fn calendar_datetime_sets_input_type_datetime() {
+    fn calendar_datetime_renders_time_input() {
         let html = view! {
             <Calendar
                 id="with-datetime".to_string()
@@ -359,24 +376,113 @@ mod tests {
         }
         .to_html();
         assert!(
-            html.contains(r#"type="datetime""#),
-            "expected input type=datetime when time_format provided; got: {}",
+            html.contains(r#"type="time""#) && html.contains(r#"value="12:34""#),
+            "expected time input when time_format provided; got: {}",
             html
         );
     }
 
     #[test]
-    #[should_panic(expected = "Calendar date_format must be exactly 'yyyy-MM-dd'")]
-    fn calendar_rejects_invalid_date_format_uppercase() {
-        // Using uppercase tokens should be rejected
-        let _ = view! {
+    fn calendar_time_type_renders_time_input_only() {
+        let html = view! {
             <Calendar
-                id="bad-format".to_string()
+                id="time-only".to_string()
+                date="12:45"
+                time_format="HH:mm"
+                calendar_type="time"
+                update=noop()
+            />
+        }
+        .to_html();
+        assert!(
+            html.contains(r#"type="time""#)
+                && html.contains(r#"value="12:45""#)
+                && !html.contains("date-picker"),
+            "expected plain time input; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn calendar_accepts_other_date_formats() {
+        let html = view! {
+            <Calendar
+                id="custom-format".to_string()
+                date="03.02.2025"
+                date_format="dd.MM.yyyy"
+                update=noop()
+            />
+        }
+        .to_html();
+        assert!(
+            html.contains(r#"value="03.02.2025""#),
+            "expected value rendered in the custom format; got: {}",
+            html
+        );
+
+        // Uppercase tokens are accepted as aliases.
+        let html = view! {
+            <Calendar
+                id="upper-format".to_string()
+                date="2025-02-03"
                 date_format="YYYY-MM-DD"
                 update=noop()
             />
         }
         .to_html();
+        assert!(
+            html.contains(r#"value="2025-02-03""#),
+            "expected value rendered with uppercase pattern; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn calendar_kind_resolution() {
+        assert_eq!(calendar_kind("", false), CalendarKind::DateTime);
+        assert_eq!(calendar_kind("date", false), CalendarKind::Date);
+        assert_eq!(calendar_kind("time", false), CalendarKind::Time);
+        assert_eq!(calendar_kind("", true), CalendarKind::Range);
+        assert_eq!(calendar_kind("range", false), CalendarKind::Range);
+        assert_eq!(
+            parse_value(" ", CalendarKind::Date, "yyyy-MM-dd", "HH:mm"),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn calendar_range_mode_renders_range() {
+        let html = view! {
+            <Calendar
+                id="report".to_string()
+                date="2025-03-01"
+                date_end="2025-03-07"
+                update=noop()
+                update_range=Callback::new(|_: (String, String)| {})
+            />
+        }
+        .to_html();
+        assert!(
+            html.contains("date-range-picker")
+                && html.contains(r#"value="2025-03-01 - 2025-03-07""#),
+            "expected range picker with both dates; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn calendar_range_requires_start_before_end() {
+        assert_eq!(
+            parse_range_value("2025-03-07", "2025-03-01", "yyyy-MM-dd"),
+            None
+        );
+        assert_eq!(
+            parse_range_value("01.03.2025 - 07.03.2025", "", "dd.MM.yyyy"),
+            DateRange::new(
+                Date::new(2025, 3, 1).unwrap(),
+                Date::new(2025, 3, 7).unwrap()
+            )
+        );
     }
 }
 
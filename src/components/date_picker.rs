@@ -0,0 +1,1198 @@
+/*!
+DatePicker component: a native Leptos date (and optional time) picker styled with Bulma.
+
+Summary
+- A text input opens a dropdown with a month grid; `inline=true` renders the grid permanently.
+- Works with typed values: [`Date`] and [`Time`] instead of formatted strings.
+- `min`/`max`, `disabled_dates` and an `is_disabled` predicate block days from being picked.
+- The input accepts typed text in the display `format` (tokens: `yyyy`, `MM`, `M`, `dd`, `d`,
+  `HH`, `H`, `mm`, `m`; `YYYY`/`DD`/`D` are accepted as aliases).
+
+Keyboard (on the month grid)
+- Arrow keys move by day/week, PageUp/PageDown by month (with Shift: by year).
+- Home/End jump to the start/end of the week, Enter or Space picks the focused day.
+- Escape closes the dropdown. ArrowDown on the input opens it.
+
+No JS plugin or CDN asset is required.
+*/
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use leptos::callback::Callback;
+use leptos::ev::{FocusEvent, KeyboardEvent, MouseEvent};
+use leptos::html;
+use leptos::prelude::{
+    AriaAttributes, Callable, ClassAttribute, CollectView, CustomAttribute, Effect, ElementChild,
+    Get, GetUntracked, GlobalAttributes, IntoAny, IntoView, NodeRef, NodeRefAttribute, OnAttribute,
+    Owner, PropAttribute, RwSignal, Set, Signal, With, component, event_target_value,
+    provide_context, use_context, view,
+};
+use leptos::wasm_bindgen::JsCast;
+
+use crate::util::TestAttr;
+
+/// A calendar date in the proleptic Gregorian calendar.
+///
+/// Displays and parses as ISO `yyyy-MM-dd`; use [`format_date`]/[`parse_date`] for other patterns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Returns `None` for dates that do not exist (e.g. February 30th).
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Date> {
+        ((1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month))
+            .then_some(Date { year, month, day })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Month of the year, 1-12.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Day of the month, 1-31.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Today's date (local time in the browser, UTC elsewhere).
+    pub fn today() -> Date {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let now = js_sys::Date::new_0();
+            Date {
+                year: now.get_full_year() as i32,
+                month: now.get_month() as u8 + 1,
+                day: now.get_date() as u8,
+            }
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let secs = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs() as i64)
+                .unwrap_or_default();
+            Date::from_days(secs.div_euclid(86_400))
+        }
+    }
+
+    /// Days since 1970-01-01.
+    fn to_days(self) -> i64 {
+        let (month, day) = (i64::from(self.month), i64::from(self.day));
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn from_days(days: i64) -> Date {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Date {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+
+    /// Day of the week, 0 = Monday ... 6 = Sunday.
+    pub fn weekday(&self) -> u8 {
+        (self.to_days() + 3).rem_euclid(7) as u8
+    }
+
+    pub fn add_days(self, days: i64) -> Date {
+        Date::from_days(self.to_days() + days)
+    }
+
+    /// Moves by whole months, clamping the day to the length of the target month.
+    pub fn add_months(self, months: i32) -> Date {
+        let index = self.year * 12 + i32::from(self.month) - 1 + months;
+        let (year, month) = (index.div_euclid(12), (index.rem_euclid(12) + 1) as u8);
+        Date {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    pub fn first_of_month(self) -> Date {
+        Date { day: 1, ..self }
+    }
+
+    pub fn last_of_month(self) -> Date {
+        Date {
+            day: days_in_month(self.year, self.month),
+            ..self
+        }
+    }
+
+    /// Signed number of days from `self` to `other`.
+    pub fn days_until(self, other: Date) -> i64 {
+        other.to_days() - self.to_days()
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_date(text, "yyyy-MM-dd").ok_or_else(|| format!("'{}' is not a valid date", text))
+    }
+}
+
+/// A time of day with minute precision.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+}
+
+impl Time {
+    pub fn new(hour: u8, minute: u8) -> Option<Time> {
+        (hour < 24 && minute < 60).then_some(Time { hour, minute })
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+impl FromStr for Time {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_time(text, "HH:mm").ok_or_else(|| format!("'{}' is not a valid time", text))
+    }
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token {
+    Year,
+    Month { padded: bool },
+    Day { padded: bool },
+    Hour { padded: bool },
+    Minute { padded: bool },
+    Literal(char),
+}
+
+fn tokenize(pattern: &str) -> Vec<Token> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        let current = chars[index];
+        let run = chars[index..]
+            .iter()
+            .take_while(|other| **other == current)
+            .count();
+        let (token, width) = match (current, run) {
+            ('y' | 'Y', 4..) => (Token::Year, 4),
+            ('M', 2..) => (Token::Month { padded: true }, 2),
+            ('M', _) => (Token::Month { padded: false }, 1),
+            ('d' | 'D', 2..) => (Token::Day { padded: true }, 2),
+            ('d' | 'D', _) => (Token::Day { padded: false }, 1),
+            ('H', 2..) => (Token::Hour { padded: true }, 2),
+            ('H', _) => (Token::Hour { padded: false }, 1),
+            ('m', 2..) => (Token::Minute { padded: true }, 2),
+            ('m', _) => (Token::Minute { padded: false }, 1),
+            (other, _) => (Token::Literal(other), 1),
+        };
+        tokens.push(token);
+        index += width;
+    }
+    tokens
+}
+
+/// Formats the available parts with `pattern`; tokens without a value render as empty.
+pub fn format_parts(pattern: &str, date: Option<Date>, time: Option<Time>) -> String {
+    let number = |value: Option<u32>, padded: bool| match value {
+        Some(value) if padded => format!("{:02}", value),
+        Some(value) => value.to_string(),
+        None => String::new(),
+    };
+    tokenize(pattern)
+        .into_iter()
+        .map(|token| match token {
+            Token::Year => date.map(|d| format!("{:04}", d.year)).unwrap_or_default(),
+            Token::Month { padded } => number(date.map(|d| u32::from(d.month)), padded),
+            Token::Day { padded } => number(date.map(|d| u32::from(d.day)), padded),
+            Token::Hour { padded } => number(time.map(|t| u32::from(t.hour)), padded),
+            Token::Minute { padded } => number(time.map(|t| u32::from(t.minute)), padded),
+            Token::Literal(c) => c.to_string(),
+        })
+        .collect()
+}
+
+pub fn format_date(date: Date, pattern: &str) -> String {
+    format_parts(pattern, Some(date), None)
+}
+
+/// Parses `text` with `pattern`. Returns `None` when the text does not match or a part is
+/// out of range; parts that the pattern does not contain are `None`.
+pub fn parse_parts(text: &str, pattern: &str) -> Option<(Option<Date>, Option<Time>)> {
+    let chars: Vec<char> = text.trim().chars().collect();
+    let mut index = 0;
+    let take_number = |index: &mut usize, max_digits: usize| -> Option<u32> {
+        let digits: String = chars[*index..]
+            .iter()
+            .take(max_digits)
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+        *index += digits.len();
+        digits.parse().ok()
+    };
+    let (mut year, mut month, mut day, mut hour, mut minute) = (None, None, None, None, None);
+    for token in tokenize(pattern) {
+        match token {
+            Token::Year => year = Some(take_number(&mut index, 4)? as i32),
+            Token::Month { .. } => month = Some(take_number(&mut index, 2)? as u8),
+            Token::Day { .. } => day = Some(take_number(&mut index, 2)? as u8),
+            Token::Hour { .. } => hour = Some(take_number(&mut index, 2)? as u8),
+            Token::Minute { .. } => minute = Some(take_number(&mut index, 2)? as u8),
+            Token::Literal(expected) => {
+                if chars.get(index) != Some(&expected) {
+                    return None;
+                }
+                index += 1;
+            }
+        }
+    }
+    if index != chars.len() {
+        return None;
+    }
+    let date = match (year, month, day) {
+        (None, None, None) => None,
+        (Some(year), Some(month), Some(day)) => Some(Date::new(year, month, day)?),
+        _ => return None,
+    };
+    let time = match (hour, minute) {
+        (None, None) => None,
+        (Some(hour), minute) => Some(Time::new(hour, minute.unwrap_or(0))?),
+        (None, Some(_)) => return None,
+    };
+    Some((date, time))
+}
+
+pub fn parse_date(text: &str, pattern: &str) -> Option<Date> {
+    parse_parts(text, pattern)?.0
+}
+
+pub fn parse_time(text: &str, pattern: &str) -> Option<Time> {
+    parse_parts(text, pattern)?.1
+}
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [(&str, &str); 7] = [
+    ("Mo", "Monday"),
+    ("Tu", "Tuesday"),
+    ("We", "Wednesday"),
+    ("Th", "Thursday"),
+    ("Fr", "Friday"),
+    ("Sa", "Saturday"),
+    ("Su", "Sunday"),
+];
+
+fn month_title(month: Date) -> String {
+    format!(
+        "{} {}",
+        MONTH_NAMES[usize::from(month.month - 1)],
+        month.year
+    )
+}
+
+/// Weekday offset of `date` within a week starting on Monday or Sunday.
+fn week_offset(date: Date, week_starts_sunday: bool) -> i64 {
+    let weekday = i64::from(date.weekday());
+    if week_starts_sunday {
+        (weekday + 1) % 7
+    } else {
+        weekday
+    }
+}
+
+/// The 42 days (6 weeks) shown for the month containing `month`.
+pub(crate) fn month_grid(month: Date, week_starts_sunday: bool) -> Vec<Date> {
+    let first = month.first_of_month();
+    let start = first.add_days(-week_offset(first, week_starts_sunday));
+    (0..42).map(|offset| start.add_days(offset)).collect()
+}
+
+/// The day the grid focus moves to for `key`, or `None` if the key does not navigate.
+pub(crate) fn keyboard_target(
+    key: &str,
+    shift: bool,
+    focused: Date,
+    week_starts_sunday: bool,
+) -> Option<Date> {
+    let offset = week_offset(focused, week_starts_sunday);
+    Some(match key {
+        "ArrowLeft" => focused.add_days(-1),
+        "ArrowRight" => focused.add_days(1),
+        "ArrowUp" => focused.add_days(-7),
+        "ArrowDown" => focused.add_days(7),
+        "PageUp" => focused.add_months(if shift { -12 } else { -1 }),
+        "PageDown" => focused.add_months(if shift { 12 } else { 1 }),
+        "Home" => focused.add_days(-offset),
+        "End" => focused.add_days(6 - offset),
+        _ => return None,
+    })
+}
+
+/// How a day cell is rendered by [`MonthGrid`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DayState {
+    pub selected: bool,
+    /// Inside a (previewed) range, between the selected endpoints.
+    pub in_range: bool,
+    pub disabled: bool,
+}
+
+fn day_class(state: DayState, in_month: bool, is_today: bool, is_focused: bool) -> String {
+    let mut parts = vec!["button", "is-small", "is-fullwidth"];
+    if state.selected {
+        parts.push("is-primary");
+    } else if state.in_range {
+        parts.push("is-primary");
+        parts.push("is-light");
+    } else if is_today {
+        parts.push("is-link");
+        parts.push("is-light");
+    } else {
+        parts.push("is-white");
+    }
+    if !in_month && !state.selected && !state.in_range {
+        parts.push("has-text-grey-light");
+    }
+    if is_focused {
+        parts.push("is-focused");
+    }
+    parts.join(" ")
+}
+
+/// Month navigation plus a keyboard-navigable grid of days (ARIA grid pattern).
+#[component]
+pub(crate) fn MonthGrid(
+    /// Prefix for the day cell ids (`{id}-day-yyyy-MM-dd`).
+    id: String,
+    /// First day of the month being shown.
+    view_month: RwSignal<Date>,
+    /// Day with keyboard focus.
+    focused: RwSignal<Date>,
+    /// State of a day cell; read reactively.
+    day_state: Callback<Date, DayState>,
+    /// Called when a day is clicked or picked with Enter/Space.
+    on_pick: Callback<Date>,
+    #[prop(optional)] week_starts_sunday: bool,
+    /// Called with the day under the pointer or keyboard focus, `None` when leaving the grid.
+    #[prop(optional)]
+    on_hover: Option<Callback<Option<Date>>>,
+    /// Called when Escape is pressed on the grid.
+    #[prop(optional)]
+    on_escape: Option<Callback<()>>,
+    /// Reference to the grid element, used to move focus into it.
+    #[prop(optional)]
+    grid_ref: Option<NodeRef<html::Table>>,
+) -> impl IntoView {
+    let grid_ref = grid_ref.unwrap_or_default();
+    let today = Date::today();
+    let day_id = {
+        let id = id.clone();
+        move |date: Date| format!("{}-day-{}", id, date)
+    };
+    let hover = move |date: Option<Date>| {
+        if let Some(on_hover) = on_hover {
+            on_hover.run(date);
+        }
+    };
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        let key = ev.key();
+        match key.as_str() {
+            "Escape" => {
+                if let Some(on_escape) = on_escape {
+                    ev.prevent_default();
+                    on_escape.run(());
+                }
+            }
+            "Enter" | " " => {
+                ev.prevent_default();
+                on_pick.run(focused.get_untracked());
+            }
+            _ => {
+                if let Some(target) = keyboard_target(
+                    &key,
+                    ev.shift_key(),
+                    focused.get_untracked(),
+                    week_starts_sunday,
+                ) {
+                    ev.prevent_default();
+                    focused.set(target);
+                    view_month.set(target.first_of_month());
+                    hover(Some(target));
+                }
+            }
+        }
+    };
+
+    let step_month = move |months: i32| {
+        let target = focused.get_untracked().add_months(months);
+        focused.set(target);
+        view_month.set(target.first_of_month());
+    };
+
+    let weekday_headers = (0..7)
+        .map(|index| {
+            let (short, long) = WEEKDAYS[(index + usize::from(week_starts_sunday) * 6) % 7];
+            view! {
+                <th class="has-text-centered" scope="col" abbr=long>
+                    {short}
+                </th>
+            }
+        })
+        .collect_view();
+
+    let rows = {
+        let day_id = day_id.clone();
+        move || {
+            let month = view_month.get();
+            let focus = focused.get();
+            month_grid(month, week_starts_sunday)
+                .chunks(7)
+                .map(|week| {
+                    let cells = week
+                        .iter()
+                        .map(|&date| {
+                            let state = day_state.run(date);
+                            let class = day_class(
+                                state,
+                                date.month() == month.month(),
+                                date == today,
+                                date == focus,
+                            );
+                            view! {
+                                <td
+                                    class="p-0"
+                                    role="gridcell"
+                                    aria-selected=state.selected.to_string()
+                                >
+                                    <button
+                                        r#type="button"
+                                        tabindex="-1"
+                                        id=day_id(date)
+                                        class=class
+                                        disabled=state.disabled
+                                        aria-label=date.to_string()
+                                        data-date=date.to_string()
+                                        on:mousedown=move |ev: MouseEvent| ev.prevent_default()
+                                        on:mouseenter=move |_| hover(Some(date))
+                                        on:click=move |_| {
+                                            focused.set(date);
+                                            on_pick.run(date);
+                                        }
+                                    >
+                                        {date.day()}
+                                    </button>
+                                </td>
+                            }
+                        })
+                        .collect_view();
+                    view! { <tr>{cells}</tr> }
+                })
+                .collect_view()
+        }
+    };
+
+    view! {
+        <div class="date-picker-calendar">
+            <div class="is-flex is-justify-content-space-between is-align-items-center mb-2">
+                <button
+                    r#type="button"
+                    class="button is-small is-white"
+                    aria-label="Previous month"
+                    on:mousedown=move |ev: MouseEvent| ev.prevent_default()
+                    on:click=move |_| step_month(-1)
+                >
+                    "‹"
+                </button>
+                <strong aria-live="polite">{move || month_title(view_month.get())}</strong>
+                <button
+                    r#type="button"
+                    class="button is-small is-white"
+                    aria-label="Next month"
+                    on:mousedown=move |ev: MouseEvent| ev.prevent_default()
+                    on:click=move |_| step_month(1)
+                >
+                    "›"
+                </button>
+            </div>
+            <table
+                node_ref=grid_ref
+                class="table is-narrow is-fullwidth date-picker-grid"
+                role="grid"
+                tabindex="0"
+                aria-activedescendant=move || day_id(focused.get())
+                on:keydown=on_keydown
+                on:mouseleave=move |_| hover(None)
+            >
+                <thead>
+                    <tr>{weekday_headers}</tr>
+                </thead>
+                <tbody>{rows}</tbody>
+            </table>
+        </div>
+    }
+}
+
+/// Constraints shared by the pickers: a day is blocked when outside `min..=max`, listed in
+/// `disabled_dates` or rejected by `is_disabled`.
+#[derive(Clone, Copy)]
+pub(crate) struct DateBounds {
+    pub min: Signal<Option<Date>>,
+    pub max: Signal<Option<Date>>,
+    pub disabled_dates: Signal<Vec<Date>>,
+    pub is_disabled: Option<Callback<Date, bool>>,
+}
+
+impl DateBounds {
+    pub fn blocks(&self, date: Date) -> bool {
+        self.min.get().is_some_and(|min| date < min)
+            || self.max.get().is_some_and(|max| date > max)
+            || self.disabled_dates.with(|dates| dates.contains(&date))
+            || self.is_disabled.is_some_and(|check| check.run(date))
+    }
+}
+
+/// Whether a focusout event moves focus outside of `root`.
+pub(crate) fn focus_leaves(root: NodeRef<html::Div>, ev: &FocusEvent) -> bool {
+    !ev.related_target()
+        .and_then(|target| target.dyn_into::<leptos::web_sys::Node>().ok())
+        .is_some_and(|node| {
+            root.get_untracked()
+                .is_some_and(|root| root.contains(Some(&node)))
+        })
+}
+
+/// Moves focus back to the text input inside `root`.
+pub(crate) fn focus_input(root: NodeRef<html::Div>) {
+    if let Some(root) = root.get_untracked()
+        && let Ok(Some(input)) = root.query_selector("input")
+        && let Ok(input) = input.dyn_into::<leptos::web_sys::HtmlElement>()
+    {
+        let _ = input.focus();
+    }
+}
+
+/// Moves focus into the month grid once the dropdown has been shown.
+pub(crate) fn focus_grid(grid: NodeRef<html::Table>) {
+    leptos::prelude::request_animation_frame(move || {
+        if let Some(grid) = grid.get_untracked() {
+            let _ = grid.focus();
+        }
+    });
+}
+
+/// Bulma "input" plus "is-danger" for unparseable text and any extra classes.
+pub(crate) fn picker_input_class(invalid: bool, extra: &str) -> String {
+    let mut parts = vec!["input"];
+    if invalid {
+        parts.push("is-danger");
+    }
+    if !extra.trim().is_empty() {
+        parts.push(extra);
+    }
+    parts.join(" ")
+}
+
+/// Counter for generated picker ids, kept on the root owner of the render tree.
+#[derive(Clone)]
+struct PickerIds(Arc<AtomicUsize>);
+
+/// The id counter of the current render tree, created on its root owner on first use.
+///
+/// Each tree (a server render, the hydrating client) counts from 0 and renders components in
+/// the same order, so generated ids agree between the server HTML and hydration.
+fn picker_ids() -> Option<PickerIds> {
+    if let Some(ids) = use_context::<PickerIds>() {
+        return Some(ids);
+    }
+    let mut root = Owner::current()?;
+    while let Some(parent) = root.parent() {
+        root = parent;
+    }
+    let ids = PickerIds(Arc::new(AtomicUsize::new(0)));
+    root.with(|| provide_context(ids.clone()));
+    Some(ids)
+}
+
+/// Counter for generated ids when there is no reactive owner, so ids stay unique anyway.
+static UNOWNED_PICKER_IDS: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns `id` or an id generated from the render tree's counter with the given prefix.
+///
+/// Without a reactive owner there is no render tree to count in; the id then comes from a
+/// process-wide counter (`{prefix}-u{n}`), which is unique but not hydration-safe.
+pub(crate) fn picker_id(id: Option<String>, prefix: &str) -> String {
+    id.unwrap_or_else(|| match picker_ids() {
+        Some(ids) => format!("{}-{}", prefix, ids.0.fetch_add(1, Ordering::Relaxed)),
+        None => format!(
+            "{}-u{}",
+            prefix,
+            UNOWNED_PICKER_IDS.fetch_add(1, Ordering::Relaxed)
+        ),
+    })
+}
+
+/// A date picker with a month grid.
+///
+/// Controlled component: `value` is the selected date and `update` receives picks, typed
+/// dates and `None` on clear. Provide `update_time` to also edit a time of day.
+#[component]
+pub fn DatePicker(
+    /// The selected date.
+    #[prop(into)]
+    value: Signal<Option<Date>>,
+
+    /// Called with the new date, or `None` when cleared.
+    update: Callback<Option<Date>>,
+
+    /// DOM id of the text input; also prefixes the day cell ids. Generated when omitted, counting
+    /// up per render tree so server and hydration agree; pass one for ids that must not depend
+    /// on render order.
+    #[prop(optional, into)]
+    id: Option<String>,
+
+    /// Earliest selectable date.
+    #[prop(optional, into)]
+    min: Signal<Option<Date>>,
+
+    /// Latest selectable date.
+    #[prop(optional, into)]
+    max: Signal<Option<Date>>,
+
+    /// Individual dates that cannot be picked.
+    #[prop(optional, into)]
+    disabled_dates: Signal<Vec<Date>>,
+
+    /// Predicate for dates that cannot be picked (e.g. weekends).
+    #[prop(optional)]
+    is_disabled: Option<Callback<Date, bool>>,
+
+    /// The selected time of day (with `update_time`).
+    #[prop(optional, into)]
+    time: Signal<Option<Time>>,
+
+    /// Enables the time input; called with the new time.
+    #[prop(optional)]
+    update_time: Option<Callback<Option<Time>>>,
+
+    /// Display/parse pattern for the input. Defaults to "yyyy-MM-dd" (plus " HH:mm" with time).
+    #[prop(optional, into)]
+    format: Signal<String>,
+
+    /// Render the calendar permanently instead of as a dropdown.
+    #[prop(optional)]
+    inline: bool,
+
+    /// Start weeks on Sunday instead of Monday.
+    #[prop(optional)]
+    week_starts_sunday: bool,
+
+    /// Placeholder for the input.
+    #[prop(optional, into)]
+    placeholder: Signal<String>,
+
+    /// Extra classes appended after Bulma "input".
+    #[prop(optional, into)]
+    classes: Signal<String>,
+
+    /// Disable the input.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Optional test attribute (renders as data-* attribute) on the input (or the inline calendar).
+    ///
+    /// When provided as a &str or String, this becomes `data-testid="value"`.
+    /// You can also pass a full `TestAttr` to override the attribute key.
+    #[prop(optional, into)]
+    test_attr: Option<TestAttr>,
+) -> impl IntoView {
+    let id = picker_id(id, "lbc-date-picker");
+    let with_time = update_time.is_some();
+    let pattern = move || {
+        let pattern = format.get();
+        if !pattern.trim().is_empty() {
+            pattern
+        } else if with_time {
+            "yyyy-MM-dd HH:mm".to_string()
+        } else {
+            "yyyy-MM-dd".to_string()
+        }
+    };
+
+    let bounds = DateBounds {
+        min,
+        max,
+        disabled_dates,
+        is_disabled,
+    };
+    let is_blocked = move |date: Date| bounds.blocks(date);
+
+    let start = value.get_untracked().unwrap_or_else(Date::today);
+    let focused = RwSignal::new(start);
+    let view_month = RwSignal::new(start.first_of_month());
+    let open = RwSignal::new(inline);
+    let invalid = RwSignal::new(false);
+    let root_ref: NodeRef<html::Div> = NodeRef::new();
+    let grid_ref: NodeRef<html::Table> = NodeRef::new();
+
+    // Follow external value changes.
+    Effect::new(move |_| {
+        if let Some(date) = value.get() {
+            focused.set(date);
+            view_month.set(date.first_of_month());
+        }
+    });
+
+    let close = move || {
+        if !inline {
+            open.set(false);
+        }
+    };
+
+    let pick = Callback::new(move |date: Date| {
+        if is_blocked(date) {
+            return;
+        }
+        invalid.set(false);
+        update.run(Some(date));
+        if !with_time {
+            close();
+        }
+    });
+
+    let day_state = Callback::new(move |date: Date| DayState {
+        selected: value.get() == Some(date),
+        disabled: is_blocked(date),
+        ..DayState::default()
+    });
+
+    // Without a time yet, drop the separators the empty time tokens leave behind.
+    let display = move || {
+        value
+            .get()
+            .map(|date| match time.get() {
+                Some(time) => format_parts(&pattern(), Some(date), Some(time)),
+                None => format_parts(&pattern(), Some(date), None)
+                    .trim_matches(|c: char| !c.is_alphanumeric())
+                    .to_string(),
+            })
+            .unwrap_or_default()
+    };
+    let initial_display = display();
+
+    let on_change = move |ev| {
+        let text = event_target_value(&ev);
+        if text.trim().is_empty() {
+            invalid.set(false);
+            update.run(None);
+            if let Some(update_time) = update_time {
+                update_time.run(None);
+            }
+            return;
+        }
+        match parse_parts(&text, &pattern()) {
+            Some((Some(date), parsed_time)) if !is_blocked(date) => {
+                invalid.set(false);
+                update.run(Some(date));
+                if let (Some(update_time), Some(parsed_time)) = (update_time, parsed_time) {
+                    update_time.run(Some(parsed_time));
+                }
+            }
+            _ => invalid.set(true),
+        }
+    };
+
+    let on_input_keydown = move |ev: KeyboardEvent| match ev.key().as_str() {
+        "ArrowDown" => {
+            ev.prevent_default();
+            open.set(true);
+            focus_grid(grid_ref);
+        }
+        "Escape" => close(),
+        _ => {}
+    };
+
+    // Close when focus leaves the whole picker.
+    let on_focusout = move |ev: FocusEvent| {
+        if !inline && focus_leaves(root_ref, &ev) {
+            open.set(false);
+        }
+    };
+
+    let on_escape = Callback::new(move |_: ()| {
+        close();
+        if !inline {
+            focus_input(root_ref);
+        }
+    });
+
+    let input_class = move || picker_input_class(invalid.get(), &classes.get());
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    let time_input = update_time.map(|update_time| {
+        view! {
+            <input
+                class="input is-small date-picker-time"
+                r#type="time"
+                aria-label="Time"
+                value=time.get_untracked().map(|t| t.to_string()).unwrap_or_default()
+                prop:value=move || time.get().map(|t| t.to_string()).unwrap_or_default()
+                on:change=move |ev| {
+                    update_time.run(event_target_value(&ev).parse::<Time>().ok());
+                }
+            />
+        }
+    });
+
+    let panel = view! {
+        <MonthGrid
+            id=id.clone()
+            view_month=view_month
+            focused=focused
+            day_state=day_state
+            on_pick=pick
+            week_starts_sunday=week_starts_sunday
+            on_escape=on_escape
+            grid_ref=grid_ref
+        />
+        <div class="is-flex is-justify-content-space-between is-align-items-center mt-2">
+            {time_input}
+            <div class="buttons mb-0 ml-auto">
+                <button
+                    r#type="button"
+                    class="button is-small is-text"
+                    on:mousedown=move |ev: MouseEvent| ev.prevent_default()
+                    on:click=move |_| pick.run(Date::today())
+                >
+                    "Today"
+                </button>
+                <button
+                    r#type="button"
+                    class="button is-small is-text"
+                    on:mousedown=move |ev: MouseEvent| ev.prevent_default()
+                    on:click=move |_| {
+                        update.run(None);
+                        close();
+                    }
+                >
+                    "Clear"
+                </button>
+            </div>
+        </div>
+    };
+
+    if inline {
+        view! {
+            <div
+                class="date-picker is-inline box"
+                attr:data-testid=data_testid
+                attr:data-cy=data_cy
+            >
+                {panel}
+            </div>
+        }
+        .into_any()
+    } else {
+        view! {
+            <div
+                node_ref=root_ref
+                class=move || {
+                    if open.get() { "dropdown date-picker is-active" } else { "dropdown date-picker" }
+                }
+                on:focusout=on_focusout
+            >
+                <div class="dropdown-trigger">
+                    <input
+                        id=id.clone()
+                        class=input_class
+                        r#type="text"
+                        autocomplete="off"
+                        aria-haspopup="dialog"
+                        aria-expanded=move || open.get().to_string()
+                        placeholder=move || placeholder.get()
+                        disabled=move || disabled.get()
+                        value=initial_display
+                        prop:value=display
+                        on:click=move |_| open.set(!open.get_untracked())
+                        on:keydown=on_input_keydown
+                        on:change=on_change
+                        attr:data-testid=data_testid
+                        attr:data-cy=data_cy
+                    />
+                </div>
+                <div class="dropdown-menu" role="dialog" aria-label="Choose date">
+                    <div class="dropdown-content">
+                        <div class="dropdown-item">{panel}</div>
+                    </div>
+                </div>
+            </div>
+        }
+        .into_any()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::prelude::RenderHtml;
+
+    #[test]
+    fn generated_ids_count_per_render_tree() {
+        let ids = || {
+            Owner::new().with(|| {
+                let first = picker_id(None, "p");
+                let child = Owner::current().unwrap().child();
+                let nested = child.with(|| picker_id(None, "p"));
+                (first, nested, picker_id(Some("mine".into()), "p"))
+            })
+        };
+        let first = ids();
+        assert_eq!(
+            first,
+            ("p-0".to_string(), "p-1".to_string(), "mine".to_string())
+        );
+        assert_eq!(ids(), first, "a new tree (e.g. hydration) repeats the ids");
+    }
+
+    #[test]
+    fn generated_ids_stay_unique_without_an_owner() {
+        assert!(Owner::current().is_none());
+        let (first, second) = (picker_id(None, "p"), picker_id(None, "p"));
+        assert!(first.starts_with("p-u"), "got {}", first);
+        assert_ne!(first, second);
+    }
+
+    fn date(year: i32, month: u8, day: u8) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn date_validation_and_leap_years() {
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert!(Date::new(2023, 2, 29).is_none());
+        assert!(Date::new(1900, 2, 29).is_none());
+        assert!(Date::new(2000, 2, 29).is_some());
+        assert!(Date::new(2025, 13, 1).is_none());
+        assert!(Date::new(2025, 4, 31).is_none());
+    }
+
+    #[test]
+    fn date_arithmetic_round_trips() {
+        assert_eq!(date(2024, 12, 31).add_days(1), date(2025, 1, 1));
+        assert_eq!(date(2024, 3, 1).add_days(-1), date(2024, 2, 29));
+        assert_eq!(date(1970, 1, 1).to_days(), 0);
+        assert_eq!(Date::from_days(-1), date(1969, 12, 31));
+        assert_eq!(date(2025, 1, 31).add_months(1), date(2025, 2, 28));
+        assert_eq!(date(2025, 1, 15).add_months(-13), date(2023, 12, 15));
+        assert_eq!(date(2025, 1, 1).days_until(date(2025, 3, 1)), 59);
+        // 2025-01-01 was a Wednesday.
+        assert_eq!(date(2025, 1, 1).weekday(), 2);
+    }
+
+    #[test]
+    fn format_and_parse_patterns() {
+        let day = date(2025, 2, 3);
+        assert_eq!(day.to_string(), "2025-02-03");
+        assert_eq!(format_date(day, "dd.MM.yyyy"), "03.02.2025");
+        assert_eq!(format_date(day, "M/d/YYYY"), "2/3/2025");
+        assert_eq!(
+            format_parts("yyyy-MM-dd HH:mm", Some(day), Time::new(9, 5)),
+            "2025-02-03 09:05"
+        );
+
+        assert_eq!(parse_date("03.02.2025", "dd.MM.yyyy"), Some(day));
+        assert_eq!(parse_date("2025-02-30", "yyyy-MM-dd"), None);
+        assert_eq!(parse_date("2025-02-03x", "yyyy-MM-dd"), None);
+        assert_eq!(
+            parse_parts("2025-02-03 18:45", "yyyy-MM-dd HH:mm"),
+            Some((Some(day), Time::new(18, 45)))
+        );
+        assert_eq!("18:45".parse::<Time>(), Ok(Time::new(18, 45).unwrap()));
+        assert!("24:00".parse::<Time>().is_err());
+    }
+
+    #[test]
+    fn month_grid_starts_on_configured_weekday() {
+        let grid = month_grid(date(2025, 1, 15), false);
+        assert_eq!(grid.len(), 42);
+        assert_eq!(grid[0], date(2024, 12, 30), "Monday before Jan 1st");
+
+        let grid = month_grid(date(2025, 1, 15), true);
+        assert_eq!(grid[0], date(2024, 12, 29), "Sunday before Jan 1st");
+    }
+
+    #[test]
+    fn keyboard_targets() {
+        let day = date(2025, 1, 1);
+        assert_eq!(
+            keyboard_target("ArrowRight", false, day, false),
+            Some(date(2025, 1, 2))
+        );
+        assert_eq!(
+            keyboard_target("ArrowUp", false, day, false),
+            Some(date(2024, 12, 25))
+        );
+        assert_eq!(
+            keyboard_target("PageDown", true, day, false),
+            Some(date(2026, 1, 1))
+        );
+        assert_eq!(
+            keyboard_target("Home", false, day, false),
+            Some(date(2024, 12, 30))
+        );
+        assert_eq!(
+            keyboard_target("End", false, day, true),
+            Some(date(2025, 1, 4))
+        );
+        assert_eq!(keyboard_target("a", false, day, false), None);
+    }
+
+    #[test]
+    fn date_picker_renders_input_and_grid() {
+        let html = view! {
+            <DatePicker
+                id="dp"
+                value=Date::new(2025, 3, 14)
+                update=Callback::new(|_: Option<Date>| {})
+                classes="is-small"
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"id="dp""#) && html.contains(r#"value="2025-03-14""#),
+            "expected input with formatted value; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"class="input is-small""#),
+            "expected input classes; got: {}",
+            html
+        );
+        assert!(
+            html.contains("March 2025") && html.contains(r#"role="grid""#),
+            "expected month grid; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"aria-activedescendant="dp-day-2025-03-14""#),
+            "expected focused day; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn date_picker_marks_selected_and_disabled_days() {
+        let html = view! {
+            <DatePicker
+                id="dp2"
+                value=Date::new(2025, 3, 14)
+                update=Callback::new(|_: Option<Date>| {})
+                min=Date::new(2025, 3, 10)
+                disabled_dates=vec![date(2025, 3, 20)]
+                is_disabled=Callback::new(|d: Date| d.weekday() == 6)
+                inline=true
+            />
+        }
+        .to_html();
+
+        let cell = |day: &str| {
+            let marker = format!(r#"id="dp2-day-{}""#, day);
+            let start = html.find(&marker).expect("day cell");
+            let tag_start = html[..start].rfind("<button").unwrap();
+            html[tag_start..start + marker.len() + 200].to_string()
+        };
+        assert!(cell("2025-03-14").contains("is-primary"));
+        assert!(cell("2025-03-09").contains("disabled"), "before min");
+        assert!(cell("2025-03-20").contains("disabled"), "listed date");
+        assert!(cell("2025-03-16").contains("disabled"), "Sunday predicate");
+        assert!(!cell("2025-03-12").contains("disabled"));
+        assert!(
+            html.contains("date-picker is-inline"),
+            "expected inline calendar; got: {}",
+            html
+        );
+    }
+}
@@ -0,0 +1,589 @@
+/*!
+DateRangePicker component: pick a start/end pair on the native month grid.
+
+Summary
+- The first click sets the start, the second click the end; days in between are previewed
+  while hovering (or moving with the keyboard).
+- Endpoints are ordered automatically when picked on the grid; typed ranges with the start
+  after the end are rejected with a help message.
+- Preset buttons ("Last 7 days", "This month" by default) commit a range in one click.
+- Shares `min`/`max`, `disabled_dates` and `is_disabled` with [`DatePicker`](super::date_picker::DatePicker).
+*/
+
+use std::fmt;
+
+use leptos::callback::Callback;
+use leptos::ev::{FocusEvent, KeyboardEvent, MouseEvent};
+use leptos::html;
+use leptos::prelude::{
+    AriaAttributes, Callable, ClassAttribute, CollectView, CustomAttribute, Effect, ElementChild,
+    Get, GetUntracked, GlobalAttributes, IntoAny, IntoView, NodeRef, NodeRefAttribute, OnAttribute,
+    PropAttribute, RwSignal, Set, Signal, component, event_target_value, view,
+};
+
+use crate::components::date_picker::{
+    Date, DateBounds, DayState, MonthGrid, focus_grid, focus_input, focus_leaves, format_date,
+    parse_date, picker_id, picker_input_class,
+};
+use crate::util::TestAttr;
+
+/// Separator between the start and end date in the text input.
+pub const RANGE_SEPARATOR: &str = " - ";
+
+/// An inclusive range of days with `start <= end`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    start: Date,
+    end: Date,
+}
+
+impl DateRange {
+    /// Returns `None` when `start` is after `end`.
+    pub fn new(start: Date, end: Date) -> Option<DateRange> {
+        (start <= end).then_some(DateRange { start, end })
+    }
+
+    /// The range spanning both days, in whichever order they are given.
+    pub fn between(a: Date, b: Date) -> DateRange {
+        DateRange {
+            start: a.min(b),
+            end: a.max(b),
+        }
+    }
+
+    pub fn start(&self) -> Date {
+        self.start
+    }
+
+    pub fn end(&self) -> Date {
+        self.end
+    }
+
+    pub fn contains(&self, date: Date) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    /// Number of days in the range, counting both endpoints.
+    pub fn days(&self) -> i64 {
+        self.start.days_until(self.end) + 1
+    }
+}
+
+impl fmt::Display for DateRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.start, RANGE_SEPARATOR, self.end)
+    }
+}
+
+/// Why typed range text was rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RangeError {
+    /// The text is not two dates in the expected format.
+    Invalid(String),
+    /// Both dates parse, but the start is after the end.
+    StartAfterEnd,
+    /// One of the endpoints is outside `min`/`max` or disabled.
+    Unavailable(Date),
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::Invalid(pattern) => write!(
+                f,
+                "Enter a range as {}{}{}",
+                pattern, RANGE_SEPARATOR, pattern
+            ),
+            RangeError::StartAfterEnd => write!(f, "The start date must not be after the end date"),
+            RangeError::Unavailable(date) => write!(f, "{} is not available", date),
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+pub fn format_range(range: DateRange, pattern: &str) -> String {
+    format!(
+        "{}{}{}",
+        format_date(range.start, pattern),
+        RANGE_SEPARATOR,
+        format_date(range.end, pattern)
+    )
+}
+
+/// Parses "start - end" with `pattern` for both dates.
+pub fn parse_range(text: &str, pattern: &str) -> Result<DateRange, RangeError> {
+    let invalid = || RangeError::Invalid(pattern.to_string());
+    let (start, end) = text.split_once(RANGE_SEPARATOR).ok_or_else(invalid)?;
+    let start = parse_date(start, pattern).ok_or_else(invalid)?;
+    let end = parse_date(end, pattern).ok_or_else(invalid)?;
+    DateRange::new(start, end).ok_or(RangeError::StartAfterEnd)
+}
+
+/// A named shortcut range shown as a button next to the grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangePreset {
+    pub label: String,
+    pub range: DateRange,
+}
+
+impl RangePreset {
+    pub fn new(label: impl Into<String>, range: DateRange) -> Self {
+        Self {
+            label: label.into(),
+            range,
+        }
+    }
+
+    /// The last `days` days, ending with `today`.
+    pub fn last_days(days: u32, today: Date) -> Self {
+        let start = today.add_days(1 - i64::from(days.max(1)));
+        Self::new(
+            format!("Last {} days", days),
+            DateRange::between(start, today),
+        )
+    }
+
+    /// From the first of the current month up to `today`.
+    pub fn this_month(today: Date) -> Self {
+        Self::new(
+            "This month",
+            DateRange::between(today.first_of_month(), today),
+        )
+    }
+
+    /// "Last 7 days" and "This month".
+    pub fn defaults(today: Date) -> Vec<Self> {
+        vec![Self::last_days(7, today), Self::this_month(today)]
+    }
+}
+
+/// A date range picker with hover preview and preset ranges.
+///
+/// Controlled component: `value` is the selected range and `update` receives completed ranges
+/// (never a half-picked one) and `None` on clear.
+#[component]
+pub fn DateRangePicker(
+    /// The selected range.
+    #[prop(into)]
+    value: Signal<Option<DateRange>>,
+
+    /// Called with the new range, or `None` when cleared.
+    update: Callback<Option<DateRange>>,
+
+    /// DOM id of the text input; also prefixes the day cell ids. Generated when omitted, like
+    /// `DatePicker`'s.
+    #[prop(optional, into)]
+    id: Option<String>,
+
+    /// Earliest selectable date.
+    #[prop(optional, into)]
+    min: Signal<Option<Date>>,
+
+    /// Latest selectable date.
+    #[prop(optional, into)]
+    max: Signal<Option<Date>>,
+
+    /// Individual dates that cannot be picked as an endpoint.
+    #[prop(optional, into)]
+    disabled_dates: Signal<Vec<Date>>,
+
+    /// Predicate for dates that cannot be picked as an endpoint.
+    #[prop(optional)]
+    is_disabled: Option<Callback<Date, bool>>,
+
+    /// Preset buttons. Defaults to [`RangePreset::defaults`]; pass an empty list to hide them.
+    #[prop(optional)]
+    presets: Option<Vec<RangePreset>>,
+
+    /// Display/parse pattern for each date in the input. Defaults to "yyyy-MM-dd".
+    #[prop(optional, into)]
+    format: Signal<String>,
+
+    /// Render the calendar permanently instead of as a dropdown.
+    #[prop(optional)]
+    inline: bool,
+
+    /// Start weeks on Sunday instead of Monday.
+    #[prop(optional)]
+    week_starts_sunday: bool,
+
+    /// Placeholder for the input.
+    #[prop(optional, into)]
+    placeholder: Signal<String>,
+
+    /// Extra classes appended after Bulma "input".
+    #[prop(optional, into)]
+    classes: Signal<String>,
+
+    /// Disable the input.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Optional test attribute (renders as data-* attribute) on the input (or the inline calendar).
+    ///
+    /// When provided as a &str or String, this becomes `data-testid="value"`.
+    /// You can also pass a full `TestAttr` to override the attribute key.
+    #[prop(optional, into)]
+    test_attr: Option<TestAttr>,
+) -> impl IntoView {
+    let id = picker_id(id, "lbc-date-range-picker");
+    let pattern = move || {
+        let pattern = format.get();
+        if pattern.trim().is_empty() {
+            "yyyy-MM-dd".to_string()
+        } else {
+            pattern
+        }
+    };
+    let bounds = DateBounds {
+        min,
+        max,
+        disabled_dates,
+        is_disabled,
+    };
+    let presets = presets.unwrap_or_else(|| RangePreset::defaults(Date::today()));
+
+    let start = value
+        .get_untracked()
+        .map(|range| range.start())
+        .unwrap_or_else(Date::today);
+    let focused = RwSignal::new(start);
+    let view_month = RwSignal::new(start.first_of_month());
+    let open = RwSignal::new(inline);
+    // First endpoint of a range being picked, and the day currently previewed as the second.
+    let anchor = RwSignal::new(None::<Date>);
+    let hover = RwSignal::new(None::<Date>);
+    let error = RwSignal::new(None::<RangeError>);
+    let root_ref: NodeRef<html::Div> = NodeRef::new();
+    let grid_ref: NodeRef<html::Table> = NodeRef::new();
+
+    // Follow external value changes.
+    Effect::new(move |_| {
+        if let Some(range) = value.get() {
+            focused.set(range.start());
+            view_month.set(range.start().first_of_month());
+        }
+    });
+
+    let close = move || {
+        if !inline {
+            open.set(false);
+        }
+    };
+
+    let commit = move |range: Option<DateRange>| {
+        anchor.set(None);
+        hover.set(None);
+        error.set(None);
+        update.run(range);
+        close();
+    };
+
+    let pick = Callback::new(move |date: Date| {
+        if bounds.blocks(date) {
+            return;
+        }
+        match anchor.get_untracked() {
+            None => anchor.set(Some(date)),
+            Some(start) => commit(Some(DateRange::between(start, date))),
+        }
+    });
+
+    let preview = move || match (anchor.get(), hover.get()) {
+        (Some(start), Some(end)) => Some(DateRange::between(start, end)),
+        (Some(start), None) => Some(DateRange::between(start, start)),
+        _ => value.get(),
+    };
+
+    let day_state = Callback::new(move |date: Date| {
+        let range = preview();
+        DayState {
+            selected: range.is_some_and(|r| date == r.start() || date == r.end()),
+            in_range: range.is_some_and(|r| r.contains(date)),
+            disabled: bounds.blocks(date),
+        }
+    });
+
+    let display = move || {
+        value
+            .get()
+            .map(|range| format_range(range, &pattern()))
+            .unwrap_or_default()
+    };
+    let initial_display = display();
+
+    let on_change = move |ev| {
+        let text = event_target_value(&ev);
+        if text.trim().is_empty() {
+            commit(None);
+            return;
+        }
+        let parsed = parse_range(&text, &pattern()).and_then(|range| {
+            [range.start(), range.end()]
+                .into_iter()
+                .find(|date| bounds.blocks(*date))
+                .map_or(Ok(range), |date| Err(RangeError::Unavailable(date)))
+        });
+        match parsed {
+            Ok(range) => commit(Some(range)),
+            Err(err) => error.set(Some(err)),
+        }
+    };
+
+    let on_input_keydown = move |ev: KeyboardEvent| match ev.key().as_str() {
+        "ArrowDown" => {
+            ev.prevent_default();
+            open.set(true);
+            focus_grid(grid_ref);
+        }
+        "Escape" => close(),
+        _ => {}
+    };
+
+    // Close (and drop a half-picked range) when focus leaves the whole picker.
+    let on_focusout = move |ev: FocusEvent| {
+        if !inline && focus_leaves(root_ref, &ev) {
+            anchor.set(None);
+            hover.set(None);
+            open.set(false);
+        }
+    };
+
+    let on_escape = Callback::new(move |_: ()| {
+        if anchor.get_untracked().is_some() {
+            anchor.set(None);
+            hover.set(None);
+            return;
+        }
+        close();
+        if !inline {
+            focus_input(root_ref);
+        }
+    });
+
+    let input_class = move || picker_input_class(error.get().is_some(), &classes.get());
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    let preset_buttons = presets
+        .into_iter()
+        .map(|preset| {
+            let range = preset.range;
+            view! {
+                <button
+                    r#type="button"
+                    class="button is-small is-light"
+                    disabled=move || bounds.blocks(range.start()) || bounds.blocks(range.end())
+                    on:mousedown=move |ev: MouseEvent| ev.prevent_default()
+                    on:click=move |_| {
+                        focused.set(range.end());
+                        view_month.set(range.end().first_of_month());
+                        commit(Some(range));
+                    }
+                >
+                    {preset.label}
+                </button>
+            }
+        })
+        .collect_view();
+
+    let panel = view! {
+        <MonthGrid
+            id=id.clone()
+            view_month=view_month
+            focused=focused
+            day_state=day_state
+            on_pick=pick
+            week_starts_sunday=week_starts_sunday
+            on_hover=Callback::new(move |date: Option<Date>| hover.set(date))
+            on_escape=on_escape
+            grid_ref=grid_ref
+        />
+        <div class="is-flex is-justify-content-space-between is-align-items-center mt-2">
+            <div class="buttons mb-0 date-range-presets">{preset_buttons}</div>
+            <button
+                r#type="button"
+                class="button is-small is-text ml-auto"
+                on:mousedown=move |ev: MouseEvent| ev.prevent_default()
+                on:click=move |_| commit(None)
+            >
+                "Clear"
+            </button>
+        </div>
+    };
+
+    let help = move || {
+        error
+            .get()
+            .map(|err| view! { <p class="help is-danger">{err.to_string()}</p> })
+    };
+
+    if inline {
+        view! {
+            <div
+                class="date-picker date-range-picker is-inline box"
+                attr:data-testid=data_testid
+                attr:data-cy=data_cy
+            >
+                {panel}
+            </div>
+        }
+        .into_any()
+    } else {
+        view! {
+            <div
+                node_ref=root_ref
+                class=move || {
+                    if open.get() {
+                        "dropdown date-picker date-range-picker is-active"
+                    } else {
+                        "dropdown date-picker date-range-picker"
+                    }
+                }
+                on:focusout=on_focusout
+            >
+                <div class="dropdown-trigger">
+                    <input
+                        id=id.clone()
+                        class=input_class
+                        r#type="text"
+                        autocomplete="off"
+                        aria-haspopup="dialog"
+                        aria-expanded=move || open.get().to_string()
+                        placeholder=move || placeholder.get()
+                        disabled=move || disabled.get()
+                        value=initial_display
+                        prop:value=display
+                        on:click=move |_| open.set(!open.get_untracked())
+                        on:keydown=on_input_keydown
+                        on:change=on_change
+                        attr:data-testid=data_testid
+                        attr:data-cy=data_cy
+                    />
+                    {help}
+                </div>
+                <div class="dropdown-menu" role="dialog" aria-label="Choose date range">
+                    <div class="dropdown-content">
+                        <div class="dropdown-item">{panel}</div>
+                    </div>
+                </div>
+            </div>
+        }
+        .into_any()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::prelude::RenderHtml;
+
+    fn date(year: i32, month: u8, day: u8) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn date_range_orders_and_counts() {
+        assert_eq!(DateRange::new(date(2025, 3, 2), date(2025, 3, 1)), None);
+        let range = DateRange::between(date(2025, 3, 10), date(2025, 3, 1));
+        assert_eq!(range.start(), date(2025, 3, 1));
+        assert_eq!(range.end(), date(2025, 3, 10));
+        assert_eq!(range.days(), 10);
+        assert!(range.contains(date(2025, 3, 5)));
+        assert!(!range.contains(date(2025, 3, 11)));
+        assert_eq!(range.to_string(), "2025-03-01 - 2025-03-10");
+    }
+
+    #[test]
+    fn parse_range_checks_order_and_format() {
+        assert_eq!(
+            parse_range("01.03.2025 - 10.03.2025", "dd.MM.yyyy"),
+            Ok(DateRange::between(date(2025, 3, 1), date(2025, 3, 10)))
+        );
+        assert_eq!(
+            parse_range("2025-03-10 - 2025-03-01", "yyyy-MM-dd"),
+            Err(RangeError::StartAfterEnd)
+        );
+        assert_eq!(
+            parse_range("2025-03-10", "yyyy-MM-dd"),
+            Err(RangeError::Invalid("yyyy-MM-dd".to_string()))
+        );
+    }
+
+    #[test]
+    fn presets_end_today() {
+        let today = date(2025, 3, 5);
+        let last_week = RangePreset::last_days(7, today);
+        assert_eq!(last_week.label, "Last 7 days");
+        assert_eq!(
+            last_week.range,
+            DateRange::between(date(2025, 2, 27), today)
+        );
+        assert_eq!(last_week.range.days(), 7);
+        assert_eq!(
+            RangePreset::this_month(today).range,
+            DateRange::between(date(2025, 3, 1), today)
+        );
+    }
+
+    #[test]
+    fn range_picker_renders_value_range_and_presets() {
+        let html = view! {
+            <DateRangePicker
+                id="report"
+                value=DateRange::new(date(2025, 3, 3), date(2025, 3, 5))
+                update=Callback::new(|_: Option<DateRange>| {})
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"value="2025-03-03 - 2025-03-05""#),
+            "expected formatted range; got: {}",
+            html
+        );
+        let cell = |day: &str| {
+            let marker = format!(r#"id="report-day-{}""#, day);
+            let start = html.find(&marker).expect("day cell");
+            let tag_start = html[..start].rfind("<button").unwrap();
+            html[tag_start..start + marker.len() + 200].to_string()
+        };
+        assert!(cell("2025-03-03").contains("is-primary is-focused"));
+        assert!(cell("2025-03-04").contains("is-primary is-light"));
+        assert!(!cell("2025-03-06").contains("is-primary"));
+        assert!(
+            html.contains("Last 7 days") && html.contains("This month"),
+            "expected default presets; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn range_picker_custom_presets() {
+        let quarter = RangePreset::new(
+            "Q1",
+            DateRange::between(date(2025, 1, 1), date(2025, 3, 31)),
+        );
+        let empty: Option<DateRange> = None;
+        let html = view! {
+            <DateRangePicker
+                value=empty
+                update=Callback::new(|_: Option<DateRange>| {})
+                presets=vec![quarter]
+                inline=true
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(">Q1<") && !html.contains("Last 7 days"),
+            "expected only the custom preset; got: {}",
+            html
+        );
+    }
+}
@@ -2,6 +2,8 @@ pub mod accordion;
 pub mod breadcrumb;
 pub mod calendar;
 pub mod card;
+pub mod date_picker;
+pub mod date_range_picker;
 pub mod dropdown;
 pub mod menu;
 pub mod message;
@@ -9,12 +11,15 @@ pub mod modal;
 pub mod navbar;
 pub mod pagination;
 pub mod panel;
+pub mod steps;
 pub mod tabs;
 
 pub use accordion::{AccordionItem, Accordions};
 pub use breadcrumb::{Breadcrumb, BreadcrumbSeparator, BreadcrumbSize};
 pub use calendar::Calendar;
 pub use card::{Card, CardContent, CardFooter, CardHeader, CardImage};
+pub use date_picker::{Date, DatePicker, Time};
+pub use date_range_picker::{DateRange, DateRangePicker, RangeError, RangePreset};
 pub use dropdown::Dropdown;
 pub use menu::{Menu, MenuLabel, MenuList};
 pub use message::{Message, MessageBody, MessageHeader};
@@ -24,4 +29,5 @@ pub use navbar::{
 };
 pub use pagination::{Pagination, PaginationEllipsis, PaginationItem, PaginationItemType};
 pub use panel::{Panel, PanelBlock, PanelTabs};
+pub use steps::{Step, Steps, Wizard, WizardStep};
 pub use tabs::{Alignment, Tabs};
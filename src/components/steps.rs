@@ -0,0 +1,763 @@
+/*!
+Steps indicator and multi-step `Wizard`, using the bulma-steps markup.
+
+Required static assets
+- Add the bulma-steps CSS into your HTML <head>:
+  <link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/bulma-steps@2.2.1/dist/css/bulma-steps.min.css"/>
+
+`Steps` only renders the indicator. `Wizard` pairs it with the content of each step, Back / Skip /
+Next / Finish buttons and a `FormContext` shared by all steps:
+
+```ignore
+let form = use_form();
+let (email, set_email) = form.bind("email");
+let steps = vec![
+    WizardStep::new("Account", move || view! {
+        <Field label="Email" name="email" validators=vec![required(), email()]>
+            <Control><Input name="email" value=email update=set_email /></Control>
+        </Field>
+    })
+    .with_fields(["email"]),
+    WizardStep::new("Newsletter", || view! { ... }).with_optional(true),
+    WizardStep::new("Confirm", || view! { ... }),
+];
+view! { <Wizard steps=steps form=form on_finish=Callback::new(|values: FormValues| save(values)) /> }
+```
+
+Notes
+- Next (or Enter in a field) validates the fields listed with `with_fields`; when one fails, the
+  wizard stays on the step and each `Field` shows its error.
+- Optional steps can be skipped without validation.
+- Completed steps can be revisited by clicking them in the indicator.
+- All steps stay mounted (inactive ones are hidden), so their state survives navigation.
+*/
+
+use std::collections::BTreeSet;
+
+use leptos::callback::{Callable, Callback};
+use leptos::prelude::{
+    AriaAttributes, ClassAttribute, CollectView, CustomAttribute, ElementChild, Get, GetUntracked,
+    GlobalAttributes, IntoView, OnAttribute, RwSignal, Set, Signal, StoredValue, Update, ViewFn,
+    WithValue, component, provide_context, untrack, view,
+};
+
+use crate::elements::button::{Button, ButtonColor};
+use crate::form::context::{FormContext, FormValues};
+use crate::util::{Size, TestAttr};
+
+/// One entry of a [`Steps`] indicator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub title: String,
+    pub description: Option<String>,
+    /// Marked "Optional"; a [`Wizard`] lets the user skip it.
+    pub optional: bool,
+}
+
+impl Step {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            description: None,
+            optional: false,
+        }
+    }
+
+    /// Adds a line of text under the title.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Marks this step as optional.
+    pub fn with_optional(mut self, optional: bool) -> Self {
+        self.optional = optional;
+        self
+    }
+}
+
+/// A progress indicator for multi-step flows.
+/// https://wikiki.github.io/components/steps/
+#[component]
+pub fn Steps(
+    /// The steps, in order.
+    #[prop(into)]
+    steps: Signal<Vec<Step>>,
+
+    /// Index of the current step.
+    #[prop(into)]
+    current: Signal<usize>,
+
+    /// Indices of the completed steps.
+    #[prop(optional, into)]
+    completed: Signal<BTreeSet<usize>>,
+
+    /// Called with the index of a completed step when it is clicked. Steps are not clickable
+    /// without it.
+    #[prop(optional)]
+    on_select: Option<Callback<usize>>,
+
+    /// The size of this component.
+    #[prop(optional)]
+    size: Option<Size>,
+
+    /// Extra classes to apply to the root "steps" container.
+    #[prop(optional, into)]
+    classes: Signal<String>,
+
+    /// Optional test attribute (renders as data-* attribute) on the root <div>.
+    ///
+    /// When provided as a &str or String, this becomes `data-testid="value"`.
+    /// You can also pass a full `TestAttr` to override the attribute key.
+    #[prop(optional, into)]
+    test_attr: Option<TestAttr>,
+) -> impl IntoView {
+    let class = move || {
+        let mut parts = vec!["steps".to_string()];
+        if let Some(size) = size
+            && size != Size::Normal
+        {
+            parts.push(size.bulma().to_string());
+        }
+        let extra = classes.get();
+        if !extra.trim().is_empty() {
+            parts.push(extra);
+        }
+        parts.join(" ")
+    };
+
+    let items = move || {
+        let current = current.get();
+        let completed = completed.get();
+        steps
+            .get()
+            .into_iter()
+            .enumerate()
+            .map(|(index, step)| {
+                let is_active = index == current;
+                let is_completed = completed.contains(&index);
+                let selectable = on_select.is_some() && is_completed && !is_active;
+
+                let mut class = vec!["step-item"];
+                if is_active {
+                    class.push("is-active");
+                }
+                if is_completed {
+                    class.push("is-completed");
+                }
+                if selectable {
+                    class.push("is-clickable");
+                }
+                let marker = if is_completed && !is_active {
+                    "✓".to_string()
+                } else {
+                    (index + 1).to_string()
+                };
+                let select = move || {
+                    if selectable && let Some(on_select) = on_select {
+                        on_select.run(index);
+                    }
+                };
+
+                view! {
+                    <div
+                        class=class.join(" ")
+                        aria-current=is_active.then_some("step")
+                        role=selectable.then_some("button")
+                        tabindex=selectable.then_some("0")
+                        on:click=move |_| select()
+                        on:keydown=move |ev: leptos::ev::KeyboardEvent| {
+                            if matches!(ev.key().as_str(), "Enter" | " ") {
+                                ev.prevent_default();
+                                select();
+                            }
+                        }
+                    >
+                        <div class="step-marker">{marker}</div>
+                        <div class="step-details">
+                            <p class="step-title">{step.title}</p>
+                            {step.description.map(|description| view! { <p>{description}</p> })}
+                            {step
+                                .optional
+                                .then(|| view! { <p class="is-size-7 has-text-grey">"Optional"</p> })}
+                        </div>
+                    </div>
+                }
+            })
+            .collect_view()
+    };
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    view! {
+        <div
+            class=class
+            attr:data-testid=move || data_testid.clone()
+            attr:data-cy=move || data_cy.clone()
+        >
+            {items}
+        </div>
+    }
+}
+
+/// One step of a [`Wizard`]: its indicator entry, its content and the fields Next validates.
+#[derive(Clone)]
+pub struct WizardStep {
+    pub step: Step,
+    /// Names of the form fields validated before moving past this step.
+    pub fields: Vec<String>,
+    pub content: ViewFn,
+}
+
+impl WizardStep {
+    pub fn new(title: impl Into<String>, content: impl Into<ViewFn>) -> Self {
+        Self {
+            step: Step::new(title),
+            fields: Vec::new(),
+            content: content.into(),
+        }
+    }
+
+    /// Adds a line of text under the title in the indicator.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.step = self.step.with_description(description);
+        self
+    }
+
+    /// Lets the user skip this step without validating it.
+    pub fn with_optional(mut self, optional: bool) -> Self {
+        self.step = self.step.with_optional(optional);
+        self
+    }
+
+    /// The fields validated before moving past this step.
+    pub fn with_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+/// What [`WizardNav`] needs to know about a step.
+struct StepRules {
+    fields: Vec<String>,
+    optional: bool,
+}
+
+/// Navigation state of a [`Wizard`].
+#[derive(Clone, Copy)]
+struct WizardNav {
+    form: FormContext,
+    steps: StoredValue<Vec<StepRules>>,
+    current: RwSignal<usize>,
+    completed: RwSignal<BTreeSet<usize>>,
+    on_finish: Option<Callback<FormValues>>,
+    on_step_change: Option<Callback<usize>>,
+}
+
+impl WizardNav {
+    fn new(
+        form: FormContext,
+        steps: &[WizardStep],
+        on_finish: Option<Callback<FormValues>>,
+        on_step_change: Option<Callback<usize>>,
+    ) -> Self {
+        let rules = steps
+            .iter()
+            .map(|step| StepRules {
+                fields: step.fields.clone(),
+                optional: step.step.optional,
+            })
+            .collect();
+        Self {
+            form,
+            steps: StoredValue::new(rules),
+            current: RwSignal::new(0),
+            completed: RwSignal::new(BTreeSet::new()),
+            on_finish,
+            on_step_change,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.steps.with_value(Vec::len)
+    }
+
+    fn is_optional(&self, index: usize) -> bool {
+        self.steps
+            .with_value(|steps| steps.get(index).is_some_and(|step| step.optional))
+    }
+
+    /// Runs the validators of the step's fields, showing their errors.
+    fn validate(&self, index: usize) -> bool {
+        let fields = self.steps.with_value(|steps| {
+            steps
+                .get(index)
+                .map(|step| step.fields.clone())
+                .unwrap_or_default()
+        });
+        self.form.validate_fields(fields)
+    }
+
+    fn go(&self, index: usize) {
+        self.current.set(index);
+        if let Some(on_step_change) = self.on_step_change {
+            on_step_change.run(index);
+        }
+    }
+
+    /// Next / Finish: validates the current step and moves past it.
+    fn next(&self) {
+        let index = self.current.get_untracked();
+        if !self.validate(index) {
+            return;
+        }
+        self.completed.update(|completed| {
+            completed.insert(index);
+        });
+        if index + 1 < self.len() {
+            self.go(index + 1);
+        } else {
+            self.finish();
+        }
+    }
+
+    fn back(&self) {
+        let index = self.current.get_untracked();
+        if index > 0 {
+            self.go(index - 1);
+        }
+    }
+
+    /// Moves past an optional step without validating it.
+    fn skip(&self) {
+        let index = self.current.get_untracked();
+        if !self.is_optional(index) {
+            return;
+        }
+        self.completed.update(|completed| {
+            completed.remove(&index);
+        });
+        if index + 1 < self.len() {
+            self.go(index + 1);
+        } else {
+            self.finish();
+        }
+    }
+
+    /// Jumps to a step chosen in the indicator: any earlier step, or a completed later one once
+    /// the current step is valid.
+    fn select(&self, index: usize) {
+        let current = self.current.get_untracked();
+        if index < current {
+            self.go(index);
+        } else if index > current
+            && self.completed.get_untracked().contains(&index)
+            && self.validate(current)
+        {
+            self.completed.update(|completed| {
+                completed.insert(current);
+            });
+            self.go(index);
+        }
+    }
+
+    /// Calls `on_finish` unless a required step is incomplete or a completed one became invalid;
+    /// then that step is shown instead.
+    fn finish(&self) {
+        let completed = self.completed.get_untracked();
+        let unfinished = (0..self.len()).find(|index| {
+            if completed.contains(index) {
+                !self.validate(*index)
+            } else {
+                !self.is_optional(*index)
+            }
+        });
+        match unfinished {
+            Some(index) => self.go(index),
+            None => {
+                if let Some(on_finish) = self.on_finish {
+                    on_finish.run(untrack(|| self.form.values()));
+                }
+            }
+        }
+    }
+}
+
+/// A multi-step form: a [`Steps`] indicator, the content of the current step and navigation
+/// buttons.
+///
+/// Provides its `FormContext` to the steps like `Form` does.
+#[component]
+pub fn Wizard(
+    /// The steps, in order.
+    steps: Vec<WizardStep>,
+
+    /// The state container shared by the steps; a new one is created when omitted.
+    #[prop(optional)]
+    form: Option<FormContext>,
+
+    /// Called with all form values when the last step is finished.
+    #[prop(optional)]
+    on_finish: Option<Callback<FormValues>>,
+
+    /// Called with the index of the step shown after each navigation.
+    #[prop(optional)]
+    on_step_change: Option<Callback<usize>>,
+
+    /// Text of the button going to the previous step.
+    #[prop(default = "Back".to_string().into(), into)]
+    back_label: Signal<String>,
+
+    /// Text of the button going to the next step.
+    #[prop(default = "Next".to_string().into(), into)]
+    next_label: Signal<String>,
+
+    /// Text of the button skipping an optional step.
+    #[prop(default = "Skip".to_string().into(), into)]
+    skip_label: Signal<String>,
+
+    /// Text of the button on the last step.
+    #[prop(default = "Finish".to_string().into(), into)]
+    finish_label: Signal<String>,
+
+    /// Extra classes to apply to the root "wizard" container.
+    #[prop(optional, into)]
+    classes: Signal<String>,
+
+    /// Optional test attribute (renders as data-* attribute) on the root <div>.
+    ///
+    /// When provided as a &str or String, this becomes `data-testid="value"`.
+    /// You can also pass a full `TestAttr` to override the attribute key.
+    #[prop(optional, into)]
+    test_attr: Option<TestAttr>,
+) -> impl IntoView {
+    let form = form.unwrap_or_default();
+    provide_context::<FormContext>(form);
+
+    let nav = WizardNav::new(form, &steps, on_finish, on_step_change);
+    let current = nav.current;
+    let indicator = steps
+        .iter()
+        .map(|step| step.step.clone())
+        .collect::<Vec<_>>();
+    let is_last = move || current.get() + 1 >= nav.len();
+
+    let contents = steps
+        .into_iter()
+        .enumerate()
+        .map(|(index, step)| {
+            let class = move || {
+                if current.get() == index {
+                    "wizard-step"
+                } else {
+                    "wizard-step is-hidden"
+                }
+            };
+            view! { <div class=class>{step.content.run()}</div> }
+        })
+        .collect_view();
+
+    let class = move || {
+        let extra = classes.get();
+        if extra.trim().is_empty() {
+            "wizard".to_string()
+        } else {
+            format!("wizard {}", extra)
+        }
+    };
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    view! {
+        <div
+            class=class
+            attr:data-testid=move || data_testid.clone()
+            attr:data-cy=move || data_cy.clone()
+        >
+            <Steps
+                steps=indicator
+                current=current
+                completed=nav.completed
+                on_select=Callback::new(move |index| nav.select(index))
+            />
+            <form
+                class="wizard-form"
+                novalidate=true
+                on:submit=move |ev: leptos::ev::SubmitEvent| {
+                    ev.prevent_default();
+                    nav.next();
+                }
+            >
+                {contents}
+                <div class="level mt-5">
+                    <div class="level-left">
+                        <div class="level-item">
+                            <Button
+                                r#type="button"
+                                disabled=Signal::derive(move || current.get() == 0)
+                                on_click=Callback::new(move |_| nav.back())
+                            >
+                                {back_label}
+                            </Button>
+                        </div>
+                    </div>
+                    <div class="level-right">
+                        {move || {
+                            nav.is_optional(current.get())
+                                .then(|| {
+                                    view! {
+                                        <div class="level-item">
+                                            <Button
+                                                r#type="button"
+                                                on_click=Callback::new(move |_| nav.skip())
+                                            >
+                                                {skip_label}
+                                            </Button>
+                                        </div>
+                                    }
+                                })
+                        }}
+                        <div class="level-item">
+                            <Button color=ButtonColor::Primary r#type="submit">
+                                {move || if is_last() { finish_label.get() } else { next_label.get() }}
+                            </Button>
+                        </div>
+                    </div>
+                </div>
+            </form>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::form::context::use_form;
+    use crate::form::validation::{ValidateOn, required};
+    use leptos::prelude::{Owner, RenderHtml};
+
+    fn steps() -> Vec<Step> {
+        vec![
+            Step::new("Account").with_description("Sign in details"),
+            Step::new("Newsletter").with_optional(true),
+            Step::new("Confirm"),
+        ]
+    }
+
+    #[test]
+    fn steps_normal_size_adds_no_class() {
+        let html = view! { <Steps steps=steps() current=0 size=Size::Normal /> }.to_html();
+        assert!(
+            html.contains(r#"class="steps""#) && !html.contains("is-normal"),
+            "expected no size class: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn steps_marks_active_and_completed_items() {
+        let html = view! {
+            <Steps
+                steps=steps()
+                current=1
+                completed=BTreeSet::from([0])
+                on_select=Callback::new(|_index: usize| {})
+                size=Size::Small
+                test_attr="steps"
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"class="steps is-small""#) && html.contains(r#"data-testid="steps""#),
+            "expected root classes: {}",
+            html
+        );
+        assert!(
+            html.contains(
+                r#"role="button" tabindex="0" class="step-item is-completed is-clickable""#
+            ) && html.contains(r#"<div class="step-marker">✓</div>"#),
+            "expected a clickable completed step: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"aria-current="step" class="step-item is-active""#)
+                && html.contains(r#"<div class="step-marker">2</div>"#),
+            "expected the active step: {}",
+            html
+        );
+        assert!(
+            html.contains("<p>Sign in details</p>") && html.contains(">Optional</p>"),
+            "expected description and optional marker: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"<div class="step-item"><div class="step-marker">3</div>"#),
+            "expected a pending step: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn steps_are_not_clickable_without_on_select() {
+        let html =
+            view! { <Steps steps=steps() current=1 completed=BTreeSet::from([0]) /> }.to_html();
+        assert!(
+            !html.contains("is-clickable") && !html.contains(r#"role="button""#),
+            "expected plain items: {}",
+            html
+        );
+    }
+
+    fn wizard_steps() -> Vec<WizardStep> {
+        vec![
+            WizardStep::new("Account", || view! { <p>"Account form"</p> }).with_fields(["email"]),
+            WizardStep::new("Newsletter", || view! { <p>"Topics"</p> }).with_optional(true),
+            WizardStep::new("Confirm", || view! { <p>"Check and send"</p> }).with_fields(["terms"]),
+        ]
+    }
+
+    #[test]
+    fn wizard_validates_each_step_before_moving_on() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let form = use_form();
+            form.set_validators("email", ValidateOn::Blur, vec![required()]);
+            form.set_validators("terms", ValidateOn::Blur, vec![required()]);
+            let finished = RwSignal::new(None::<FormValues>);
+            let shown = RwSignal::new(Vec::new());
+            let nav = WizardNav::new(
+                form,
+                &wizard_steps(),
+                Some(Callback::new(move |values| finished.set(Some(values)))),
+                Some(Callback::new(move |index| {
+                    shown.update(|shown| shown.push(index))
+                })),
+            );
+
+            nav.next();
+            assert_eq!(nav.current.get_untracked(), 0, "email is required");
+            assert!(form.error("email").is_some() && form.is_touched("email"));
+            assert!(nav.completed.get_untracked().is_empty());
+
+            form.set_value("email", "ada@example.com");
+            nav.next();
+            assert_eq!(nav.current.get_untracked(), 1);
+            nav.skip();
+            assert_eq!(
+                nav.current.get_untracked(),
+                2,
+                "optional steps can be skipped"
+            );
+            assert_eq!(nav.completed.get_untracked(), BTreeSet::from([0]));
+
+            nav.select(0);
+            assert_eq!(nav.current.get_untracked(), 0, "back to a completed step");
+            nav.select(2);
+            assert_eq!(
+                nav.current.get_untracked(),
+                0,
+                "step 2 is not completed yet"
+            );
+            nav.next();
+            nav.back();
+            assert_eq!(nav.current.get_untracked(), 0);
+            nav.skip();
+            assert_eq!(
+                nav.current.get_untracked(),
+                0,
+                "required steps cannot be skipped"
+            );
+
+            nav.next();
+            nav.next();
+            assert_eq!(nav.current.get_untracked(), 2);
+            nav.next();
+            assert_eq!(finished.get_untracked(), None, "terms are required");
+            assert!(form.error("terms").is_some());
+
+            form.set_value("terms", "accepted");
+            nav.next();
+            let values = finished.get_untracked().expect("finished");
+            assert_eq!(
+                values.get("email").map(String::as_str),
+                Some("ada@example.com")
+            );
+            assert_eq!(shown.get_untracked(), [1, 2, 0, 1, 0, 1, 2]);
+        });
+    }
+
+    #[test]
+    fn wizard_finish_returns_to_a_step_that_became_invalid() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let form = use_form();
+            form.set_validators("email", ValidateOn::Blur, vec![required()]);
+            form.set_value("email", "ada@example.com");
+            let finished = RwSignal::new(false);
+            let nav = WizardNav::new(
+                form,
+                &wizard_steps(),
+                Some(Callback::new(move |_values| finished.set(true))),
+                None,
+            );
+            nav.next();
+            nav.skip();
+            form.set_value("email", "");
+            nav.next();
+            assert!(!finished.get_untracked());
+            assert_eq!(nav.current.get_untracked(), 0);
+            assert!(form.error("email").is_some());
+        });
+    }
+
+    #[test]
+    fn wizard_renders_indicator_steps_and_buttons() {
+        let html = view! {
+            <Wizard steps=wizard_steps() classes="onboarding" next_label="Continue" />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"class="wizard onboarding""#),
+            "expected root classes: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"aria-current="step" class="step-item is-active""#)
+                && html.contains(">Confirm</p>"),
+            "expected the indicator: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"<div class="wizard-step"><p>Account form</p></div>"#)
+                && html.contains(r#"<div class="wizard-step is-hidden"><p>Topics</p></div>"#),
+            "expected every step mounted, inactive ones hidden: {}",
+            html
+        );
+        assert!(
+            html.contains(">Back</button>")
+                && html.contains(">Continue</button>")
+                && !html.contains(">Skip</button>"),
+            "expected back and next buttons: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"<form novalidate class="wizard-form">"#),
+            "expected the form element: {}",
+            html
+        );
+    }
+}
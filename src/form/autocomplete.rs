@@ -1,78 +1,110 @@
 /*!
-AutoComplete component: a Leptos wrapper around the Bulma Tags Input plugin.
-
-Required static assets
-- Add the Bulma TagsInput CSS into your HTML <head>:
-  <link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/@creativebulma/bulma-tagsinput@1.0.3/dist/css/bulma-tagsinput.min.css" />
-
-- Add the Bulma TagsInput JS so `BulmaTagsInput` is available on window. Place this before your wasm bootstrap script
-  (or ensure it loads before your app mounts):
-  <script src="https://cdn.jsdelivr.net/npm/@creativebulma/bulma-tagsinput@1.0.3/dist/js/bulma-tagsinput.min.js"></script>
+AutoComplete component: a native Leptos tags typeahead styled with Bulma.
+
+Selected values are rendered as Bulma tags in front of a text input; suggestions are shown in a
+Bulma dropdown below it. No third-party JS plugin or CDN asset is required.
+
+Three modes:
+- Static: provide `items`; suggestions are filtered locally as the user types.
+- Dynamic: provide a `source` (see [`SuggestionSource`]) or `url_for_fetch`. Input is debounced
+  (`debounce_ms`, default 250), the control shows a spinner while a lookup runs, superseded
+  lookups are cancelled (aborting their request) and failures are shown as a `help is-danger`
+  message.
+  With `url_for_fetch` the typed text is appended (URI-encoded) and the JSON response
+  (an array of strings or objects) is used as suggestions; `data_item_text`/`data_item_value`
+  select the object fields to show and to emit.
+- Plain: none of the above; Enter adds the typed text as a tag.
+
+Keyboard
+- ArrowDown/ArrowUp move through the suggestions, Enter adds the highlighted one
+  (or the first match), Escape closes the list.
+- Backspace in an empty input removes the last tag.
 
 Notes
-- We attach the JS behavior on mount and detach on unmount.
 - SSR tests only verify the rendered HTML structure.
 */
 
+use std::time::Duration;
+
 use leptos::callback::Callback;
+use leptos::ev::{KeyboardEvent, MouseEvent};
 use leptos::prelude::{
-    Callable, ClassAttribute, CustomAttribute, ElementChild, Get, GlobalAttributes, IntoAny,
-    IntoView, Signal, component, view,
+    AriaAttributes, Callable, ClassAttribute, CollectView, CustomAttribute, ElementChild, Get,
+    GetUntracked, GlobalAttributes, IntoView, Memo, OnAttribute, PropAttribute, RwSignal, Set,
+    Signal, Update, With, WithUntracked, component, event_target_value, view,
 };
 
-#[cfg(target_arch = "wasm32")]
-use leptos::wasm_bindgen::JsCast;
-#[cfg(target_arch = "wasm32")]
-use leptos::wasm_bindgen::JsValue;
-#[cfg(target_arch = "wasm32")]
-use leptos::wasm_bindgen::closure::Closure;
-#[cfg(target_arch = "wasm32")]
-use leptos::web_sys::Element;
+use crate::elements::delete::Delete;
+use crate::elements::tag::Tag;
+use crate::elements::tags::Tags;
+use crate::form::suggestion::{
+    Lookup, Suggestion, SuggestionSourceRef, UrlSource, contains_value, filter_suggestions,
+};
+use crate::util::TestAttr;
 
-#[cfg(target_arch = "wasm32")]
-use js_sys::JSON;
+/// Move the highlight one step, wrapping around at both ends.
+fn next_index(current: Option<usize>, len: usize, forward: bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    Some(match (current, forward) {
+        (None, true) => 0,
+        (None, false) => len - 1,
+        (Some(index), true) => (index + 1) % len,
+        (Some(index), false) => (index + len - 1) % len,
+    })
+}
 
-use crate::util::TestAttr;
+/// Resolves a prop and its deprecated `_`-prefixed alias; the new name wins when both are set.
+fn resolve_alias<T>(current: Option<T>, deprecated: Option<T>) -> Option<T> {
+    current.or(deprecated)
+}
 
-/// A tags autocomplete input based on Bulma TagsInput.
+/// A tags autocomplete input.
 ///
-/// Two modes:
-/// - Static: provide `items` (and leave `data_item_*` empty) to render a <select data-type="tags"> with options.
-/// - Dynamic: provide both `data_item_text` and `data_item_value` plus `url_for_fetch` for async suggestions.
+/// Three modes:
+/// - Static: provide `items` to filter suggestions locally.
+/// - Dynamic: provide a `source`, or `url_for_fetch` (and optionally `data_item_text`/`data_item_value`),
+///   for async suggestions.
+/// - Plain: free text tags, added with Enter.
+// The `_`-prefixed props are deprecated aliases of the renamed ones.
+#[allow(clippy::duplicate_underscore_argument)]
 #[component]
 pub fn AutoComplete(
-    /// Unique DOM id for the element used by the JS plugin.
+    /// Unique DOM id for the input; the suggestion list uses `{id}-listbox`.
     id: String,
 
     /// Maximum number of tags allowed (default 10).
     #[prop(optional)]
     max_items: Option<u32>,
 
-    /// Static list of items. If provided and `data_item_text/value` are empty, renders a <select data-type="tags">.
+    /// Static list of items to filter locally.
     #[prop(optional)]
     items: Option<Vec<String>>,
 
-    /// Called when a tag is added.
-    _on_update: Callback<String>,
+    /// Called with the value of a tag when it is added.
+    #[prop(optional)]
+    on_update: Option<Callback<String>>,
 
-    /// Called when a tag is removed.
-    _on_remove: Callback<String>,
+    /// Called with the value of a tag when it is removed.
+    #[prop(optional)]
+    on_remove: Option<Callback<String>>,
 
     /// Currently selected single tag (for initial value).
     #[prop(optional, into)]
     current_selector: Signal<String>,
 
-    /// Placeholder to show in the input/select.
+    /// Placeholder to show in the input.
     #[prop(optional, into)]
     placeholder: Signal<String>,
 
-    /// Extra classes appended to the control.
+    /// Extra classes appended to the input.
     #[prop(optional, into)]
     classes: Signal<String>,
 
-    /// Case sensitive matching.
+    /// Case sensitive matching and duplicate detection.
     #[prop(optional, into)]
-    _case_sensitive: Signal<bool>,
+    case_sensitive: Option<Signal<bool>>,
 
     /// For dynamic mode: object field to show as text.
     #[prop(optional, into)]
@@ -82,296 +114,358 @@ pub fn AutoComplete(
     #[prop(optional, into)]
     data_item_value: Signal<String>,
 
-    /// For dynamic mode: base URL to fetch suggestions (the plugin appends the typed value).
+    /// For dynamic mode: base URL to fetch suggestions (the typed value is appended).
     #[prop(optional, into)]
-    _url_for_fetch: Signal<String>,
+    url_for_fetch: Option<Signal<String>>,
 
     /// Optional Authorization header value for dynamic fetches.
     #[prop(optional, into)]
-    _auth_header: Signal<String>,
+    auth_header: Option<Signal<String>>,
+
+    /// For dynamic mode: asynchronous suggestion provider. Takes precedence over `url_for_fetch`.
+    #[prop(optional, into)]
+    source: Option<SuggestionSourceRef>,
+
+    /// For dynamic mode: wait this long after the last keystroke before looking up (default 250).
+    #[prop(optional)]
+    debounce_ms: Option<u32>,
+
+    /// Deprecated alias of `on_update`; will be removed in the next release.
+    #[prop(optional)]
+    _on_update: Option<Callback<String>>,
+
+    /// Deprecated alias of `on_remove`; will be removed in the next release.
+    #[prop(optional)]
+    _on_remove: Option<Callback<String>>,
+
+    /// Deprecated alias of `case_sensitive`; will be removed in the next release.
+    #[prop(optional, into)]
+    _case_sensitive: Option<Signal<bool>>,
 
-    /// Optional test attribute (renders as data-* attribute) on the rendered input/select wrapper.
+    /// Deprecated alias of `url_for_fetch`; will be removed in the next release.
+    #[prop(optional, into)]
+    _url_for_fetch: Option<Signal<String>>,
+
+    /// Deprecated alias of `auth_header`; will be removed in the next release.
+    #[prop(optional, into)]
+    _auth_header: Option<Signal<String>>,
+
+    /// Optional test attribute (renders as data-* attribute) on the wrapper.
     ///
     /// When provided as a &str or String, this becomes `data-testid="value"`.
     /// You can also pass a full `TestAttr` to override the attribute key (e.g., `data-cy`).
     #[prop(optional, into)]
     test_attr: Option<TestAttr>,
 ) -> impl IntoView {
-    let _max_items_value = max_items.unwrap_or(10);
+    #[cfg(debug_assertions)]
+    if _on_update.is_some()
+        || _on_remove.is_some()
+        || _case_sensitive.is_some()
+        || _url_for_fetch.is_some()
+        || _auth_header.is_some()
+    {
+        leptos::logging::warn!(
+            "<AutoComplete id={:?}> props starting with `_` are deprecated; drop the underscore",
+            id
+        );
+    }
+    let on_update = resolve_alias(on_update, _on_update);
+    let on_remove = resolve_alias(on_remove, _on_remove);
+    let case_sensitive = resolve_alias(case_sensitive, _case_sensitive).unwrap_or_default();
+    let url_for_fetch = resolve_alias(url_for_fetch, _url_for_fetch).unwrap_or_default();
+    let auth_header = resolve_alias(auth_header, _auth_header).unwrap_or_default();
+
+    let max_items = max_items.unwrap_or(10) as usize;
+    let items: Vec<Suggestion> = items
+        .unwrap_or_default()
+        .into_iter()
+        .map(Suggestion::new)
+        .collect();
+    let static_mode = !items.is_empty();
+
+    let initial = current_selector.get_untracked();
+    let selected = RwSignal::new(if initial.trim().is_empty() {
+        Vec::new()
+    } else {
+        vec![Suggestion::new(initial)]
+    });
+    let query = RwSignal::new(String::new());
+    let open = RwSignal::new(false);
+    let highlighted = RwSignal::new(None::<usize>);
+    let lookup = Lookup::new();
+    let debounce = Duration::from_millis(u64::from(debounce_ms.unwrap_or(250)));
+
+    // An explicit source wins; otherwise `url_for_fetch` is read at lookup time.
+    let current_source = move || {
+        source.clone().or_else(|| {
+            let url = url_for_fetch.get_untracked();
+            (!url.trim().is_empty()).then(|| {
+                SuggestionSourceRef::new(
+                    UrlSource::new(url)
+                        .with_auth_header(auth_header.get_untracked())
+                        .with_item_fields(
+                            data_item_text.get_untracked(),
+                            data_item_value.get_untracked(),
+                        ),
+                )
+            })
+        })
+    };
 
-    // Build base input class
-    let input_class = {
-        let classes = classes.clone();
-        move || {
-            let extra = classes.get();
-            if extra.trim().is_empty() {
-                "input".to_string()
+    let visible = Memo::new(move |_| {
+        let case_sensitive = case_sensitive.get();
+        selected.with(|selected| {
+            if static_mode {
+                filter_suggestions(&items, &query.get(), case_sensitive, selected)
             } else {
-                format!("input {}", extra)
+                lookup.results.with(|remote| {
+                    remote
+                        .iter()
+                        .filter(|item| !contains_value(selected, &item.value, case_sensitive))
+                        .cloned()
+                        .collect()
+                })
             }
+        })
+    });
+
+    let add = move |suggestion: Suggestion| {
+        let value = suggestion.value.trim().to_string();
+        if value.is_empty() {
+            return;
+        }
+        let case_sensitive = case_sensitive.get_untracked();
+        let mut added = false;
+        selected.update(|list| {
+            if list.len() < max_items && !contains_value(list, &value, case_sensitive) {
+                list.push(suggestion);
+                added = true;
+            }
+        });
+        query.set(String::new());
+        highlighted.set(None);
+        lookup.clear();
+        if added && let Some(on_update) = on_update {
+            on_update.run(value);
         }
     };
 
-    // Render structure based on props.
-    let static_mode = items.as_ref().map(|v| !v.is_empty()).unwrap_or(false)
-        && data_item_text.get().trim().is_empty()
-        && data_item_value.get().trim().is_empty();
-
-    // Derive specific optional attributes that our macro can render.
-    let (data_testid, data_cy) = match &test_attr {
-        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
-        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
-        _ => (None, None),
+    let remove = move |index: usize| {
+        let mut removed = None;
+        selected.update(|list| {
+            if index < list.len() {
+                removed = Some(list.remove(index));
+            }
+        });
+        if let Some(item) = removed
+            && let Some(on_remove) = on_remove
+        {
+            on_remove.run(item.value);
+        }
     };
 
-    let body = if static_mode {
-        let options_view = {
-            let items = items.clone().unwrap_or_default();
-            let current = current_selector.get();
-            view! {
-                <>
-                    {items.into_iter().map(|item| {
-                        let selected = item == current;
-                        view! {
-                            <option value=item.clone() selected=selected>{item.clone()}</option>
-                        }.into_any()
-                    }).collect::<Vec<_>>()}
-                </>
+    let request_suggestions = {
+        let current_source = current_source.clone();
+        move |text: String| {
+            let Some(source) = current_source() else {
+                return;
+            };
+            if text.trim().is_empty() {
+                lookup.clear();
+                return;
             }
-            .into_any()
-        };
-        view! {
-            <div
-                class=move || {
-                    let extra = classes.get();
-                    if extra.trim().is_empty() { "select".to_string() } else { format!("select {}", extra) }
-                }
-                attr:data-testid=move || data_testid.clone()
-                attr:data-cy=move || data_cy.clone()
-            >
-                <select
-                    id=id.clone()
-                    data-type="tags"
-                    data-placeholder=placeholder.get()
-                >
-                    {options_view}
-                </select>
-            </div>
-        }
-        .into_any()
-    } else if !data_item_text.get().trim().is_empty() && !data_item_value.get().trim().is_empty() {
-        // Dynamic object mode
-        let value_json = {
-            let current = current_selector.get();
-            if current.trim().is_empty() {
-                "{}".to_string()
+            // Starting a lookup invalidates pending and in-flight ones.
+            let generation = lookup.begin();
+            let start = move || leptos::task::spawn_local(lookup.run(source, text, generation));
+            if debounce.is_zero() {
+                start();
             } else {
-                format!("{{\"{}\":\"{}\"}}", data_item_value.get(), current)
+                leptos::prelude::set_timeout(
+                    move || {
+                        if lookup.is_current(generation) {
+                            start();
+                        }
+                    },
+                    debounce,
+                );
             }
-        };
-        view! {
-            <input
-                id=id.clone()
-                r#type="text"
-                class=move || input_class()
-                data-item-text=data_item_text.get()
-                data-item-value=data_item_value.get()
-                data-placeholder=placeholder.get()
-                value=value_json
-                attr:data-testid=move || data_testid.clone()
-                attr:data-cy=move || data_cy.clone()
-            />
-        }
-        .into_any()
-    } else {
-        // Plain text mode
-        view! {
-            <input
-                id=id.clone()
-                r#type="text"
-                class=move || input_class()
-                data-placeholder=placeholder.get()
-                value=current_selector.get()
-                attr:data-testid=move || data_testid.clone()
-                attr:data-cy=move || data_cy.clone()
-            />
         }
-        .into_any()
     };
 
-    // Attach JS plugin on mount.
-    #[cfg(target_arch = "wasm32")]
-    {
-        let id_for_js = id.clone();
-        let max_items = _max_items_value;
-        let current_selector = current_selector.clone();
-        let case_sensitive = _case_sensitive.clone();
-        let url_for_fetch = _url_for_fetch.clone();
-        let auth_header = _auth_header.clone();
-        let data_item_value = data_item_value.clone();
-
-        leptos::prelude::Effect::new(move |_| {
-            let document = leptos::prelude::document();
-            if let Some(element) = document.get_element_by_id(&id_for_js) {
-                // Build callback to route ops to on_update/on_remove
-                let cb = {
-                    let on_update = _on_update.clone();
-                    let on_remove = _on_remove.clone();
-                    Closure::wrap(Box::new(move |json: JsValue| {
-                        let Some(s) = json.as_string() else {
-                            return;
-                        };
-
-                        // Expect: {"op":"add","value":"Rust"} or {"op":"remove","value":"Rust"}
-                        let Ok(obj) = JSON::parse(&s) else {
-                            return;
-                        };
-
-                        let op = js_sys::Reflect::get(&obj, &JsValue::from_str("op"))
-                            .ok()
-                            .and_then(|v| v.as_string())
-                            .unwrap_or_default();
-
-                        let value = js_sys::Reflect::get(&obj, &JsValue::from_str("value"))
-                            .ok()
-                            .and_then(|v| v.as_string())
-                            .unwrap_or_default();
-
-                        if value.trim().is_empty() {
-                            return;
-                        }
+    let on_input = move |ev| {
+        let text = event_target_value(&ev);
+        query.set(text.clone());
+        open.set(true);
+        highlighted.set(None);
+        request_suggestions(text);
+    };
 
-                        if op == "add" {
-                            on_update.run(value);
-                        } else if op == "remove" {
-                            on_remove.run(value);
-                        }
-                    }) as Box<dyn FnMut(JsValue)>)
-                };
-
-                let url = url_for_fetch.get();
-                if url.trim().is_empty() {
-                    // Static
-                    setup_static_autocomplete(
-                        &element.unchecked_into::<Element>(),
-                        cb.as_ref(),
-                        &JsValue::from(max_items),
-                        &JsValue::from(case_sensitive.get()),
-                    );
-                } else {
-                    // Dynamic
-                    setup_dynamic_autocomplete(
-                        &element.unchecked_into::<Element>(),
-                        cb.as_ref(),
-                        &JsValue::from(max_items),
-                        &JsValue::from(url),
-                        &JsValue::from(auth_header.get()),
-                        &JsValue::from(case_sensitive.get()),
-                        &JsValue::from(data_item_value.get()),
-                        &JsValue::from(current_selector.get()),
-                    );
-                }
-                cb.forget();
+    let on_keydown = move |ev: KeyboardEvent| match ev.key().as_str() {
+        key @ ("ArrowDown" | "ArrowUp") => {
+            ev.prevent_default();
+            open.set(true);
+            let len = visible.with_untracked(Vec::len);
+            highlighted.set(next_index(
+                highlighted.get_untracked(),
+                len,
+                key == "ArrowDown",
+            ));
+        }
+        "Enter" => {
+            ev.prevent_default();
+            let text = query.get_untracked();
+            let choice = visible.with_untracked(|list| match highlighted.get_untracked() {
+                Some(index) => list.get(index).cloned(),
+                None if !text.trim().is_empty() => list.first().cloned(),
+                None => None,
+            });
+            let plain_mode = !static_mode && current_source().is_none();
+            match choice {
+                Some(suggestion) => add(suggestion),
+                None if plain_mode => add(Suggestion::new(text.trim())),
+                None => {}
             }
-        });
+        }
+        "Escape" => {
+            open.set(false);
+            highlighted.set(None);
+        }
+        "Backspace" if query.with_untracked(String::is_empty) => {
+            let len = selected.with_untracked(Vec::len);
+            if len > 0 {
+                remove(len - 1);
+            }
+        }
+        _ => {}
+    };
 
-        // Cleanup detach
-        let id_cleanup = id.clone();
-        leptos::prelude::on_cleanup(move || {
-            detach_autocomplete(&JsValue::from(id_cleanup.as_str()));
-        });
-    }
+    let is_open = move || open.get() && !visible.with(Vec::is_empty);
 
-    view! { {body} }
-}
+    let root_class = move || {
+        if is_open() {
+            "autocomplete dropdown is-block is-active"
+        } else {
+            "autocomplete dropdown is-block"
+        }
+    };
 
-// JS bridge similar to the Yew version, adapted for Leptos.
-#[cfg(target_arch = "wasm32")]
-#[leptos::wasm_bindgen::prelude::wasm_bindgen(inline_js = r#"
-let init = new Map();
-export function setup_dynamic_autocomplete(element, callback, max_tags, url_for_fetch, auth_header, case_sensitive, data_item_value, initial_value) {
-     if (!init.has(element.id)) {
-         let autocompleteInstance = BulmaTagsInput.attach(element, {
-            maxTags: Number(max_tags) || 10,
-            caseSensitive: !!case_sensitive,
-            source: async function(value) {
-                return await fetch(String(url_for_fetch) + value, {
-                    headers: auth_header ? { 'Authorization': String(auth_header) } : {}
-                })
-                .then(function(response) {
-                    if (response.status !== 200) {
-                        throw new Error('Failed to fetch data');
-                    }
-                    return response.json();
-                });
-            },
-         });
-         let autocomplete = autocompleteInstance[0];
-         autocomplete.on('after.add', function(tag) {
-            callback('{"op":"add","value":"'+tag.item[data_item_value]+'"}');
-         });
-         autocomplete.on('after.remove', function(tag) {
-            callback('{"op":"remove","value":"'+tag[data_item_value]+'"}');
-         });
-         if (String(initial_value).length > 0) {
-            autocomplete.add('{"'+data_item_value+'":"'+String(initial_value)+'"}');
-         }
-         init.set(element.id, autocomplete);
-     }
-}
+    let input_class = move || {
+        let extra = classes.get();
+        if extra.trim().is_empty() {
+            "input".to_string()
+        } else {
+            format!("input {}", extra)
+        }
+    };
 
-export function setup_static_autocomplete(element, callback, max_tags, case_sensitive) {
-     if (!init.has(element.id)) {
-         let autocompleteInstance = BulmaTagsInput.attach(element, {
-            maxTags: Number(max_tags) || 10,
-            caseSensitive: !!case_sensitive,
-         });
-         let autocomplete = autocompleteInstance[0];
-         autocomplete.on('after.add', function(tag) {
-            if (tag.item && tag.item.value) {
-                callback('{"op":"add","value":"'+tag.item.value+'"}');
-            } else if (tag.value) {
-                callback('{"op":"add","value":"'+tag.value+'"}');
-            } else {
-                callback('{"op":"add","value":"'+tag.item+'"}');
-            }
-         });
-         autocomplete.on('after.remove', function(tag) {
-            if (tag.item && tag.item.value) {
-                callback('{"op":"remove","value":"'+tag.item.value+'"}');
-            } else if (tag.value) {
-                callback('{"op":"remove","value":"'+tag.value+'"}');
-            } else {
-                callback('{"op":"remove","value":"'+tag+'"}');
+    let listbox_id = format!("{}-listbox", id);
+    let option_id = {
+        let id = id.clone();
+        move |index: usize| format!("{}-option-{}", id, index)
+    };
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    let tags_view = move || {
+        let list = selected.get();
+        (!list.is_empty()).then(|| {
+            view! {
+                <Tags classes="mb-1">
+                    {list
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, item)| {
+                            view! {
+                                <Tag>
+                                    {item.label}
+                                    <Delete
+                                        tag="a"
+                                        classes="is-small"
+                                        on_click=Callback::new(move |_: MouseEvent| remove(index))
+                                    />
+                                </Tag>
+                            }
+                        })
+                        .collect_view()}
+                </Tags>
             }
-         });
-         init.set(element.id, autocomplete);
-     }
-}
+        })
+    };
 
-export function detach_autocomplete(id) {
-   init.delete(String(id));
-}
-"#)]
-#[allow(improper_ctypes, improper_ctypes_definitions)]
-extern "C" {
-    fn setup_dynamic_autocomplete(
-        element: &Element,
-        callback: &JsValue,
-        max_tags: &JsValue,
-        url_to_fetch: &JsValue,
-        auth_header: &JsValue,
-        case_sensitive: &JsValue,
-        data_item_value: &JsValue,
-        initial_value: &JsValue,
-    );
-    fn setup_static_autocomplete(
-        element: &Element,
-        callback: &JsValue,
-        max_tags: &JsValue,
-        case_sensitive: &JsValue,
-    );
-    fn detach_autocomplete(id: &JsValue);
+    let options_view = {
+        let option_id = option_id.clone();
+        move || {
+            let active = highlighted.get();
+            visible
+                .get()
+                .into_iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    let is_active = active == Some(index);
+                    let label = item.label.clone();
+                    view! {
+                        <a
+                            id=option_id(index)
+                            role="option"
+                            aria-selected=is_active.to_string()
+                            class=if is_active { "dropdown-item is-active" } else { "dropdown-item" }
+                            on:mousedown=move |ev: MouseEvent| {
+                                // Keep focus in the input so blur does not close the list first.
+                                ev.prevent_default();
+                                add(item.clone());
+                            }
+                        >
+                            {label}
+                        </a>
+                    }
+                })
+                .collect_view()
+        }
+    };
+
+    view! {
+        <div
+            class=root_class
+            attr:data-testid=move || data_testid.clone()
+            attr:data-cy=move || data_cy.clone()
+        >
+            <div class=move || {
+                if lookup.loading.get() { "control is-loading" } else { "control" }
+            }>
+                {tags_view}
+                <input
+                    id=id.clone()
+                    r#type="text"
+                    class=input_class
+                    placeholder=move || placeholder.get()
+                    autocomplete="off"
+                    role="combobox"
+                    aria-autocomplete="list"
+                    aria-controls=listbox_id.clone()
+                    aria-expanded=move || is_open().to_string()
+                    aria-activedescendant=move || highlighted.get().map(option_id.clone())
+                    prop:value=move || query.get()
+                    on:input=on_input
+                    on:keydown=on_keydown
+                    on:focus=move |_| open.set(true)
+                    on:blur=move |_| {
+                        open.set(false);
+                        highlighted.set(None);
+                    }
+                />
+            </div>
+            {move || {
+                lookup.error.get().map(|err| view! { <p class="help is-danger">{err}</p> })
+            }}
+            <div class="dropdown-menu" id=listbox_id role="listbox">
+                <div class="dropdown-content">{options_view}</div>
+            </div>
+        </div>
+    }
 }
 
 #[cfg(test)]
@@ -384,12 +478,83 @@ mod tests {
     }
 
     #[test]
-    fn renders_static_select_when_items_provided() {
+    fn highlight_wraps_in_both_directions() {
+        assert_eq!(next_index(None, 3, true), Some(0));
+        assert_eq!(next_index(None, 3, false), Some(2));
+        assert_eq!(next_index(Some(2), 3, true), Some(0));
+        assert_eq!(next_index(Some(0), 3, false), Some(2));
+        assert_eq!(next_index(Some(1), 0, true), None);
+    }
+
+    #[test]
+    fn renders_static_suggestions_as_listbox_options() {
         let html = view! {
             <AutoComplete
                 id="ac1".to_string()
                 items=vec!["A".to_string(), "B".to_string()]
                 placeholder="Choose"
+                on_update=noop()
+                on_remove=noop()
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"role="combobox""#) && html.contains(r#"aria-controls="ac1-listbox""#),
+            "expected combobox input; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"id="ac1-option-0""#) && html.contains(">B</a>"),
+            "expected options; got: {}",
+            html
+        );
+        assert!(
+            !html.contains("is-active") && !html.contains("data-type"),
+            "expected a closed native dropdown; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn new_prop_names_win_over_deprecated_aliases() {
+        assert_eq!(resolve_alias(Some(false), Some(true)), Some(false));
+        assert_eq!(
+            resolve_alias(Some("/new?q="), Some("/old?q=")),
+            Some("/new?q=")
+        );
+        assert_eq!(resolve_alias(None, Some("Bearer old")), Some("Bearer old"));
+        assert_eq!(resolve_alias::<bool>(None, None), None);
+
+        // Both spellings at once still render.
+        let html = view! {
+            <AutoComplete
+                id="ac-both".to_string()
+                url_for_fetch="/new?q="
+                _url_for_fetch="/old?q="
+                case_sensitive=false
+                _case_sensitive=true
+                auth_header="Bearer new"
+                _auth_header="Bearer old"
+            />
+        }
+        .to_html();
+        assert!(
+            html.contains(r#"aria-controls="ac-both-listbox""#),
+            "expected the component to render; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn deprecated_underscore_props_are_still_accepted() {
+        let html = view! {
+            <AutoComplete
+                id="ac-old".to_string()
+                current_selector="Rust"
+                _url_for_fetch="/api?q="
+                _auth_header="Bearer token"
+                _case_sensitive=true
                 _on_update=noop()
                 _on_remove=noop()
             />
@@ -397,35 +562,60 @@ mod tests {
         .to_html();
 
         assert!(
-            html.contains(r#"data-type="tags""#),
-            "expected tags select; got: {}",
+            html.contains(r#"aria-controls="ac-old-listbox""#) && html.contains("Rust"),
+            "expected the component to render; got: {}",
             html
         );
-        assert!(html.contains("<option"), "expected options; got: {}", html);
     }
 
     #[test]
-    fn renders_dynamic_input_with_data_attrs() {
+    fn renders_current_selector_as_removable_tag() {
         let html = view! {
             <AutoComplete
                 id="ac2".to_string()
+                current_selector="Rust"
                 data_item_text="name"
                 data_item_value="name"
-                _url_for_fetch="/api?q="
-                _on_update=noop()
-                _on_remove=noop()
+                url_for_fetch="/api?q="
+                on_update=noop()
+                on_remove=noop()
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"<div class="tags mb-1">"#) && html.contains("Rust"),
+            "expected initial tag; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"class="delete is-small""#),
+            "expected delete button; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn renders_source_mode_without_loading_or_error() {
+        let html = view! {
+            <AutoComplete
+                id="ac4".to_string()
+                source=crate::form::suggestion::LocalSource::new(["Rust", "Go"])
+                debounce_ms=0
+                on_update=noop()
+                on_remove=noop()
             />
         }
         .to_html();
 
         assert!(
-            html.contains(r#"data-item-text="name""#),
-            "expected data-item-text; got: {}",
+            html.contains(r#"<div class="control">"#),
+            "expected idle control; got: {}",
             html
         );
         assert!(
-            html.contains(r#"class="input""#),
-            "expected input class; got: {}",
+            !html.contains("is-danger") && !html.contains("ac4-option-0"),
+            "expected no error and no suggestions before typing; got: {}",
             html
         );
     }
@@ -436,8 +626,8 @@ mod tests {
             <AutoComplete
                 id="ac3".to_string()
                 placeholder="Type..."
-                _on_update=noop()
-                _on_remove=noop()
+                on_update=noop()
+                on_remove=noop()
             />
         }
         .to_html();
@@ -447,6 +637,11 @@ mod tests {
             "expected plain input; got: {}",
             html
         );
+        assert!(
+            !html.contains(r#"class="tags"#),
+            "expected no tags; got: {}",
+            html
+        );
     }
 }
 
@@ -470,8 +665,8 @@ mod wasm_tests {
                 id="ac1".to_string()
                 items=vec!["A".to_string(), "B".to_string()]
                 placeholder="Choose"
-                _on_update=noop()
-                _on_remove=noop()
+                on_update=noop()
+                on_remove=noop()
                 test_attr=TestAttr::test_id("autocomplete-test")
             />
         }
@@ -491,8 +686,8 @@ mod wasm_tests {
                 id="ac1".to_string()
                 items=vec!["A".to_string(), "B".to_string()]
                 placeholder="Choose"
-                _on_update=noop()
-                _on_remove=noop()
+                on_update=noop()
+                on_remove=noop()
             />
         }
         .to_html();
@@ -1,3 +1,4 @@
+use crate::form::select::{SelectOption, option_dom_value};
 use crate::util::TestAttr;
 use leptos::callback::Callback;
 use leptos::prelude::Callable;
@@ -7,13 +8,16 @@ use std::cell::Cell;
 #[allow(unused_imports)]
 use std::rc::Rc;
 
-/// The 2-state checkbox in its native Bulma format.
+/// The checkbox in its native Bulma format, with an optional indeterminate (mixed) state.
 ///
 /// https://bulma.io/documentation/form/checkbox/
 ///
 /// Controlled component:
 /// - `checked` is the current value (supports static bool or reactive signal).
 /// - `update` is an optional callback invoked with the next value when the user clicks.
+/// - `indeterminate` sets the DOM `indeterminate` property, e.g. for a "select all" box when only
+///   some children are checked; assistive tech announces it as mixed. Clicking still reports
+///   `checked`. The input also gets `data-indeterminate="true"` while set, for styling and SSR.
 ///
 #[component]
 pub fn Checkbox(
@@ -27,6 +31,14 @@ pub fn Checkbox(
     #[prop(into)]
     checked: Signal<bool>,
 
+    /// Show the mixed state; this only affects display, not `checked`.
+    #[prop(optional, into)]
+    indeterminate: Signal<bool>,
+
+    /// The `value` submitted with the form when checked (browsers default to "on").
+    #[prop(optional, into)]
+    value: Option<String>,
+
     /// Optional callback to propagate changes to the parent with the new value.
     #[prop(optional)]
     update: Option<Callback<bool>>,
@@ -73,8 +85,14 @@ pub fn Checkbox(
             <input
                 type="checkbox"
                 name=name.clone()
+                value=value
                 // Bind reactively so UI reflects changes
                 prop:checked=move || checked.get()
+                // `indeterminate` only exists as a DOM property; assistive tech reads it natively,
+                // so no `aria-checked` (ARIA in HTML forbids it on a native checkbox).
+                prop:indeterminate=move || indeterminate.get()
+                // Mirrors the property so SSR output and CSS selectors can see the state.
+                data-indeterminate=move || indeterminate.get().then_some("true")
                 // Bind disabled as an attribute so SSR renders it and it's still reactive at runtime
                 disabled=move || disabled.get()
                 // Notify parent about user interaction
@@ -89,6 +107,218 @@ pub fn Checkbox(
     }
 }
 
+/// How the checkboxes of a [`CheckboxGroup`] are laid out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GroupLayout {
+    /// Side by side, wrapping as needed (Bulma's `checkboxes` container).
+    #[default]
+    Inline,
+    /// One per line.
+    Stacked,
+}
+
+impl GroupLayout {
+    pub(crate) fn class(self, base: &str) -> String {
+        match self {
+            GroupLayout::Inline => base.to_string(),
+            GroupLayout::Stacked => format!(
+                "{} is-flex-direction-column is-align-items-flex-start",
+                base
+            ),
+        }
+    }
+}
+
+/// State of a "select all" box: `(checked, indeterminate)` over the enabled options.
+pub(crate) fn select_all_state<T: PartialEq>(
+    options: &[SelectOption<T>],
+    chosen: &[T],
+) -> (bool, bool) {
+    let enabled = options.iter().filter(|option| !option.disabled);
+    let (total, picked) = enabled.fold((0, 0), |(total, picked), option| {
+        (
+            total + 1,
+            picked + usize::from(chosen.contains(&option.value)),
+        )
+    });
+    (total > 0 && picked == total, picked > 0 && picked < total)
+}
+
+/// The chosen values after clicking "select all": clears the enabled options when all of them
+/// are chosen, otherwise adds the missing ones. Disabled options keep their state.
+pub(crate) fn toggle_all<T: Clone + PartialEq>(
+    options: &[SelectOption<T>],
+    chosen: &[T],
+) -> Vec<T> {
+    let (all, _) = select_all_state(options, chosen);
+    let enabled = || options.iter().filter(|option| !option.disabled);
+    if all {
+        chosen
+            .iter()
+            .filter(|value| !enabled().any(|option| option.value == **value))
+            .cloned()
+            .collect()
+    } else {
+        let mut next = chosen.to_vec();
+        next.extend(
+            enabled()
+                .filter(|option| !chosen.contains(&option.value))
+                .map(|option| option.value.clone()),
+        );
+        next
+    }
+}
+
+/// A group of checkboxes bound to a list of chosen values, with an optional "select all" box.
+///
+/// Controlled component: `value` holds the chosen option values and `update` receives the new
+/// list.
+///
+/// **Native form posts:** each checkbox submits its option index as its `value`, under the
+/// group `name`, unless `form_value` is given; a plain HTML form submission (one not handled
+/// by `Form`) then sends `0`, `2`, ... instead of your values. Pass `form_value` to submit a
+/// string form of each value.
+///
+/// The "select all" box is shown when `select_all` is given; it is checked when every enabled
+/// option is chosen and indeterminate when only some are.
+#[component]
+pub fn CheckboxGroup<T>(
+    /// The `name` attribute shared by the checkboxes.
+    #[prop(into)]
+    name: String,
+
+    /// The options to render as checkboxes.
+    #[prop(into)]
+    options: Signal<Vec<SelectOption<T>>>,
+
+    /// The chosen values.
+    #[prop(into)]
+    value: Signal<Vec<T>>,
+
+    /// Called with the new list of chosen values.
+    update: Callback<Vec<T>>,
+
+    /// Maps an option value to the checkbox `value` a native form post submits. Values must map
+    /// to distinct strings. Defaults to the option index.
+    #[prop(optional, into)]
+    form_value: Option<Callback<T, String>>,
+
+    /// Label of the "select all" box; no such box is rendered when omitted.
+    #[prop(optional, into)]
+    select_all: Option<Signal<String>>,
+
+    /// Inline (default) or stacked layout.
+    #[prop(optional)]
+    layout: GroupLayout,
+
+    /// Extra classes for the group container.
+    #[prop(optional, into)]
+    classes: Signal<String>,
+
+    /// Disable every checkbox in the group.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Optional test attribute (renders as data-* attribute) on the group container.
+    ///
+    /// When provided as a &str or String, this becomes `data-testid="value"`.
+    /// You can also pass a full `TestAttr` to override the attribute key.
+    #[prop(optional, into)]
+    test_attr: Option<TestAttr>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let class = move || {
+        let base = layout.class("checkboxes");
+        let extra = classes.get();
+        if extra.trim().is_empty() {
+            base
+        } else {
+            format!("{} {}", base, extra)
+        }
+    };
+
+    let toggle = move |index: usize, on: bool| {
+        let Some(option) = options.with_untracked(|options| options.get(index).cloned()) else {
+            return;
+        };
+        let mut chosen = value.get_untracked();
+        chosen.retain(|v| *v != option.value);
+        if on {
+            chosen.push(option.value);
+        }
+        update.run(chosen);
+    };
+
+    let all_state =
+        move || options.with(|options| value.with(|chosen| select_all_state(options, chosen)));
+
+    let select_all_view = select_all.map(|label| {
+        view! {
+            <Checkbox
+                name=""
+                checked=Signal::derive(move || all_state().0)
+                indeterminate=Signal::derive(move || all_state().1)
+                update=Callback::new(move |_: bool| {
+                    let next = options.with_untracked(|options| {
+                        value.with_untracked(|chosen| toggle_all(options, chosen))
+                    });
+                    update.run(next);
+                })
+                classes="has-text-weight-semibold"
+                disabled=disabled
+            >
+                {move || label.get()}
+            </Checkbox>
+        }
+    });
+
+    let items = move || {
+        options.with(|options| {
+            options
+                .iter()
+                .enumerate()
+                .map(|(index, option)| {
+                    let dom_value = option_dom_value(form_value, index, option);
+                    let option_value = option.value.clone();
+                    let option_disabled = option.disabled;
+                    let label = option.label.clone();
+                    view! {
+                        <Checkbox
+                            name=name.clone()
+                            value=dom_value
+                            checked=Signal::derive(move || value.with(|chosen| chosen.contains(&option_value)))
+                            update=Callback::new(move |on: bool| toggle(index, on))
+                            disabled=Signal::derive(move || option_disabled || disabled.get())
+                        >
+                            {label}
+                        </Checkbox>
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    view! {
+        <div
+            class=class
+            role="group"
+            attr:data-testid=data_testid
+            attr:data-cy=data_cy
+        >
+            {select_all_view}
+            {items}
+        </div>
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,6 +357,161 @@ mod tests {
         );
     }
 
+    #[test]
+    fn checkbox_indeterminate_relies_on_the_native_state() {
+        let html = view! {
+            <Checkbox name="all" checked=false indeterminate=true value="x">"All"</Checkbox>
+        }
+        .to_html();
+        assert!(
+            html.contains(r#"type="checkbox""#) && html.contains(r#"value="x""#),
+            "expected a native checkbox with its value in: {}",
+            html
+        );
+        assert!(
+            !html.contains("aria-checked"),
+            "expected no aria-checked on the native checkbox in: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"data-indeterminate="true""#),
+            "expected the indeterminate hook in: {}",
+            html
+        );
+
+        let html = view! { <Checkbox name="all" checked=true>"All"</Checkbox> }.to_html();
+        assert!(
+            !html.contains("data-indeterminate"),
+            "expected no indeterminate hook by default in: {}",
+            html
+        );
+    }
+
+    fn permissions() -> Vec<SelectOption<&'static str>> {
+        let mut options = SelectOption::from_pairs([("read", "Read"), ("write", "Write")]);
+        options.push(SelectOption::new("admin", "Admin").with_disabled(true));
+        options
+    }
+
+    #[test]
+    fn select_all_state_ignores_disabled_options() {
+        let options = permissions();
+        assert_eq!(select_all_state(&options, &[]), (false, false));
+        assert_eq!(select_all_state(&options, &["read"]), (false, true));
+        assert_eq!(
+            select_all_state(&options, &["write", "read"]),
+            (true, false)
+        );
+        assert_eq!(select_all_state(&options, &["admin"]), (false, false));
+    }
+
+    #[test]
+    fn toggle_all_keeps_disabled_choices() {
+        let options = permissions();
+        assert_eq!(
+            toggle_all(&options, &["admin", "write"]),
+            vec!["admin", "write", "read"]
+        );
+        assert_eq!(
+            toggle_all(&options, &["read", "admin", "write"]),
+            vec!["admin"]
+        );
+    }
+
+    #[test]
+    fn checkbox_group_renders_options_and_select_all() {
+        let html = view! {
+            <CheckboxGroup
+                name="perm"
+                options=permissions()
+                value=vec!["read"]
+                update=Callback::new(|_: Vec<&'static str>| {})
+                select_all="All"
+                layout=GroupLayout::Stacked
+                test_attr="perms"
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains("checkboxes is-flex-direction-column")
+                && html.contains(r#"role="group""#),
+            "expected stacked group container in: {}",
+            html
+        );
+        assert!(
+            html.contains(">All<") && !html.contains("aria-checked"),
+            "expected a native select-all checkbox in: {}",
+            html
+        );
+        assert_eq!(
+            html.matches(r#"name="perm""#).count(),
+            3,
+            "expected one checkbox per option in: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"value="2""#) && html.contains(">Admin<"),
+            "expected index values and labels in: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"data-testid="perms""#),
+            "expected test id on group in: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn checkbox_group_submits_form_value_strings_when_given() {
+        let html = view! {
+            <CheckboxGroup
+                name="perm"
+                options=permissions()
+                value=vec!["read"]
+                update=Callback::new(|_: Vec<&'static str>| {})
+                form_value=|permission: &'static str| permission.to_string()
+            />
+        }
+        .to_html();
+
+        for permission in ["read", "write", "admin"] {
+            assert!(
+                html.contains(&format!(r#"value="{}""#, permission)),
+                "expected value {} in: {}",
+                permission,
+                html
+            );
+        }
+        assert!(
+            !html.contains(r#"value="0""#),
+            "expected no index values in: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn checkbox_group_disabled_disables_every_box() {
+        let none: Vec<&'static str> = Vec::new();
+        let html = view! {
+            <CheckboxGroup
+                name="perm"
+                options=permissions()
+                value=none
+                update=Callback::new(|_: Vec<&'static str>| {})
+                select_all="All"
+                disabled=true
+            />
+        }
+        .to_html();
+        assert_eq!(
+            html.matches("disabled").count(),
+            4,
+            "expected all four inputs disabled in: {}",
+            html
+        );
+    }
+
     #[test]
     fn checkbox_can_be_disabled() {
         let html =
@@ -142,6 +527,7 @@ mod tests {
 #[cfg(all(test, target_arch = "wasm32"))]
 mod wasm_tests {
     use super::*;
+    use crate::form::select::SelectOption;
     use crate::util::TestAttr;
     use leptos::prelude::*;
     use wasm_bindgen_test::*;
@@ -162,6 +548,29 @@ mod wasm_tests {
         );
     }
 
+    #[wasm_bindgen_test]
+    fn checkbox_sets_the_indeterminate_property() {
+        use leptos::wasm_bindgen::JsCast;
+
+        let document = document();
+        let parent = document.create_element("div").unwrap();
+        document.body().unwrap().append_child(&parent).unwrap();
+        let _handle = leptos::mount::mount_to(parent.clone().unchecked_into(), || {
+            view! { <Checkbox name="all" checked=false indeterminate=true>"All"</Checkbox> }
+        });
+
+        let input: leptos::web_sys::HtmlInputElement = parent
+            .query_selector("input")
+            .unwrap()
+            .unwrap()
+            .unchecked_into();
+        assert!(
+            input.indeterminate(),
+            "expected the DOM indeterminate property"
+        );
+        assert!(!input.checked());
+    }
+
     #[wasm_bindgen_test]
     fn checkbox_no_test_attr_when_not_provided() {
         let html = view! {
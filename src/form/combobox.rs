@@ -0,0 +1,624 @@
+/*!
+Combobox component: a searchable select for long option lists, styled with Bulma.
+
+Typing filters the options (case-insensitive, matching anywhere in the label); matches are shown
+in a scrollable Bulma dropdown. The input follows the WAI-ARIA combobox pattern: `role="combobox"`,
+`aria-expanded`, `aria-controls` pointing at the `role="listbox"`, and `aria-activedescendant`
+naming the highlighted `role="option"`.
+
+Modes
+- Single (default): the input shows the label of the chosen option. Clearing the text and
+  leaving the field clears the value.
+- Multiple (`multiple=true`): chosen options are shown as tags in front of the input; picking an
+  option toggles it and keeps the list open. Backspace in an empty input removes the last tag.
+
+Keyboard
+- ArrowDown/ArrowUp move the highlight (opening the list), Home/End jump to the first/last match.
+- Enter picks the highlighted option, Escape closes the list (or clears the typed text when closed).
+- Disabled options are shown but skipped.
+*/
+
+use leptos::callback::Callback;
+use leptos::ev::{KeyboardEvent, MouseEvent};
+use leptos::prelude::{
+    AriaAttributes, Callable, ClassAttribute, CollectView, CustomAttribute, Effect, ElementChild,
+    Get, GetUntracked, GlobalAttributes, IntoAny, IntoView, Memo, OnAttribute, PropAttribute,
+    RwSignal, Set, Signal, StyleAttribute, With, WithUntracked, component, event_target_value,
+    view,
+};
+
+use crate::elements::delete::Delete;
+use crate::elements::tag::Tag;
+use crate::elements::tags::Tags;
+use crate::form::select::SelectOption;
+use crate::util::TestAttr;
+
+/// Indices of the options whose label contains `query` (case-insensitive); all for a blank query.
+pub(crate) fn filter_options<T>(options: &[SelectOption<T>], query: &str) -> Vec<usize> {
+    let needle = query.trim().to_lowercase();
+    options
+        .iter()
+        .enumerate()
+        .filter(|(_, option)| needle.is_empty() || option.label.to_lowercase().contains(&needle))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// New highlight position among the matches for a navigation key, skipping disabled entries.
+///
+/// `enabled[i]` tells whether the i-th match can be highlighted. Arrow keys wrap around.
+pub(crate) fn move_highlight(current: Option<usize>, enabled: &[bool], key: &str) -> Option<usize> {
+    let len = enabled.len();
+    let first = enabled.iter().position(|e| *e);
+    let last = enabled.iter().rposition(|e| *e);
+    let step = |forward: bool| {
+        let start = match (current, forward) {
+            (None, true) => len - 1,
+            (None, false) => 0,
+            (Some(index), _) => index,
+        };
+        (1..=len)
+            .map(|offset| {
+                if forward {
+                    (start + offset) % len
+                } else {
+                    (start + len - offset % len) % len
+                }
+            })
+            .find(|index| enabled[*index])
+    };
+    match key {
+        _ if first.is_none() => None,
+        "ArrowDown" => step(true),
+        "ArrowUp" => step(false),
+        "Home" => first,
+        "End" => last,
+        _ => current,
+    }
+}
+
+/// A searchable select with single- and multi-value modes.
+///
+/// Controlled component: `value` holds the chosen option values (at most one unless `multiple`)
+/// and `update` receives the new list.
+#[component]
+pub fn Combobox<T>(
+    /// Unique DOM id for the input; the list uses `{id}-listbox` and options `{id}-option-{index}`.
+    #[prop(into)]
+    id: String,
+
+    /// The options to choose from. Options with a `group` get a heading in the list.
+    #[prop(into)]
+    options: Signal<Vec<SelectOption<T>>>,
+
+    /// The chosen values.
+    #[prop(into)]
+    value: Signal<Vec<T>>,
+
+    /// Called with the new list of chosen values.
+    update: Callback<Vec<T>>,
+
+    /// Allow choosing several options (shown as tags).
+    #[prop(optional)]
+    multiple: bool,
+
+    /// Placeholder to show in the input.
+    #[prop(optional, into)]
+    placeholder: Signal<String>,
+
+    /// Label for a chosen value that matches no option, e.g. after the options changed or
+    /// while they are still loading. Such values render with an empty label when omitted.
+    #[prop(optional, into)]
+    value_label: Option<Callback<T, String>>,
+
+    /// Text shown when no option matches. Defaults to "No matches".
+    #[prop(optional, into)]
+    no_results: Option<Signal<String>>,
+
+    /// Extra classes appended to the input.
+    #[prop(optional, into)]
+    classes: Signal<String>,
+
+    /// Number of options visible before the list scrolls. Defaults to 8.
+    #[prop(optional)]
+    list_size: Option<u32>,
+
+    /// Display a loading spinner within the input.
+    #[prop(optional, into)]
+    loading: Signal<bool>,
+
+    /// Disable the input.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Optional test attribute (renders as data-* attribute) on the wrapper.
+    ///
+    /// When provided as a &str or String, this becomes `data-testid="value"`.
+    /// You can also pass a full `TestAttr` to override the attribute key (e.g., `data-cy`).
+    #[prop(optional, into)]
+    test_attr: Option<TestAttr>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    // `None` while the user is not typing: single mode then shows the chosen label.
+    let query = RwSignal::new(None::<String>);
+    let open = RwSignal::new(false);
+    // Position within `matches`, not an option index.
+    let highlighted = RwSignal::new(None::<usize>);
+
+    let matches = Memo::new(move |_| {
+        let text = query.get().unwrap_or_default();
+        options.with(|options| filter_options(options, &text))
+    });
+
+    let is_chosen =
+        move |option: &SelectOption<T>| value.with(|chosen| chosen.contains(&option.value));
+
+    let label_of = move |chosen: &T| {
+        options
+            .with(|options| {
+                options
+                    .iter()
+                    .find(|option| option.value == *chosen)
+                    .map(|option| option.label.clone())
+            })
+            .or_else(|| value_label.map(|label| label.run(chosen.clone())))
+    };
+    let chosen_label = move || value.with(|chosen| chosen.first().and_then(label_of));
+
+    let close = move || {
+        open.set(false);
+        highlighted.set(None);
+    };
+
+    let pick = move |index: usize| {
+        let Some(option) = options.with_untracked(|options| options.get(index).cloned()) else {
+            return;
+        };
+        if option.disabled {
+            return;
+        }
+        if multiple {
+            let mut chosen = value.get_untracked();
+            match chosen.iter().position(|v| *v == option.value) {
+                Some(position) => {
+                    chosen.remove(position);
+                }
+                None => chosen.push(option.value),
+            }
+            query.set(Some(String::new()));
+            update.run(chosen);
+        } else {
+            query.set(None);
+            close();
+            update.run(vec![option.value]);
+        }
+    };
+
+    let remove_at = move |position: usize| {
+        if disabled.get_untracked() {
+            return;
+        }
+        let mut chosen = value.get_untracked();
+        if position < chosen.len() {
+            chosen.remove(position);
+            update.run(chosen);
+        }
+    };
+
+    let enabled_matches = move || {
+        matches.with_untracked(|matches| {
+            options.with_untracked(|options| {
+                matches
+                    .iter()
+                    .map(|index| !options[*index].disabled)
+                    .collect::<Vec<_>>()
+            })
+        })
+    };
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        let key = ev.key();
+        match key.as_str() {
+            "ArrowDown" | "ArrowUp" | "Home" | "End" => {
+                if !open.get_untracked() && matches!(key.as_str(), "Home" | "End") {
+                    return;
+                }
+                ev.prevent_default();
+                open.set(true);
+                highlighted.set(move_highlight(
+                    highlighted.get_untracked(),
+                    &enabled_matches(),
+                    &key,
+                ));
+            }
+            "Enter" => {
+                if let Some(position) = highlighted.get_untracked()
+                    && open.get_untracked()
+                {
+                    ev.prevent_default();
+                    if let Some(index) = matches.with_untracked(|m| m.get(position).copied()) {
+                        pick(index);
+                    }
+                }
+            }
+            "Escape" => {
+                if open.get_untracked() {
+                    close();
+                } else {
+                    query.set(None);
+                }
+            }
+            "Backspace"
+                if multiple && query.with_untracked(|q| q.as_deref().unwrap_or("").is_empty()) =>
+            {
+                let len = value.with_untracked(Vec::len);
+                if len > 0 {
+                    remove_at(len - 1);
+                }
+            }
+            _ => {}
+        }
+    };
+
+    let on_input = move |ev| {
+        query.set(Some(event_target_value(&ev)));
+        open.set(true);
+        highlighted.set(move_highlight(None, &enabled_matches(), "Home"));
+    };
+
+    let on_blur = move |_| {
+        close();
+        let cleared = query.with_untracked(|q| q.as_deref().is_some_and(|t| t.trim().is_empty()));
+        if !multiple && cleared && value.with_untracked(|v| !v.is_empty()) {
+            update.run(Vec::new());
+        }
+        query.set(None);
+    };
+
+    let option_id = {
+        let id = id.clone();
+        move |index: usize| format!("{}-option-{}", id, index)
+    };
+
+    // Keep the highlighted option visible in the scrollable list.
+    Effect::new({
+        let option_id = option_id.clone();
+        move |_| {
+            let Some(position) = highlighted.get() else {
+                return;
+            };
+            if let Some(index) = matches.with_untracked(|m| m.get(position).copied())
+                && let Some(element) =
+                    leptos::prelude::document().get_element_by_id(&option_id(index))
+            {
+                element.scroll_into_view_with_bool(false);
+            }
+        }
+    });
+
+    let input_class = move || {
+        let extra = classes.get();
+        if extra.trim().is_empty() {
+            "input".to_string()
+        } else {
+            format!("input {}", extra)
+        }
+    };
+
+    let input_text = move || match query.get() {
+        Some(text) => text,
+        None if multiple => String::new(),
+        None => chosen_label().unwrap_or_default(),
+    };
+
+    let listbox_id = format!("{}-listbox", id);
+    // A dropdown item is about 2rem tall.
+    let listbox_style = format!(
+        "max-height: {}rem; overflow-y: auto",
+        list_size.unwrap_or(8).max(1) * 2
+    );
+    let active_descendant = {
+        let option_id = option_id.clone();
+        move || {
+            let position = highlighted.get()?;
+            matches
+                .with(|m| m.get(position).copied())
+                .map(option_id.clone())
+        }
+    };
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    let tags_view = move || {
+        if !multiple {
+            return None;
+        }
+        // Positions index `value` itself, so unmatched values keep their place.
+        let labels: Vec<String> = value.with(|chosen| {
+            chosen
+                .iter()
+                .map(|v| label_of(v).unwrap_or_default())
+                .collect()
+        });
+        (!labels.is_empty()).then(|| {
+            view! {
+                <Tags classes="mb-1">
+                    {labels
+                        .into_iter()
+                        .enumerate()
+                        .map(|(position, label)| {
+                            view! {
+                                <Tag>
+                                    {label}
+                                    <Delete
+                                        tag="a"
+                                        classes="is-small"
+                                        on_click=Callback::new(move |_: MouseEvent| remove_at(position))
+                                    />
+                                </Tag>
+                            }
+                        })
+                        .collect_view()}
+                </Tags>
+            }
+        })
+    };
+
+    let options_view = {
+        let option_id = option_id.clone();
+        move || {
+            let active = highlighted.get();
+            let matches = matches.get();
+            if matches.is_empty() {
+                let text = no_results
+                    .map(|text| text.get())
+                    .unwrap_or_else(|| "No matches".to_string());
+                return view! { <div class="dropdown-item has-text-grey">{text}</div> }.into_any();
+            }
+            options
+                .with(|options| {
+                    let mut previous_group: Option<&Option<String>> = None;
+                    matches
+                        .iter()
+                        .enumerate()
+                        .map(|(position, &index)| {
+                            let option = &options[index];
+                            let heading = (previous_group != Some(&option.group))
+                                .then(|| option.group.clone())
+                                .flatten()
+                                .map(|group| {
+                                    view! {
+                                        <p class="dropdown-item has-text-weight-semibold" role="presentation">
+                                            {group}
+                                        </p>
+                                    }
+                                });
+                            previous_group = Some(&option.group);
+                            let mut class = vec!["dropdown-item"];
+                            if active == Some(position) {
+                                class.push("is-active");
+                            }
+                            if option.disabled {
+                                class.push("has-text-grey-light");
+                            }
+                            let chosen = is_chosen(option);
+                            view! {
+                                {heading}
+                                <a
+                                    id=option_id(index)
+                                    role="option"
+                                    class=class.join(" ")
+                                    aria-selected=chosen.to_string()
+                                    aria-disabled=option.disabled.then_some("true")
+                                    on:mousedown=move |ev: MouseEvent| {
+                                        // Keep focus in the input so blur does not close the list first.
+                                        ev.prevent_default();
+                                        pick(index);
+                                    }
+                                >
+                                    {option.label.clone()}
+                                </a>
+                            }
+                        })
+                        .collect_view()
+                })
+                .into_any()
+        }
+    };
+
+    view! {
+        <div
+            class=move || {
+                if open.get() {
+                    "combobox dropdown is-block is-active"
+                } else {
+                    "combobox dropdown is-block"
+                }
+            }
+            attr:data-testid=data_testid
+            attr:data-cy=data_cy
+        >
+            <div class=move || if loading.get() { "control is-loading" } else { "control" }>
+                {tags_view}
+                <input
+                    id=id.clone()
+                    r#type="text"
+                    class=input_class
+                    placeholder=move || placeholder.get()
+                    disabled=move || disabled.get()
+                    autocomplete="off"
+                    role="combobox"
+                    aria-autocomplete="list"
+                    aria-controls=listbox_id.clone()
+                    aria-expanded=move || open.get().to_string()
+                    aria-activedescendant=active_descendant
+                    value=input_text
+                    prop:value=input_text
+                    on:input=on_input
+                    on:keydown=on_keydown
+                    on:click=move |_| open.set(true)
+                    on:blur=on_blur
+                />
+            </div>
+            <div class="dropdown-menu">
+                <div
+                    class="dropdown-content"
+                    id=listbox_id
+                    role="listbox"
+                    aria-multiselectable=multiple.then_some("true")
+                    style=listbox_style
+                >
+                    {options_view}
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::prelude::RenderHtml;
+
+    fn countries() -> Vec<SelectOption<&'static str>> {
+        let mut options = SelectOption::group("Europe", [("de", "Germany"), ("fr", "France")]);
+        options.extend(SelectOption::group(
+            "Americas",
+            [("ca", "Canada"), ("us", "United States")],
+        ));
+        options
+    }
+
+    #[test]
+    fn filter_is_case_insensitive_substring() {
+        let options = countries();
+        assert_eq!(filter_options(&options, ""), vec![0, 1, 2, 3]);
+        assert_eq!(filter_options(&options, "AN"), vec![0, 1, 2]);
+        assert_eq!(filter_options(&options, "states"), vec![3]);
+        assert!(filter_options(&options, "xyz").is_empty());
+    }
+
+    #[test]
+    fn highlight_skips_disabled_and_wraps() {
+        let enabled = [true, false, true];
+        assert_eq!(move_highlight(None, &enabled, "ArrowDown"), Some(0));
+        assert_eq!(move_highlight(Some(0), &enabled, "ArrowDown"), Some(2));
+        assert_eq!(move_highlight(Some(2), &enabled, "ArrowDown"), Some(0));
+        assert_eq!(move_highlight(None, &enabled, "ArrowUp"), Some(2));
+        assert_eq!(move_highlight(Some(2), &enabled, "ArrowUp"), Some(0));
+        assert_eq!(move_highlight(Some(0), &enabled, "End"), Some(2));
+        assert_eq!(
+            move_highlight(Some(2), &[false, true, true], "Home"),
+            Some(1)
+        );
+        assert_eq!(move_highlight(None, &[false, false], "ArrowDown"), None);
+        assert_eq!(move_highlight(None, &[], "ArrowDown"), None);
+    }
+
+    #[test]
+    fn combobox_single_shows_chosen_label_with_aria_wiring() {
+        let html = view! {
+            <Combobox
+                id="country"
+                options=countries()
+                value=vec!["fr"]
+                update=Callback::new(|_: Vec<&'static str>| {})
+                test_attr="country-box"
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"role="combobox""#)
+                && html.contains(r#"aria-controls="country-listbox""#)
+                && html.contains(r#"aria-expanded="false""#),
+            "expected combobox aria wiring; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"value="France""#),
+            "expected chosen label in the input; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"id="country-listbox" role="listbox""#),
+            "expected listbox; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"id="country-option-1" role="option" aria-selected="true""#),
+            "expected France marked selected; got: {}",
+            html
+        );
+        assert!(
+            html.contains(">Europe</p>") && html.contains(">Americas</p>"),
+            "expected group headings; got: {}",
+            html
+        );
+        assert!(html.contains(r#"data-testid="country-box""#));
+    }
+
+    #[test]
+    fn combobox_multiple_renders_tags() {
+        let html = view! {
+            <Combobox
+                id="visited"
+                options=countries()
+                value=vec!["de", "us"]
+                update=Callback::new(|_: Vec<&'static str>| {})
+                multiple=true
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"class="tags mb-1""#)
+                && html.contains("Germany")
+                && html.contains(r#"aria-multiselectable="true""#),
+            "expected tags and multiselectable listbox; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"value="""#),
+            "expected an empty filter input in multiple mode; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn combobox_keeps_a_tag_for_a_chosen_value_without_option() {
+        let html = view! {
+            <Combobox
+                id="visited"
+                options=countries()
+                value=vec!["xx", "us"]
+                update=Callback::new(|_: Vec<&'static str>| {})
+                multiple=true
+                value_label=|raw: &'static str| raw.to_string()
+            />
+        }
+        .to_html();
+
+        let unmatched = html.find(">xx<").expect("tag for the unmatched value");
+        let matched = html
+            .find(">United States<")
+            .expect("tag for the matched value");
+        assert!(
+            unmatched < matched,
+            "expected tags in value order; got: {}",
+            html
+        );
+        assert_eq!(
+            html.matches("class=\"delete is-small\"").count(),
+            2,
+            "expected a remove button per chosen value; got: {}",
+            html
+        );
+    }
+}
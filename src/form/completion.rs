@@ -0,0 +1,304 @@
+/*!
+Inline completion providers for `TextArea`'s GenAI mode.
+
+A [`CompletionProvider`] turns the text typed so far into a continuation, which `TextArea` shows as
+grey "ghost text" after the caret. Tab accepts it, Escape dismisses it. Plug in a local model, a
+remote API or a [`PhraseCompletions`] stub:
+
+```ignore
+let provider = PhraseCompletions::new(["Thank you for your message.", "Kind regards"]);
+view! { <TextArea name="reply" value=reply update=set_reply is_genai=true completion=provider /> }
+
+// Any async closure works as well:
+let model = move |text: String| async move { client.complete(&text).await.ok() };
+view! { <TextArea ... is_genai=true completion=model on_accept=log_accept on_reject=log_reject /> }
+```
+
+Lookups are debounced like `AutoComplete`'s and results of lookups superseded by newer input are
+discarded.
+*/
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use leptos::prelude::{GetUntracked, RwSignal, Set, With};
+
+/// The future returned by [`CompletionProvider::complete`]; `None` means "nothing to suggest".
+///
+/// Not `Send`: browser futures (fetch, timers) are single-threaded.
+pub type CompletionFuture = Pin<Box<dyn Future<Output = Option<String>>>>;
+
+/// Suggests a continuation of the typed text.
+pub trait CompletionProvider: Send + Sync + 'static {
+    /// Returns the text to insert after `text` (the content before the caret), if any.
+    fn complete(&self, text: &str) -> CompletionFuture;
+}
+
+impl<F, Fut> CompletionProvider for F
+where
+    F: Fn(String) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Option<String>> + 'static,
+{
+    fn complete(&self, text: &str) -> CompletionFuture {
+        Box::pin(self(text.to_string()))
+    }
+}
+
+/// A shared, type-erased provider as accepted by `TextArea`'s `completion` prop.
+#[derive(Clone)]
+pub struct CompletionProviderRef(Arc<dyn CompletionProvider>);
+
+impl CompletionProviderRef {
+    pub fn new<P: CompletionProvider>(provider: P) -> Self {
+        Self(Arc::new(provider))
+    }
+
+    /// Returns the text to insert after `text`, if any.
+    pub fn complete(&self, text: &str) -> CompletionFuture {
+        self.0.complete(text)
+    }
+}
+
+impl<P: CompletionProvider> From<P> for CompletionProviderRef {
+    fn from(provider: P) -> Self {
+        CompletionProviderRef::new(provider)
+    }
+}
+
+impl std::fmt::Debug for CompletionProviderRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CompletionProviderRef")
+    }
+}
+
+/// Completes the words at the end of the text from a fixed list of phrases.
+///
+/// The longest run of whole words at the end of the text that starts one of the phrases wins;
+/// matching is case-sensitive. Meant for demos and tests.
+#[derive(Clone, Debug, Default)]
+pub struct PhraseCompletions {
+    phrases: Vec<String>,
+}
+
+impl PhraseCompletions {
+    pub fn new<I, S>(phrases: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            phrases: phrases.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// The rest of the first phrase started by the end of `text`.
+    pub fn suggest(&self, text: &str) -> Option<String> {
+        // Word starts, from the longest tail to the shortest.
+        let mut starts = text.char_indices().filter_map(|(index, ch)| {
+            let at_word_start = index == 0
+                || text[..index]
+                    .chars()
+                    .next_back()
+                    .is_some_and(char::is_whitespace);
+            (at_word_start && !ch.is_whitespace()).then_some(index)
+        });
+        starts.find_map(|start| {
+            let tail = &text[start..];
+            self.phrases
+                .iter()
+                .find(|phrase| phrase.len() > tail.len() && phrase.starts_with(tail))
+                .map(|phrase| phrase[tail.len()..].to_string())
+        })
+    }
+}
+
+impl CompletionProvider for PhraseCompletions {
+    fn complete(&self, text: &str) -> CompletionFuture {
+        let found = self.suggest(text);
+        Box::pin(async move { found })
+    }
+}
+
+/// A suggestion shown after `prefix`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Ghost {
+    /// The text the suggestion continues.
+    pub prefix: String,
+    /// The part still to be inserted.
+    pub text: String,
+    /// The suggestion as the provider returned it, reported to the accept/reject callbacks.
+    pub offered: String,
+}
+
+/// What typing did to a shown [`Ghost`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Followed {
+    /// The user typed the start of the suggestion; the rest is still shown.
+    Kept(Ghost),
+    /// The user typed the whole suggestion.
+    Completed(String),
+    /// The user typed something else or deleted text.
+    Diverged(String),
+}
+
+/// Updates `ghost` for the new `text` of the textarea.
+pub(crate) fn follow_ghost(ghost: Ghost, text: &str) -> Followed {
+    let Some(typed) = text.strip_prefix(ghost.prefix.as_str()) else {
+        return Followed::Diverged(ghost.offered);
+    };
+    match ghost.text.strip_prefix(typed) {
+        Some("") => Followed::Completed(ghost.offered),
+        Some(rest) => Followed::Kept(Ghost {
+            prefix: text.to_string(),
+            text: rest.to_string(),
+            offered: ghost.offered,
+        }),
+        None => Followed::Diverged(ghost.offered),
+    }
+}
+
+/// Reactive state of the completions requested by a textarea.
+///
+/// Like the suggestion `Lookup`, every request gets a generation number and only the latest one
+/// may show its result.
+#[derive(Clone, Copy)]
+pub(crate) struct GhostText {
+    generation: RwSignal<u64>,
+    pub ghost: RwSignal<Option<Ghost>>,
+}
+
+impl GhostText {
+    pub fn new() -> Self {
+        Self {
+            generation: RwSignal::new(0),
+            ghost: RwSignal::new(None),
+        }
+    }
+
+    /// Invalidates earlier requests and returns the generation of the next one.
+    pub fn begin(&self) -> u64 {
+        let next = self.generation.get_untracked() + 1;
+        self.generation.set(next);
+        next
+    }
+
+    pub fn is_current(&self, generation: u64) -> bool {
+        self.generation.get_untracked() == generation
+    }
+
+    /// Removes and returns the shown suggestion, cancelling pending requests.
+    pub fn take(&self) -> Option<Ghost> {
+        self.begin();
+        let ghost = self.ghost.get_untracked();
+        if ghost.is_some() {
+            self.ghost.set(None);
+        }
+        ghost
+    }
+
+    /// The suggestion to show after `text`, if there is one for exactly that text.
+    pub fn visible_after(&self, text: &str) -> Option<String> {
+        self.ghost.with(|ghost| {
+            ghost
+                .as_ref()
+                .filter(|ghost| ghost.prefix == text)
+                .map(|ghost| ghost.text.clone())
+        })
+    }
+
+    /// Asks `provider` to continue `text`, unless a newer request has started in the meantime.
+    pub async fn run(self, provider: CompletionProviderRef, text: String, generation: u64) {
+        if !self.is_current(generation) {
+            return;
+        }
+        let result = provider.complete(&text).await;
+        if !self.is_current(generation) {
+            return;
+        }
+        self.ghost.set(
+            result
+                .filter(|suggestion| !suggestion.is_empty())
+                .map(|suggestion| Ghost {
+                    prefix: text,
+                    text: suggestion.clone(),
+                    offered: suggestion,
+                }),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    fn ghost(prefix: &str, text: &str) -> Ghost {
+        Ghost {
+            prefix: prefix.to_string(),
+            text: text.to_string(),
+            offered: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn phrases_complete_the_trailing_words() {
+        let phrases = PhraseCompletions::new(["Kind regards", "See you later"]);
+        assert_eq!(
+            phrases.suggest("Thanks!\nKind re"),
+            Some("gards".to_string())
+        );
+        assert_eq!(phrases.suggest("Bye. See you l"), Some("ater".to_string()));
+        assert_eq!(phrases.suggest("Kind regards"), None, "nothing left to add");
+        assert_eq!(phrases.suggest("unkind re"), None, "must start at a word");
+        assert_eq!(phrases.suggest(""), None);
+    }
+
+    #[test]
+    fn typing_follows_or_breaks_the_ghost() {
+        assert_eq!(
+            follow_ghost(ghost("Kind re", "gards"), "Kind reg"),
+            Followed::Kept(Ghost {
+                prefix: "Kind reg".to_string(),
+                text: "ards".to_string(),
+                offered: "gards".to_string(),
+            })
+        );
+        assert_eq!(
+            follow_ghost(ghost("Kind re", "gards"), "Kind regards"),
+            Followed::Completed("gards".to_string())
+        );
+        assert_eq!(
+            follow_ghost(ghost("Kind re", "gards"), "Kind rex"),
+            Followed::Diverged("gards".to_string())
+        );
+        assert_eq!(
+            follow_ghost(ghost("Kind re", "gards"), "Kind r"),
+            Followed::Diverged("gards".to_string())
+        );
+    }
+
+    #[test]
+    fn ghost_text_shows_latest_result_for_its_prefix_only() {
+        let state = GhostText::new();
+        let stub = CompletionProviderRef::new(|text: String| async move {
+            (text != "none").then(|| format!("<{}>", text))
+        });
+
+        let stale = state.begin();
+        let current = state.begin();
+        block_on(state.run(stub.clone(), "old".to_string(), stale));
+        assert_eq!(state.ghost.get_untracked(), None, "superseded request");
+
+        block_on(state.run(stub.clone(), "new".to_string(), current));
+        assert_eq!(state.visible_after("new"), Some("<new>".to_string()));
+        assert_eq!(state.visible_after("newer"), None);
+
+        assert_eq!(state.take(), Some(ghost("new", "<new>")));
+        assert_eq!(state.take(), None);
+
+        let generation = state.begin();
+        block_on(state.run(stub, "none".to_string(), generation));
+        assert_eq!(state.ghost.get_untracked(), None);
+    }
+}
@@ -0,0 +1,649 @@
+/*!
+Form state container: `use_form`, `FormContext` and the `Form` component.
+
+Every LBC form control is controlled (`value` + `update`). `FormContext` keeps those
+values in one place, keyed by field name, and tracks per-field dirty/touched state and
+errors. `Form` provides the context to its children and collects all values on submit.
+
+Usage
+```ignore
+let form = use_form();
+let (email, set_email) = form.bind("email");
+
+view! {
+    <Form form=form on_submit=Callback::new(|values: FormValues| { /* send values */ })>
+        <Field label="Email" name="email">
+            <Control>
+                <Input name="email" value=email update=set_email />
+            </Control>
+        </Field>
+        <Button r#type="submit">"Save"</Button>
+    </Form>
+}
+```
+
+`Field name="..."` registers the field, marks it touched when focus leaves it, and shows
+the field's error from the context as its help text.
+*/
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use leptos::callback::Callback;
+use leptos::prelude::{
+    Callable, Children, ClassAttribute, CustomAttribute, ElementChild, Get, GetUntracked, IntoView,
+    OnAttribute, RwSignal, Set, Signal, StoredValue, Update, UpdateValue, WithUntracked, WithValue,
+    component, provide_context, use_context, view,
+};
+
+use crate::form::validation::{ValidateOn, ValidatorRef, run_validators};
+use crate::util::TestAttr;
+
+/// All values of a form, keyed by field name.
+pub type FormValues = BTreeMap<String, String>;
+
+/// Reactive state shared by a [`Form`] and its fields.
+///
+/// Cheap to copy: all state lives in signals, so the context can be captured freely
+/// by event handlers and closures.
+#[derive(Clone, Copy)]
+pub struct FormContext {
+    values: RwSignal<FormValues>,
+    initial: RwSignal<FormValues>,
+    touched: RwSignal<BTreeSet<String>>,
+    errors: RwSignal<BTreeMap<String, String>>,
+    validated: RwSignal<BTreeSet<String>>,
+    rules: StoredValue<BTreeMap<String, FieldRules>>,
+    submit_count: RwSignal<u32>,
+}
+
+/// Validators attached to one field.
+#[derive(Clone)]
+struct FieldRules {
+    validate_on: ValidateOn,
+    validators: Vec<ValidatorRef>,
+}
+
+impl Default for FormContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FormContext {
+    pub fn new() -> Self {
+        Self {
+            values: RwSignal::new(FormValues::new()),
+            initial: RwSignal::new(FormValues::new()),
+            touched: RwSignal::new(BTreeSet::new()),
+            errors: RwSignal::new(BTreeMap::new()),
+            validated: RwSignal::new(BTreeSet::new()),
+            rules: StoredValue::new(BTreeMap::new()),
+            submit_count: RwSignal::new(0),
+        }
+    }
+
+    /// Register a field with its initial value.
+    ///
+    /// Registering an already known field is a no-op, so both the control binding and the
+    /// surrounding `Field` may register the same name.
+    pub fn register(&self, name: impl Into<String>, initial: impl Into<String>) {
+        let name = name.into();
+        if self.is_registered(&name) {
+            return;
+        }
+        let initial = initial.into();
+        self.initial.update(|values| {
+            values.insert(name.clone(), initial.clone());
+        });
+        self.values.update(|values| {
+            values.insert(name, initial);
+        });
+    }
+
+    /// Forget a field: its value, initial value, touched state, error and validators.
+    ///
+    /// For fields that disappear from a form, such as the rows of a repeatable group.
+    pub fn unregister(&self, name: &str) {
+        if !self.is_registered(name) {
+            return;
+        }
+        self.initial.update(|values| {
+            values.remove(name);
+        });
+        self.values.update(|values| {
+            values.remove(name);
+        });
+        if self.touched.with_untracked(|set| set.contains(name)) {
+            self.touched.update(|set| {
+                set.remove(name);
+            });
+        }
+        if self.validated.with_untracked(|set| set.contains(name)) {
+            self.validated.update(|set| {
+                set.remove(name);
+            });
+        }
+        if self
+            .errors
+            .with_untracked(|errors| errors.contains_key(name))
+        {
+            self.set_error(name, None);
+        }
+        self.rules.update_value(|rules| {
+            rules.remove(name);
+        });
+    }
+
+    /// Returns true if a field with this name has been registered (untracked).
+    pub fn is_registered(&self, name: &str) -> bool {
+        self.initial
+            .with_untracked(|values| values.contains_key(name))
+    }
+
+    /// The current value of a field; empty for unknown fields.
+    pub fn value(&self, name: &str) -> String {
+        self.values.get().get(name).cloned().unwrap_or_default()
+    }
+
+    /// Set the value of a field, registering it with an empty initial value if needed.
+    pub fn set_value(&self, name: impl Into<String>, value: impl Into<String>) {
+        let name = name.into();
+        self.register(name.clone(), "");
+        let value = value.into();
+        self.values.update(|values| {
+            values.insert(name.clone(), value);
+        });
+
+        let has_error = self
+            .errors
+            .with_untracked(|errors| errors.contains_key(&name));
+        if has_error || self.validate_on(&name) == Some(ValidateOn::Input) {
+            self.validate_field(&name);
+        }
+    }
+
+    /// Snapshot of all field values (reactive).
+    pub fn values(&self) -> FormValues {
+        self.values.get()
+    }
+
+    /// Returns true if the field's value differs from its initial value.
+    pub fn is_dirty(&self, name: &str) -> bool {
+        let initial = self.initial.get().get(name).cloned().unwrap_or_default();
+        self.value(name) != initial
+    }
+
+    /// Returns true if any field differs from its initial value.
+    pub fn is_form_dirty(&self) -> bool {
+        self.values.get() != self.initial.get()
+    }
+
+    /// Mark a field as touched (the user has interacted with it and left it).
+    ///
+    /// Runs the field's validators unless it only validates on submit.
+    pub fn touch(&self, name: impl Into<String>) {
+        let name = name.into();
+        if matches!(
+            self.validate_on(&name),
+            Some(ValidateOn::Blur | ValidateOn::Input)
+        ) {
+            self.validate_field(&name);
+        }
+        if self.touched.with_untracked(|set| set.contains(&name)) {
+            return;
+        }
+        self.touched.update(|set| {
+            set.insert(name);
+        });
+    }
+
+    /// Returns true if the field has been touched.
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.touched.get().contains(name)
+    }
+
+    /// The current error message of a field, if any.
+    pub fn error(&self, name: &str) -> Option<String> {
+        self.errors.get().get(name).cloned()
+    }
+
+    /// Set or clear the error message of a field.
+    pub fn set_error(&self, name: impl Into<String>, error: Option<String>) {
+        let name = name.into();
+        self.errors.update(|errors| match error {
+            Some(message) => {
+                errors.insert(name, message);
+            }
+            None => {
+                errors.remove(&name);
+            }
+        });
+    }
+
+    /// Remove all error messages.
+    pub fn clear_errors(&self) {
+        self.errors.set(BTreeMap::new());
+    }
+
+    /// Returns true if any field currently has an error.
+    pub fn has_errors(&self) -> bool {
+        !self.errors.get().is_empty()
+    }
+
+    /// How many times the form has been submitted.
+    pub fn submit_count(&self) -> u32 {
+        self.submit_count.get()
+    }
+
+    /// Restore initial values and clear touched state and errors.
+    pub fn reset(&self) {
+        self.values.set(self.initial.get_untracked());
+        self.touched.set(BTreeSet::new());
+        self.errors.set(BTreeMap::new());
+        self.validated.set(BTreeSet::new());
+        self.submit_count.set(0);
+    }
+
+    /// Attach validators to a field, replacing any previous ones.
+    ///
+    /// `Field` calls this for its `validators` prop.
+    pub fn set_validators(
+        &self,
+        name: impl Into<String>,
+        validate_on: ValidateOn,
+        validators: Vec<ValidatorRef>,
+    ) {
+        let name = name.into();
+        self.register(name.clone(), "");
+        self.rules.update_value(|rules| {
+            rules.insert(
+                name,
+                FieldRules {
+                    validate_on,
+                    validators,
+                },
+            );
+        });
+    }
+
+    fn validate_on(&self, name: &str) -> Option<ValidateOn> {
+        self.rules
+            .with_value(|rules| rules.get(name).map(|rules| rules.validate_on))
+    }
+
+    /// Run a field's validators against its current value and store the result.
+    ///
+    /// Returns true when the field is valid (or has no validators).
+    pub fn validate_field(&self, name: &str) -> bool {
+        let Some(validators) = self
+            .rules
+            .with_value(|rules| rules.get(name).map(|rules| rules.validators.clone()))
+        else {
+            return true;
+        };
+        let value = self
+            .values
+            .with_untracked(|values| values.get(name).cloned().unwrap_or_default());
+        let result = run_validators(&validators, &value);
+
+        if !self.validated.with_untracked(|set| set.contains(name)) {
+            self.validated.update(|set| {
+                set.insert(name.to_string());
+            });
+        }
+        let current = self
+            .errors
+            .with_untracked(|errors| errors.get(name).cloned());
+        let next = result.as_ref().err().cloned();
+        if current != next {
+            self.set_error(name, next);
+        }
+        result.is_ok()
+    }
+
+    /// Validate every field that has validators. Returns true when all are valid.
+    pub fn validate_all(&self) -> bool {
+        let names = self
+            .rules
+            .with_value(|rules| rules.keys().cloned().collect::<Vec<_>>());
+        // Validate every field (no short-circuit) so all errors show at once.
+        let mut valid = true;
+        for name in &names {
+            valid &= self.validate_field(name);
+        }
+        valid
+    }
+
+    /// Mark the named fields as touched and run their validators.
+    ///
+    /// Returns true when all of them are valid. For validating part of a form, such as one
+    /// step of a wizard.
+    pub fn validate_fields<I, S>(&self, names: I) -> bool
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut valid = true;
+        for name in names {
+            let name = name.as_ref();
+            if !self.touched.with_untracked(|set| set.contains(name)) {
+                self.touched.update(|set| {
+                    set.insert(name.to_string());
+                });
+            }
+            valid &= self.validate_field(name);
+        }
+        valid
+    }
+
+    /// Bulma color class for a control bound to this field.
+    ///
+    /// `is-danger` while the field has an error, `is-success` once its validators passed,
+    /// `None` before validation or for fields without validators.
+    pub fn validation_class(&self, name: &str) -> Option<&'static str> {
+        if self.errors.get().contains_key(name) {
+            Some("is-danger")
+        } else if self.validated.get().contains(name) {
+            Some("is-success")
+        } else {
+            None
+        }
+    }
+
+    /// Returns a value signal and an update callback for wiring a control to a field.
+    ///
+    /// The field is registered with an empty initial value if it is not known yet.
+    pub fn bind(&self, name: impl Into<String>) -> (Signal<String>, Callback<String>) {
+        let name = name.into();
+        self.register(name.clone(), "");
+        let form = *self;
+        let read_name = name.clone();
+        (
+            Signal::derive(move || form.value(&read_name)),
+            Callback::new(move |value: String| form.set_value(name.clone(), value)),
+        )
+    }
+
+    /// Like [`bind`](Self::bind), for boolean controls such as `Checkbox`.
+    ///
+    /// The value is stored as `"true"` / `"false"`.
+    pub fn bind_bool(&self, name: impl Into<String>) -> (Signal<bool>, Callback<bool>) {
+        let name = name.into();
+        self.register(name.clone(), "false");
+        let form = *self;
+        let read_name = name.clone();
+        (
+            Signal::derive(move || form.value(&read_name) == "true"),
+            Callback::new(move |checked: bool| form.set_value(name.clone(), checked.to_string())),
+        )
+    }
+
+    /// Mark every registered field as touched, run all validators and bump the submit counter.
+    ///
+    /// Returns the collected values when the form has no errors.
+    pub fn submit(&self) -> Option<FormValues> {
+        let names = self
+            .initial
+            .with_untracked(|values| values.keys().cloned().collect::<BTreeSet<_>>());
+        self.touched.update(|set| set.extend(names));
+        self.validate_all();
+        self.submit_count.update(|count| *count += 1);
+
+        if self.errors.get_untracked().is_empty() {
+            Some(self.values.get_untracked())
+        } else {
+            None
+        }
+    }
+}
+
+/// Create a new form state container.
+///
+/// Pass it to [`Form`] via `form=` and use [`FormContext::bind`] to wire controls.
+pub fn use_form() -> FormContext {
+    FormContext::new()
+}
+
+/// Returns the `FormContext` provided by the nearest enclosing [`Form`], if any.
+pub fn use_form_context() -> Option<FormContext> {
+    use_context::<FormContext>()
+}
+
+/// An HTML form that provides a [`FormContext`] to its children.
+///
+/// On submit the browser default is prevented, all registered fields are marked touched,
+/// and `on_submit` receives the collected values unless a field has an error.
+#[component]
+pub fn Form(
+    /// The state container; a new one is created when omitted.
+    #[prop(optional)]
+    form: Option<FormContext>,
+
+    /// Called with all field values when the form is submitted without errors.
+    #[prop(optional)]
+    on_submit: Option<Callback<FormValues>>,
+
+    /// Extra classes to apply to the <form>.
+    #[prop(optional, into)]
+    classes: Signal<String>,
+
+    /// Optional test attribute (renders as data-* attribute) on the <form>.
+    ///
+    /// When provided as a &str or String, this becomes `data-testid="value"`.
+    /// You can also pass a full `TestAttr` to override the attribute key.
+    #[prop(optional, into)]
+    test_attr: Option<TestAttr>,
+
+    /// Form content: typically `Field`s and a submit `Button`.
+    children: Children,
+) -> impl IntoView {
+    let form = form.unwrap_or_default();
+    provide_context::<FormContext>(form);
+
+    let class = move || {
+        let extra = classes.get();
+        (!extra.trim().is_empty()).then_some(extra)
+    };
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    let on_form_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        if let Some(values) = form.submit() {
+            crate::lbc_log!("<Form> submit with {} values", values.len());
+            if let Some(on_submit) = on_submit {
+                on_submit.run(values);
+            }
+        }
+    };
+
+    view! {
+        <form
+            class=class
+            novalidate=true
+            attr:data-testid=move || data_testid.clone()
+            attr:data-cy=move || data_cy.clone()
+            on:submit=on_form_submit
+        >
+            {children()}
+        </form>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::form::control::Control;
+    use crate::form::field::Field;
+    use crate::form::input::Input;
+    use crate::form::validation::{min_length, range, required};
+    use leptos::prelude::RenderHtml;
+
+    #[test]
+    fn form_context_tracks_values_and_dirty_state() {
+        let form = use_form();
+        form.register("email", "a@b.c");
+        form.register("email", "ignored");
+
+        assert_eq!(form.value("email"), "a@b.c");
+        assert!(!form.is_dirty("email"));
+        assert!(!form.is_form_dirty());
+
+        form.set_value("email", "x@y.z");
+        assert!(form.is_dirty("email"));
+        assert!(form.is_form_dirty());
+
+        form.reset();
+        assert_eq!(form.value("email"), "a@b.c");
+        assert!(!form.is_form_dirty());
+    }
+
+    #[test]
+    fn form_context_bind_reads_and_writes_values() {
+        let form = use_form();
+        let (value, update) = form.bind("name");
+        let (checked, toggle) = form.bind_bool("agree");
+
+        update.run("Ada".to_string());
+        toggle.run(true);
+
+        assert_eq!(value.get_untracked(), "Ada");
+        assert!(checked.get_untracked());
+        assert_eq!(form.value("agree"), "true");
+    }
+
+    #[test]
+    fn form_context_unregister_forgets_the_field() {
+        let form = use_form();
+        form.set_validators("row", ValidateOn::Blur, vec![required()]);
+        form.touch("row");
+        assert!(form.error("row").is_some());
+
+        form.unregister("row");
+        assert!(!form.is_registered("row"));
+        assert!(!form.is_touched("row"));
+        assert!(!form.has_errors());
+        assert!(form.submit().is_some(), "its validators are gone");
+    }
+
+    #[test]
+    fn form_context_validates_a_subset_of_fields() {
+        let form = use_form();
+        form.set_validators("email", ValidateOn::Submit, vec![required()]);
+        form.set_validators("city", ValidateOn::Submit, vec![required()]);
+
+        assert!(!form.validate_fields(["email"]));
+        assert!(form.is_touched("email"));
+        assert!(form.error("email").is_some());
+        assert_eq!(form.error("city"), None, "other fields are left alone");
+        assert!(!form.is_touched("city"));
+
+        form.set_value("email", "a@b.c");
+        assert!(form.validate_fields(["email"]));
+    }
+
+    #[test]
+    fn form_context_submit_touches_fields_and_respects_errors() {
+        let form = use_form();
+        form.register("a", "1");
+        form.register("b", "2");
+
+        form.set_error("a", Some("Required".to_string()));
+        assert_eq!(form.submit(), None);
+        assert!(form.is_touched("a") && form.is_touched("b"));
+        assert_eq!(form.submit_count(), 1);
+
+        form.set_error("a", None);
+        let values = form.submit().expect("no errors left");
+        assert_eq!(values.get("a").map(String::as_str), Some("1"));
+        assert_eq!(values.get("b").map(String::as_str), Some("2"));
+    }
+
+    #[test]
+    fn form_context_runs_validators_per_trigger() {
+        let form = use_form();
+        form.set_validators("name", ValidateOn::Blur, vec![required()]);
+        form.set_validators("code", ValidateOn::Input, vec![min_length(3)]);
+        form.set_validators("age", ValidateOn::Submit, vec![range(Some(18.0), None)]);
+
+        // Input-triggered validation runs immediately.
+        form.set_value("code", "ab");
+        assert!(form.error("code").is_some());
+        assert_eq!(form.validation_class("code"), Some("is-danger"));
+
+        // Blur-triggered validation waits for touch.
+        form.set_value("name", "");
+        assert_eq!(form.error("name"), None);
+        form.touch("name");
+        assert!(form.error("name").is_some());
+
+        // Submit-triggered validation waits for submit; blur does nothing.
+        form.set_value("age", "12");
+        form.touch("age");
+        assert_eq!(form.error("age"), None);
+        assert_eq!(form.submit(), None);
+        assert!(form.error("age").is_some());
+
+        // Fixing a value clears its error on the next input.
+        form.set_value("name", "Ada");
+        form.set_value("code", "abc");
+        form.set_value("age", "30");
+        assert!(!form.has_errors());
+        assert_eq!(form.validation_class("name"), Some("is-success"));
+        assert!(form.submit().is_some());
+    }
+
+    #[test]
+    fn form_renders_form_element_with_children() {
+        let html = view! {
+            <Form classes="my-form">
+                <p>"Inside"</p>
+            </Form>
+        }
+        .to_html();
+
+        assert!(
+            html.contains("<form") && html.contains(r#"class="my-form""#),
+            "expected form element with classes; got: {}",
+            html
+        );
+        assert!(html.contains("Inside"), "expected children; got: {}", html);
+    }
+
+    #[test]
+    fn field_shows_error_from_form_context() {
+        // Context lookups need a reactive owner.
+        let owner = leptos::prelude::Owner::new();
+        let html = owner.with(|| {
+            let form = use_form();
+            let (value, update) = form.bind("email");
+            form.set_error("email", Some("Email is required".to_string()));
+
+            view! {
+                <Form form=form>
+                    <Field label="Email" name="email" help="We never share it">
+                        <Control>
+                            <Input name="email" value=value update=update />
+                        </Control>
+                    </Field>
+                </Form>
+            }
+            .to_html()
+        });
+
+        assert!(
+            html.contains(r#"class="help is-danger""#) && html.contains("Email is required"),
+            "expected context error as help; got: {}",
+            html
+        );
+        assert!(
+            !html.contains("We never share it"),
+            "expected error to replace help text; got: {}",
+            html
+        );
+    }
+}
@@ -1,8 +1,13 @@
+use std::fmt;
+
 use leptos::prelude::{
-    Children, ClassAttribute, CustomAttribute, ElementChild, Get, IntoAny, IntoView, Signal,
-    component, view,
+    Children, ClassAttribute, CustomAttribute, ElementChild, Get, IntoAny, IntoView, OnAttribute,
+    Owner, RwSignal, Set, Signal, component, provide_context, use_context, view,
 };
+use leptos::tachys::reactive_graph::OwnedView;
 
+use crate::form::context::use_form_context;
+use crate::form::validation::{ValidateOn, ValidatorRef};
 use crate::util::TestAttr;
 
 /// Alignment options available for field addons (Bulma).
@@ -55,14 +60,87 @@ impl LabelSize {
     }
 }
 
+/// A character count against a limit, shown as `count/max` (e.g. `123/500`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CharCount {
+    pub count: usize,
+    pub max: usize,
+}
+
+impl CharCount {
+    /// Whether the count is over the limit.
+    pub fn exceeded(&self) -> bool {
+        self.count > self.max
+    }
+}
+
+impl fmt::Display for CharCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.count, self.max)
+    }
+}
+
+/// Provided by [`Field`] to the controls inside it, so they can show status in its help area.
+#[derive(Clone, Copy)]
+pub struct FieldContext {
+    counter: RwSignal<Option<CharCount>>,
+}
+
+impl FieldContext {
+    /// Shows `counter` in the field's help area (right-aligned, `is-danger` when exceeded);
+    /// `None` removes it.
+    pub fn set_counter(&self, counter: Option<CharCount>) {
+        self.counter.set(counter);
+    }
+}
+
+/// The help line showing a [`CharCount`].
+pub(crate) fn counter_help(counter: CharCount) -> impl IntoView {
+    let class = if counter.exceeded() {
+        "help has-text-right is-danger"
+    } else {
+        "help has-text-right"
+    };
+    view! { <p class=class>{counter.to_string()}</p> }
+}
+
+/// The [`FieldContext`] of the enclosing [`Field`], if any.
+pub fn use_field_context() -> Option<FieldContext> {
+    use_context::<FieldContext>()
+}
+
 /// A container for form controls (Bulma "field").
 ///
 /// Mirrors Bulma's field structure, including optional label and help,
 /// addon/grouping modifiers, and horizontal layout support.
 ///
+/// Inside a [`Form`](crate::form::context::Form), setting `name` registers the field
+/// with the form, marks it touched when focus leaves it, and shows the field's error
+/// from the form context in place of `help`. `validators` are attached to the field
+/// and run according to `validate_on`.
+///
+/// Controls do not report their values to the field, so outside a `Form` (or without `name`)
+/// there is nothing to validate: `validators` and `validate_on` are ignored, with a warning in
+/// debug builds.
+///
+/// Controls inside the field can publish a character counter through [`FieldContext`]; it is
+/// shown under the help text.
+///
 /// https://bulma.io/documentation/form/general/
 #[component]
 pub fn Field(
+    /// The form field this container belongs to (see `FormContext`).
+    #[prop(optional, into)]
+    name: Option<String>,
+
+    /// Validators for this field; requires `name` and an enclosing `Form` (ignored otherwise).
+    #[prop(optional)]
+    validators: Vec<ValidatorRef>,
+
+    /// When the validators run. Defaults to `ValidateOn::Blur`.
+    #[prop(optional)]
+    validate_on: ValidateOn,
+
     /// Extra classes added to "field".
     #[prop(optional, into)]
     classes: Signal<String>,
@@ -132,6 +210,22 @@ pub fn Field(
     let addons_align = addons_align;
     let grouped_align = grouped_align;
 
+    let field_context = FieldContext {
+        counter: RwSignal::new(None),
+    };
+    // Provide the context in a child owner so controls after this field do not pick it up.
+    let children = move || match Owner::current() {
+        Some(parent) => {
+            let owner = parent.child();
+            let view = owner.with(|| {
+                provide_context(field_context);
+                children()
+            });
+            OwnedView::new_with_owner(view, owner).into_any()
+        }
+        None => children().into_any(),
+    };
+
     // Build main field class string.
     let class = move || {
         let mut parts = vec!["field".to_string()];
@@ -211,25 +305,56 @@ pub fn Field(
         }
     };
 
-    // Build optional help node.
+    let form = use_form_context();
+    if let (Some(form), Some(name)) = (form, &name) {
+        form.register(name.clone(), "");
+        if !validators.is_empty() {
+            form.set_validators(name.clone(), validate_on, validators);
+        }
+    } else if !validators.is_empty() {
+        #[cfg(debug_assertions)]
+        leptos::logging::warn!(
+            "<Field name={:?}> validators are ignored: they need `name` and an enclosing <Form>",
+            name
+        );
+    }
+    let form_error = {
+        let name = name.clone();
+        move || {
+            form.zip(name.as_ref())
+                .and_then(|(form, name)| form.error(name))
+        }
+    };
+
+    // Build optional help node; an error from the form context takes precedence.
     let help_node = {
         let help = help.clone();
         let help_classes = help_classes.clone();
         let help_has_error = help_has_error.clone();
 
         move || {
-            help.as_ref().map(|help_signal| {
-                let mut class_parts = vec!["help".to_string()];
-                let extra = help_classes.get();
-                if !extra.trim().is_empty() {
-                    class_parts.push(extra);
-                }
-                if help_has_error.get() {
-                    class_parts.push("is-danger".to_string());
-                }
-                let cls = class_parts.join(" ");
-                view! { <p class=cls>{help_signal.get()}</p> }.into_view()
-            })
+            let error = form_error();
+            let has_error = error.is_some() || help_has_error.get();
+            let text = error.or_else(|| help.as_ref().map(|help_signal| help_signal.get()))?;
+
+            let mut class_parts = vec!["help".to_string()];
+            let extra = help_classes.get();
+            if !extra.trim().is_empty() {
+                class_parts.push(extra);
+            }
+            if has_error {
+                class_parts.push("is-danger".to_string());
+            }
+            let cls = class_parts.join(" ");
+            Some(view! { <p class=cls>{text}</p> })
+        }
+    };
+
+    let counter_node = move || field_context.counter.get().map(counter_help);
+
+    let on_focusout = move |_ev: leptos::ev::FocusEvent| {
+        if let (Some(form), Some(name)) = (form, &name) {
+            form.touch(name.clone());
         }
     };
 
@@ -256,10 +381,12 @@ pub fn Field(
             class=class
             attr:data-testid=move || data_testid.clone()
             attr:data-cy=move || data_cy.clone()
+            on:focusout=on_focusout
         >
             {label_node()}
             {body()}
-            {help_node()}
+            {help_node}
+            {counter_node}
         </div>
     }
 }
@@ -268,7 +395,7 @@ pub fn Field(
 mod tests {
     use super::*;
     use crate::form::prelude::Control;
-    use leptos::prelude::RenderHtml;
+    use leptos::prelude::{GetUntracked, RenderHtml};
 
     #[test]
     fn field_renders_default_class_and_children() {
@@ -318,6 +445,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn field_context_does_not_leak_to_siblings() {
+        let owner = leptos::prelude::Owner::new();
+        owner.with(|| {
+            let inside = RwSignal::new(false);
+            let after = RwSignal::new(true);
+            let _ = view! {
+                <Field>
+                    {
+                        inside.set(use_field_context().is_some());
+                    }
+                </Field>
+                {
+                    after.set(use_field_context().is_some());
+                }
+            }
+            .to_html();
+            assert!(
+                inside.get_untracked(),
+                "expected the context inside the field"
+            );
+            assert!(
+                !after.get_untracked(),
+                "expected no context after the field"
+            );
+        });
+    }
+
     #[test]
     fn field_horizontal_wraps_label_and_body() {
         let html = view! {
@@ -1,30 +1,247 @@
 use leptos::callback::Callback;
-#[allow(unused_imports)]
-use leptos::prelude::Effect;
 use leptos::prelude::{
-    Callable, ClassAttribute, CustomAttribute, ElementChild, GetUntracked, IntoAny, IntoView,
-    OnAttribute, Signal, component, view,
+    Callable, ClassAttribute, CustomAttribute, ElementChild, Get, GetUntracked, GetValue, IntoView,
+    OnAttribute, Set, SetValue, Signal, StoredValue, StyleAttribute, UpdateValue, component,
+    on_cleanup, signal, view,
 };
-#[allow(unused_imports)]
-use std::cell::Cell;
-#[allow(unused_imports)]
-use std::rc::Rc;
 
+use crate::elements::image::Image;
+use crate::util::{Size, TestAttr};
+
+/// A file selected through the [`File`] component.
+///
+/// Name, size and MIME type are available on every target so SSR code can
+/// render them. On wasm32 the underlying `web_sys::File` handle is kept as
+/// well and can be passed to `FormData`, `FileReader` or `fetch` for upload.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LbcFile {
+    name: String,
+    size: u64,
+    mime_type: String,
+    preview_url: Option<String>,
+    #[cfg(target_arch = "wasm32")]
+    raw: Option<leptos::web_sys::File>,
+}
+
+impl LbcFile {
+    /// Creates a file description without a browser handle (useful for SSR and tests).
+    pub fn new<N: Into<String>, M: Into<String>>(name: N, size: u64, mime_type: M) -> Self {
+        Self {
+            name: name.into(),
+            size,
+            mime_type: mime_type.into(),
+            preview_url: None,
+            #[cfg(target_arch = "wasm32")]
+            raw: None,
+        }
+    }
+
+    /// Wraps a browser `File` handle, capturing its metadata.
+    #[cfg(target_arch = "wasm32")]
+    pub fn from_sys(file: leptos::web_sys::File) -> Self {
+        Self {
+            name: file.name(),
+            size: file.size() as u64,
+            mime_type: file.type_(),
+            preview_url: None,
+            raw: Some(file),
+        }
+    }
+
+    /// The file name, without any path information.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The file size in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// The MIME type reported by the browser (may be empty when unknown).
+    pub fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+
+    /// Whether the MIME type marks this file as an image.
+    pub fn is_image(&self) -> bool {
+        self.mime_type.starts_with("image/")
+    }
+
+    /// A URL that can be used as an `<img src>` thumbnail for this file.
+    ///
+    /// Set by [`File`] for accepted images when `previews` is enabled.
+    pub fn preview_url(&self) -> Option<&str> {
+        self.preview_url.as_deref()
+    }
+
+    /// Returns this file with the given thumbnail URL attached.
+    pub fn with_preview_url<U: Into<String>>(mut self, url: U) -> Self {
+        self.preview_url = Some(url.into());
+        self
+    }
+
+    /// The underlying browser handle, if this file came from a real `<input type="file">`.
+    #[cfg(target_arch = "wasm32")]
+    pub fn as_sys(&self) -> Option<&leptos::web_sys::File> {
+        self.raw.as_ref()
+    }
+}
+
+/// Reads the selected files from a change event on an `<input type="file">`.
 #[cfg(target_arch = "wasm32")]
-type LbcSysFile = ();
+fn event_target_files(ev: &leptos::web_sys::Event) -> Vec<LbcFile> {
+    use leptos::wasm_bindgen::JsCast;
+
+    let Some(input) = ev
+        .target()
+        .and_then(|target| target.dyn_into::<leptos::web_sys::HtmlInputElement>().ok())
+    else {
+        return Vec::new();
+    };
+    let Some(list) = input.files() else {
+        return Vec::new();
+    };
+    (0..list.length())
+        .filter_map(|index| list.get(index))
+        .map(LbcFile::from_sys)
+        .collect()
+}
+
+/// Without a DOM there is nothing to read; change events never fire outside the browser.
 #[cfg(not(target_arch = "wasm32"))]
-type LbcSysFile = ();
+fn event_target_files(_ev: &leptos::web_sys::Event) -> Vec<LbcFile> {
+    Vec::new()
+}
 
-use crate::util::{Size, TestAttr};
+/// Reads the dropped files from a drop event's `DataTransfer`.
+#[cfg(target_arch = "wasm32")]
+fn event_drop_files(ev: &leptos::ev::DragEvent) -> Vec<LbcFile> {
+    let Some(list) = ev.data_transfer().and_then(|transfer| transfer.files()) else {
+        return Vec::new();
+    };
+    (0..list.length())
+        .filter_map(|index| list.get(index))
+        .map(LbcFile::from_sys)
+        .collect()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn event_drop_files(_ev: &leptos::ev::DragEvent) -> Vec<LbcFile> {
+    Vec::new()
+}
+
+/// Creates an object URL for an image file so it can be shown as a thumbnail.
+#[cfg(target_arch = "wasm32")]
+fn create_preview_url(file: &LbcFile) -> Option<String> {
+    let raw = file.as_sys()?;
+    leptos::web_sys::Url::create_object_url_with_blob(raw).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn create_preview_url(_file: &LbcFile) -> Option<String> {
+    None
+}
+
+#[cfg(target_arch = "wasm32")]
+fn revoke_preview_url(url: &str) {
+    let _ = leptos::web_sys::Url::revoke_object_url(url);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn revoke_preview_url(_url: &str) {}
+
+/// Why a selected or dropped file was not accepted by [`File`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FileRejectionReason {
+    /// The file matches none of the entries in `accept`.
+    InvalidType,
+    /// The file is larger than `max_size` bytes.
+    TooLarge { max_size: u64 },
+    /// Accepting the file would exceed `max_files` (or 1 when `multiple` is false).
+    TooMany { max_files: usize },
+}
+
+/// A file that failed validation, together with the reason.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileRejection {
+    pub file: LbcFile,
+    pub reason: FileRejectionReason,
+}
+
+/// Checks a file against an HTML `accept` list such as `"image/*,.pdf"`.
+///
+/// An empty list accepts everything. Entries are matched case-insensitively as
+/// file extensions (`.pdf`), wildcard MIME types (`image/*`) or exact MIME types.
+fn matches_accept(file: &LbcFile, accept: &str) -> bool {
+    let name = file.name().to_lowercase();
+    let mime = file.mime_type().to_lowercase();
+    let mut entries = accept
+        .split(',')
+        .map(|entry| entry.trim().to_lowercase())
+        .filter(|entry| !entry.is_empty())
+        .peekable();
+    if entries.peek().is_none() {
+        return true;
+    }
+    entries.any(|entry| {
+        if entry.starts_with('.') {
+            name.ends_with(&entry)
+        } else if let Some(prefix) = entry.strip_suffix("/*") {
+            mime.split('/').next() == Some(prefix)
+        } else {
+            mime == entry
+        }
+    })
+}
+
+/// Splits files into accepted and rejected ones.
+///
+/// Type and size are checked first; the count limit then applies to the files
+/// that passed, in selection order.
+fn validate_files(
+    files: Vec<LbcFile>,
+    accept: &str,
+    max_size: Option<u64>,
+    max_files: Option<usize>,
+) -> (Vec<LbcFile>, Vec<FileRejection>) {
+    let mut accepted = Vec::new();
+    let mut rejected = Vec::new();
+    for file in files {
+        let reason = if !matches_accept(&file, accept) {
+            Some(FileRejectionReason::InvalidType)
+        } else if let Some(max_size) = max_size.filter(|max_size| file.size() > *max_size) {
+            Some(FileRejectionReason::TooLarge { max_size })
+        } else {
+            max_files
+                .filter(|max_files| accepted.len() >= *max_files)
+                .map(|max_files| FileRejectionReason::TooMany { max_files })
+        };
+        match reason {
+            Some(reason) => rejected.push(FileRejection { file, reason }),
+            None => accepted.push(file),
+        }
+    }
+    (accepted, rejected)
+}
 
 /// A custom file upload input in Bulma style.
 ///
 /// https://bulma.io/documentation/form/file/
 ///
 /// Controlled component:
-/// - `files` is the current value (supports static Vec<File> or reactive signal).
+/// - `files` is the current value (supports static Vec<LbcFile> or reactive signal).
 /// - `update` is a required callback invoked with the selected files on change.
 ///
+/// Selections are validated against `accept`, `max_size` and `max_files`; only
+/// accepted files reach `update`, the rest are reported through `on_reject`.
+/// When `multiple` is false at most one file is accepted.
+///
+/// Drop zone:
+/// - With `drop_zone=true` files can also be dropped onto the component (best with `boxed=true`).
+/// - While files are dragged over it, the root gets `is-dragover` plus `dragover_classes`.
+/// - With `previews=true`, accepted images are shown as thumbnails below the selector.
+///
 #[component]
 pub fn File(
     /// The `name` attribute for this form element.
@@ -33,12 +250,12 @@ pub fn File(
 
     /// The controlled list of selected files.
     ///
-    /// Accepts a Vec<File> (wasm32) or a placeholder Vec<()> (non-wasm) signal.
+    /// Used to render the selected file names when `has_name` is set.
     #[prop(into)]
-    _files: Signal<Vec<LbcSysFile>>,
+    files: Signal<Vec<LbcFile>>,
 
     /// Callback to propagate the selected files to the parent.
-    _update: Callback<Vec<LbcSysFile>>,
+    update: Callback<Vec<LbcFile>>,
 
     /// The display text for the file selector.
     #[prop(default = "Choose a file...".to_string().into(), into)]
@@ -48,7 +265,8 @@ pub fn File(
     #[prop(optional, into)]
     classes: Signal<String>,
 
-    /// If Some, adds `has-name` and shows a placeholder until files are selected.
+    /// If Some, adds `has-name` and shows this placeholder until files are selected;
+    /// afterwards the selected file names are shown instead.
     #[prop(optional, into)]
     has_name: Option<Signal<String>>,
 
@@ -72,6 +290,34 @@ pub fn File(
     #[prop(optional)]
     size: Option<Size>,
 
+    /// Accepted file types, as in the HTML `accept` attribute (e.g. `"image/*,.pdf"`).
+    #[prop(optional, into)]
+    accept: Signal<String>,
+
+    /// Maximum size of a single file in bytes.
+    #[prop(optional)]
+    max_size: Option<u64>,
+
+    /// Maximum number of files accepted from one selection.
+    #[prop(optional)]
+    max_files: Option<usize>,
+
+    /// Callback invoked with the files that failed validation.
+    #[prop(optional)]
+    on_reject: Option<Callback<Vec<FileRejection>>>,
+
+    /// Accept files dropped onto the component.
+    #[prop(optional, into)]
+    drop_zone: Signal<bool>,
+
+    /// Classes added to the root while files are dragged over the drop zone.
+    #[prop(default = "is-primary".to_string().into(), into)]
+    dragover_classes: Signal<String>,
+
+    /// Show thumbnails of the selected image files.
+    #[prop(optional, into)]
+    previews: Signal<bool>,
+
     /// Optional test attribute (renders as data-* attribute) on the root <div>.
     ///
     /// When provided as a &str or String, this becomes `data-testid="value"`.
@@ -87,8 +333,7 @@ pub fn File(
         class_parts.push(extra_classes);
     }
 
-    let has_name_text = has_name.as_ref().map(|signal| signal.get_untracked());
-    if has_name_text.is_some() {
+    if has_name.is_some() {
         class_parts.push("has-name".to_string());
     }
 
@@ -102,6 +347,11 @@ pub fn File(
         class_parts.push("is-boxed".to_string());
     }
 
+    // Bulma's `.file` is a flex row; wrapping lets the thumbnails take their own line below.
+    if previews.get_untracked() {
+        class_parts.push("is-flex-wrap-wrap".to_string());
+    }
+
     if let Some(size) = size {
         match size {
             Size::Small => class_parts.push("is-small".to_string()),
@@ -111,7 +361,17 @@ pub fn File(
         }
     }
 
-    let class = class_parts.join(" ");
+    let base_class = class_parts.join(" ");
+    let (dragover, set_dragover) = signal(false);
+    let class = move || {
+        if dragover.get() {
+            format!("{} is-dragover {}", base_class, dragover_classes.get())
+                .trim()
+                .to_string()
+        } else {
+            base_class.clone()
+        }
+    };
     let selector_label_text = selector_label.get_untracked();
     let is_multiple = multiple.get_untracked();
 
@@ -121,25 +381,149 @@ pub fn File(
         _ => (None, None),
     };
 
-    // NOTE:
-    // This crate currently aliases `LbcSysFile` to `()` for both wasm32 and non-wasm builds.
-    // Also, `HtmlInputElement::files()` requires enabling specific `web-sys` features; without
-    // them the method does not exist and the build fails.
-    //
-    // Until a real cross-platform file type is introduced (and web-sys features are enabled),
-    // we simply notify the parent that a change occurred with an empty Vec.
-    let on_change = {
-        let update = _update.clone();
-        move |_ev: leptos::web_sys::Event| {
-            update.run(Vec::<LbcSysFile>::new());
+    // Object URLs created for thumbnails; revoked when replaced or on unmount.
+    let preview_urls = StoredValue::new(Vec::<String>::new());
+    on_cleanup(move || {
+        preview_urls
+            .try_update_value(|urls| urls.drain(..).for_each(|url| revoke_preview_url(&url)));
+    });
+
+    let handle_selection = move |selected: Vec<LbcFile>| {
+        let max_files = if is_multiple { max_files } else { Some(1) };
+        let (accepted, rejected) =
+            validate_files(selected, &accept.get_untracked(), max_size, max_files);
+
+        let accepted = if previews.get_untracked() {
+            let mut created = Vec::new();
+            let with_previews = accepted
+                .into_iter()
+                .map(|file| {
+                    let url = if file.is_image() {
+                        create_preview_url(&file)
+                    } else {
+                        None
+                    };
+                    match url {
+                        Some(url) => {
+                            created.push(url.clone());
+                            file.with_preview_url(url)
+                        }
+                        None => file,
+                    }
+                })
+                .collect();
+            preview_urls.update_value(|urls| {
+                urls.drain(..).for_each(|url| revoke_preview_url(&url));
+                urls.extend(created);
+            });
+            with_previews
+        } else {
+            accepted
+        };
+
+        if !rejected.is_empty()
+            && let Some(on_reject) = on_reject
+        {
+            on_reject.run(rejected);
+        }
+        update.run(accepted);
+    };
+
+    let on_change = move |ev: leptos::web_sys::Event| {
+        handle_selection(event_target_files(&ev));
+    };
+
+    // Moving over a child fires `dragenter` on the child before `dragleave` on the parent, so
+    // count the nesting depth and only drop the highlight when the pointer leaves the zone.
+    let drag_depth = StoredValue::new(0_u32);
+
+    let on_dragenter = move |ev: leptos::ev::DragEvent| {
+        if drop_zone.get_untracked() {
+            ev.prevent_default();
+            drag_depth.update_value(|depth| *depth += 1);
+            set_dragover.set(true);
+        }
+    };
+
+    let on_dragover = move |ev: leptos::ev::DragEvent| {
+        if drop_zone.get_untracked() {
+            // Required so the browser allows dropping onto this element.
+            ev.prevent_default();
+        }
+    };
+
+    let on_dragleave = move |_ev: leptos::ev::DragEvent| {
+        drag_depth.update_value(|depth| *depth = depth.saturating_sub(1));
+        if drag_depth.get_value() == 0 {
+            set_dragover.set(false);
+        }
+    };
+
+    let on_drop = move |ev: leptos::ev::DragEvent| {
+        if drop_zone.get_untracked() {
+            ev.prevent_default();
+            drag_depth.set_value(0);
+            set_dragover.set(false);
+            handle_selection(event_drop_files(&ev));
         }
     };
 
+    let accept_attr = move || {
+        let value = accept.get();
+        (!value.trim().is_empty()).then_some(value)
+    };
+
+    let file_name = move || {
+        has_name.map(|placeholder| {
+            let names = files
+                .get()
+                .iter()
+                .map(|file| file.name().to_string())
+                .collect::<Vec<_>>();
+            let text = if names.is_empty() {
+                placeholder.get()
+            } else {
+                names.join(", ")
+            };
+            view! { <span class="file-name">{text}</span> }
+        })
+    };
+
+    let preview_list = move || {
+        if !previews.get() {
+            return None;
+        }
+        let thumbnails = files
+            .get()
+            .into_iter()
+            .filter_map(|file| {
+                let url = file.preview_url()?.to_string();
+                let alt = file.name().to_string();
+                Some(view! {
+                    <figure class="image is-64x64">
+                        <Image src=url alt=alt />
+                    </figure>
+                })
+            })
+            .collect::<Vec<_>>();
+        (!thumbnails.is_empty()).then(|| {
+            view! {
+                <div class="file-previews is-flex is-flex-wrap-wrap mt-2" style="flex-basis: 100%">
+                    {thumbnails}
+                </div>
+            }
+        })
+    };
+
     view! {
         <div
             class=class
             attr:data-testid=data_testid
             attr:data-cy=data_cy
+            on:dragenter=on_dragenter
+            on:dragover=on_dragover
+            on:dragleave=on_dragleave
+            on:drop=on_drop
         >
             <label class="file-label">
                 <input
@@ -147,6 +531,7 @@ pub fn File(
                     class="file-input"
                     name=name.clone()
                     multiple=is_multiple
+                    accept=accept_attr
                     on:change=on_change
                 />
                 <span class="file-cta">
@@ -156,14 +541,9 @@ pub fn File(
                     </span>
                 </span>
 
-                {
-                    if let Some(file_name_text) = has_name_text {
-                        view! { <span class="file-name">{file_name_text}</span> }.into_any()
-                    } else {
-                        view! { <></> }.into_any()
-                    }
-                }
+                {file_name}
             </label>
+            {preview_list}
         </div>
     }
 }
@@ -174,8 +554,8 @@ mod tests {
     use crate::util::Size;
     use leptos::prelude::RenderHtml;
 
-    fn noop_update() -> Callback<Vec<super::LbcSysFile>> {
-        Callback::new(|_files: Vec<super::LbcSysFile>| {})
+    fn noop_update() -> Callback<Vec<LbcFile>> {
+        Callback::new(|_files: Vec<LbcFile>| {})
     }
 
     #[test]
@@ -183,8 +563,8 @@ mod tests {
         let html = view! {
             <File
                 name="upload"
-                _files=Signal::derive(|| Vec::<super::LbcSysFile>::new())
-                _update=noop_update()
+                files=Signal::derive(Vec::<LbcFile>::new)
+                update=noop_update()
             />
         }
         .to_html();
@@ -206,8 +586,8 @@ mod tests {
         let html = view! {
             <File
                 name="upload"
-                _files=Signal::derive(|| Vec::<super::LbcSysFile>::new())
-                _update=noop_update()
+                files=Signal::derive(Vec::<LbcFile>::new)
+                update=noop_update()
                 size=Size::Small
                 right=true
                 fullwidth=true
@@ -237,6 +617,153 @@ mod tests {
             html
         );
     }
+
+    #[test]
+    fn file_shows_placeholder_until_files_selected() {
+        let html = view! {
+            <File
+                name="upload"
+                files=Signal::derive(Vec::<LbcFile>::new)
+                update=noop_update()
+                has_name="No file selected"
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains("has-name") && html.contains("No file selected"),
+            "expected has-name placeholder; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn file_shows_selected_file_names() {
+        let html = view! {
+            <File
+                name="upload"
+                files=vec![
+                    LbcFile::new("a.txt", 3, "text/plain"),
+                    LbcFile::new("b.png", 10, "image/png"),
+                ]
+                update=noop_update()
+                multiple=true
+                has_name="No file selected"
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains("a.txt, b.png") && !html.contains("No file selected"),
+            "expected selected file names; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn file_renders_accept_attribute() {
+        let html = view! {
+            <File
+                name="upload"
+                files=Signal::derive(Vec::<LbcFile>::new)
+                update=noop_update()
+                accept="image/*,.pdf"
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"accept="image/*,.pdf""#),
+            "expected accept attribute; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn file_renders_image_previews() {
+        let html = view! {
+            <File
+                name="upload"
+                files=vec![
+                    LbcFile::new("cat.png", 10, "image/png").with_preview_url("blob:cat"),
+                    LbcFile::new("notes.txt", 3, "text/plain"),
+                ]
+                update=noop_update()
+                boxed=true
+                drop_zone=true
+                previews=true
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"src="blob:cat""#) && html.contains(r#"alt="cat.png""#),
+            "expected image thumbnail; got: {}",
+            html
+        );
+        assert!(
+            !html.contains("notes.txt"),
+            "expected no thumbnail for non-image files; got: {}",
+            html
+        );
+        assert!(
+            html.starts_with(r#"<div class="file"#)
+                && html.contains("is-flex-wrap-wrap")
+                && html.ends_with("</div></div>")
+                && html.matches("<div").count() == 2,
+            "expected the thumbnails inside the single root element; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn accept_matches_extensions_and_mime_types() {
+        let png = LbcFile::new("Photo.PNG", 1, "image/png");
+        let pdf = LbcFile::new("doc.pdf", 1, "application/pdf");
+
+        assert!(matches_accept(&png, ""));
+        assert!(matches_accept(&png, "image/*"));
+        assert!(matches_accept(&png, ".png"));
+        assert!(matches_accept(&pdf, "image/*, application/pdf"));
+        assert!(!matches_accept(&pdf, "image/*,.txt"));
+    }
+
+    #[test]
+    fn validate_files_reports_typed_rejections() {
+        let files = vec![
+            LbcFile::new("a.png", 10, "image/png"),
+            LbcFile::new("b.exe", 10, "application/octet-stream"),
+            LbcFile::new("c.png", 5_000, "image/png"),
+            LbcFile::new("d.png", 10, "image/png"),
+            LbcFile::new("e.png", 10, "image/png"),
+        ];
+
+        let (accepted, rejected) = validate_files(files, "image/*", Some(1_000), Some(2));
+
+        let accepted_names: Vec<_> = accepted.iter().map(|file| file.name()).collect();
+        assert_eq!(accepted_names, vec!["a.png", "d.png"]);
+
+        let reasons: Vec<_> = rejected
+            .iter()
+            .map(|rejection| (rejection.file.name(), rejection.reason.clone()))
+            .collect();
+        assert_eq!(
+            reasons,
+            vec![
+                ("b.exe", FileRejectionReason::InvalidType),
+                ("c.png", FileRejectionReason::TooLarge { max_size: 1_000 }),
+                ("e.png", FileRejectionReason::TooMany { max_files: 2 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn lbc_file_exposes_metadata() {
+        let file = LbcFile::new("report.pdf", 2048, "application/pdf");
+        assert_eq!(file.name(), "report.pdf");
+        assert_eq!(file.size(), 2048);
+        assert_eq!(file.mime_type(), "application/pdf");
+    }
 }
 
 #[cfg(all(test, target_arch = "wasm32"))]
@@ -246,8 +773,8 @@ mod wasm_tests {
     use leptos::prelude::*;
     use wasm_bindgen_test::*;
 
-    fn noop_update() -> Callback<Vec<super::LbcSysFile>> {
-        Callback::new(|_files: Vec<super::LbcSysFile>| {})
+    fn noop_update() -> Callback<Vec<LbcFile>> {
+        Callback::new(|_files: Vec<LbcFile>| {})
     }
 
     wasm_bindgen_test_configure!(run_in_browser);
@@ -257,8 +784,8 @@ mod wasm_tests {
         let html = view! {
             <File
                 name="upload"
-                _files=Signal::derive(|| Vec::<super::LbcSysFile>::new())
-                _update=noop_update()
+                files=Signal::derive(Vec::<LbcFile>::new)
+                update=noop_update()
                 size=Size::Small
                 test_attr=TestAttr::test_id("file-test")
             />
@@ -277,8 +804,8 @@ mod wasm_tests {
         let html = view! {
             <File
                 name="upload"
-                _files=Signal::derive(|| Vec::<super::LbcSysFile>::new())
-                _update=noop_update()
+                files=Signal::derive(Vec::<LbcFile>::new)
+                update=noop_update()
             />
         }
         .to_html();
@@ -1,17 +1,21 @@
+use crate::form::context::use_form_context;
 use crate::lbc_log;
 use crate::util::{Size, TestAttr};
 use leptos::callback::Callback;
 use leptos::html;
 use leptos::prelude::PropAttribute;
 use leptos::prelude::{
-    Callable, ClassAttribute, CustomAttribute, Get, GetUntracked, IntoAny, IntoView, NodeRef,
-    NodeRefAttribute, Signal, component, view,
+    Callable, ClassAttribute, CustomAttribute, Get, GetUntracked, GlobalAttributes, IntoAny,
+    IntoView, NodeRef, NodeRefAttribute, Signal, component, view,
 };
 use leptos::prelude::{OnAttribute, event_target_value};
 
 use std::fmt;
 
-/// The 5 allowed types for an input component (Bulma-focused).
+/// The allowed types for an input component (Bulma-focused).
+///
+/// Covers every text-like HTML input type. Checkbox, radio and file inputs have their own
+/// components (`Checkbox`, `Radio`, `File`).
 /// https://bulma.io/documentation/form/input/
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum InputType {
@@ -20,6 +24,15 @@ pub enum InputType {
     Email,
     Tel,
     Number,
+    Search,
+    Url,
+    Date,
+    Time,
+    DateTimeLocal,
+    Month,
+    Week,
+    Color,
+    Range,
 }
 
 impl fmt::Display for InputType {
@@ -30,11 +43,75 @@ impl fmt::Display for InputType {
             InputType::Email => "email",
             InputType::Tel => "tel",
             InputType::Number => "number",
+            InputType::Search => "search",
+            InputType::Url => "url",
+            InputType::Date => "date",
+            InputType::Time => "time",
+            InputType::DateTimeLocal => "datetime-local",
+            InputType::Month => "month",
+            InputType::Week => "week",
+            InputType::Color => "color",
+            InputType::Range => "range",
         };
         write!(f, "{}", as_str)
     }
 }
 
+/// The `step` attribute of an [`Input`], kept as text so values such as `0.1` or large integer
+/// steps render exactly.
+///
+/// Converts from `f32`, `f64`, `&str` and `String`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputStep(String);
+
+impl InputStep {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for InputStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<f32> for InputStep {
+    fn from(step: f32) -> Self {
+        InputStep(step.to_string())
+    }
+}
+
+impl From<f64> for InputStep {
+    fn from(step: f64) -> Self {
+        InputStep(step.to_string())
+    }
+}
+
+impl From<String> for InputStep {
+    fn from(step: String) -> Self {
+        InputStep(step)
+    }
+}
+
+impl From<&str> for InputStep {
+    fn from(step: &str) -> Self {
+        InputStep(step.to_string())
+    }
+}
+
+/// Turns an empty string signal into "no attribute".
+fn optional_attr(signal: Signal<String>) -> impl Fn() -> Option<String> + Send + Sync + Copy {
+    move || {
+        let value = signal.get();
+        if value.trim().is_empty() {
+            None
+        } else {
+            Some(value)
+        }
+    }
+}
+
 fn size_class(size: Size) -> &'static str {
     match size {
         Size::Small => "is-small",
@@ -47,6 +124,9 @@ fn size_class(size: Size) -> &'static str {
 /// A text input element following Bulma styles.
 /// All LBC form components are controlled: the value is provided by a parent,
 /// and changes are propagated through the `update` callback.
+///
+/// Inside a `Form`, the input gets `is-danger`/`is-success` from the validation state of
+/// the field with the same `name`.
 #[component]
 pub fn Input(
     /// The `name` attribute for this form element.
@@ -96,9 +176,34 @@ pub fn Input(
     #[prop(optional, into)]
     r#static: Signal<bool>,
 
-    /// Step value for number input. If not provided, defaults to 1.0.
+    /// Step value for number/range input. If not provided, defaults to 1.
+    /// For date and time types it is only rendered when set.
+    #[prop(optional, into)]
+    step: Option<InputStep>,
+
+    /// The `min` attribute (number, range, date and time types).
+    #[prop(optional, into)]
+    min: Signal<String>,
+
+    /// The `max` attribute (number, range, date and time types).
+    #[prop(optional, into)]
+    max: Signal<String>,
+
+    /// The `pattern` attribute. Number inputs default to up to two decimal places.
+    #[prop(optional, into)]
+    pattern: Signal<String>,
+
+    /// The `autocomplete` attribute (e.g. `"email"`, `"current-password"`, `"off"`).
+    #[prop(optional, into)]
+    autocomplete: Signal<String>,
+
+    /// The `inputmode` attribute (e.g. `"numeric"`, `"decimal"`, `"tel"`).
+    #[prop(optional, into)]
+    inputmode: Signal<String>,
+
+    /// The `maxlength` attribute.
     #[prop(optional)]
-    step: Option<f32>,
+    maxlength: Option<u32>,
 
     /// Optional test attribute (renders as data-* attribute) on the <input>.
     ///
@@ -113,6 +218,8 @@ pub fn Input(
     // Avoid capturing reactive signals in event handlers; keep a plain String for logs/attrs.
     let name_for_logs = name.get_untracked();
 
+    let form = use_form_context();
+
     let class = {
         let classes = classes.clone();
         let rounded = rounded.clone();
@@ -137,11 +244,27 @@ pub fn Input(
             if r#static.get() {
                 parts.push("is-static".to_string());
             }
+            if let Some(color) = form.and_then(|form| form.validation_class(&name.get())) {
+                parts.push(color.to_string());
+            }
             parts.join(" ")
         }
     };
 
-    let numeric_step = step.unwrap_or(1.0).to_string();
+    let step_attr = match input_type {
+        InputType::Number | InputType::Range => {
+            Some(step.map(|step| step.0).unwrap_or_else(|| "1".to_string()))
+        }
+        _ => step.map(|step| step.0),
+    };
+    let min_attr = optional_attr(min);
+    let max_attr = optional_attr(max);
+    let autocomplete_attr = optional_attr(autocomplete);
+    let inputmode_attr = optional_attr(inputmode);
+    let maxlength_attr = maxlength.map(|maxlength| maxlength.to_string());
+    let number_pattern =
+        move || optional_attr(pattern)().unwrap_or_else(|| "[0-9]+([.][0-9]{0,2})?".to_string());
+    let pattern_attr = optional_attr(pattern);
 
     let (data_testid, data_cy) = match &test_attr {
         Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
@@ -228,8 +351,13 @@ pub fn Input(
                         placeholder=placeholder.get_untracked()
                         disabled=disabled.get_untracked()
                         readonly=readonly.get_untracked()
-                        step=numeric_step.clone()
-                        pattern="[0-9]+([.][0-9]{0,2})?"
+                        step=step_attr.clone()
+                        min=min_attr
+                        max=max_attr
+                        pattern=number_pattern
+                        autocomplete=autocomplete_attr
+                        inputmode=inputmode_attr
+                        maxlength=maxlength_attr.clone()
                         attr:data-testid=move || data_testid.clone()
                         attr:data-cy=move || data_cy.clone()
                         on:input=on_input_number
@@ -248,6 +376,13 @@ pub fn Input(
                         placeholder=placeholder.get_untracked()
                         disabled=disabled.get_untracked()
                         readonly=readonly.get_untracked()
+                        step=step_attr.clone()
+                        min=min_attr
+                        max=max_attr
+                        pattern=pattern_attr
+                        autocomplete=autocomplete_attr
+                        inputmode=inputmode_attr
+                        maxlength=maxlength_attr.clone()
                         attr:data-testid=move || data_testid.clone()
                         attr:data-cy=move || data_cy.clone()
                         on:input=on_input_text
@@ -324,6 +459,138 @@ mod tests {
         );
     }
 
+    #[test]
+    fn input_type_covers_html_types() {
+        let cases = [
+            (InputType::Search, "search"),
+            (InputType::Url, "url"),
+            (InputType::Date, "date"),
+            (InputType::Time, "time"),
+            (InputType::DateTimeLocal, "datetime-local"),
+            (InputType::Month, "month"),
+            (InputType::Week, "week"),
+            (InputType::Color, "color"),
+            (InputType::Range, "range"),
+        ];
+        for (input_type, expected) in cases {
+            let html =
+                view! { <Input name="x" value="" r#type=input_type update=noop() /> }.to_html();
+            assert!(
+                html.contains(&format!(r#"type="{}""#, expected)),
+                "expected type={}; got: {}",
+                expected,
+                html
+            );
+        }
+    }
+
+    #[test]
+    fn input_renders_type_specific_attributes() {
+        let html = view! {
+            <Input
+                name="day"
+                value=""
+                r#type=InputType::Date
+                min="2025-01-01"
+                max="2025-12-31"
+                autocomplete="off"
+                update=noop()
+            />
+        }
+        .to_html();
+        assert!(
+            html.contains(r#"min="2025-01-01""#) && html.contains(r#"max="2025-12-31""#),
+            "expected min/max; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"autocomplete="off""#),
+            "expected autocomplete; got: {}",
+            html
+        );
+        assert!(
+            !html.contains("step=") && !html.contains("pattern="),
+            "expected no step/pattern for date; got: {}",
+            html
+        );
+
+        let html = view! {
+            <Input
+                name="zip"
+                value=""
+                pattern="[0-9]{5}"
+                inputmode="numeric"
+                maxlength=5
+                update=noop()
+            />
+        }
+        .to_html();
+        assert!(
+            html.contains(r#"pattern="[0-9]{5}""#)
+                && html.contains(r#"inputmode="numeric""#)
+                && html.contains(r#"maxlength="5""#),
+            "expected pattern/inputmode/maxlength; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn number_input_keeps_default_pattern_and_step() {
+        let html =
+            view! { <Input name="n" value="" r#type=InputType::Number update=noop() /> }.to_html();
+        assert!(
+            html.contains(r#"step="1""#) && html.contains(r#"pattern="[0-9]+([.][0-9]{0,2})?""#),
+            "expected default number step and pattern; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn input_reflects_form_validation_state() {
+        use crate::form::context::{Form, use_form};
+        use crate::form::field::Field;
+        use crate::form::validation::required;
+
+        let owner = leptos::prelude::Owner::new();
+        let html = owner.with(|| {
+            let form = use_form();
+            let (email, set_email) = form.bind("email");
+            let (name, set_name) = form.bind("name");
+            form.set_value("name", "Ada");
+            form.set_validators("email", Default::default(), vec![required()]);
+            form.set_validators("name", Default::default(), vec![required()]);
+            form.validate_all();
+
+            view! {
+                <Form form=form>
+                    <Field name="email">
+                        <Input name="email" value=email update=set_email />
+                    </Field>
+                    <Field name="name">
+                        <Input name="name" value=name update=set_name />
+                    </Field>
+                </Form>
+            }
+            .to_html()
+        });
+
+        assert!(
+            html.contains(r#"class="input is-danger""#),
+            "expected is-danger on invalid input; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"class="input is-success""#),
+            "expected is-success on valid input; got: {}",
+            html
+        );
+        assert!(
+            html.contains("This field is required."),
+            "expected error in field help; got: {}",
+            html
+        );
+    }
+
     #[test]
     fn input_renders_test_attr_as_data_testid() {
         let html = view! {
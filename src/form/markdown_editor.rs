@@ -0,0 +1,659 @@
+/*!
+A markdown editor with a formatting toolbar and a live preview.
+
+The preview is produced by [`markdown_to_html`], which renders CommonMark (plus tables,
+strikethrough and task lists) and sanitizes the result: raw HTML in the source is shown as text
+and links or images with a scheme other than `http`, `https` or `mailto` point to `#`.
+*/
+
+use std::ops::Range;
+
+use leptos::callback::Callback;
+use leptos::ev::KeyboardEvent;
+use leptos::html;
+use leptos::prelude::{
+    AriaAttributes, Callable, ClassAttribute, CustomAttribute, Effect, ElementChild, Get,
+    GetUntracked, GlobalAttributes, InnerHtmlAttribute, IntoAny, IntoView, NodeRef,
+    NodeRefAttribute, OnAttribute, RwSignal, Set, Signal, component, event_target_value, view,
+};
+use pulldown_cmark::{CowStr, Event, Options, Parser, Tag, TagEnd};
+
+use crate::components::tabs::Tabs;
+use crate::elements::content::Content;
+use crate::elements::icon::Icon;
+use crate::form::context::use_form_context;
+use crate::util::{Size, TestAttr};
+
+/// True for relative URLs and for `http`, `https` and `mailto` links.
+pub(crate) fn is_safe_url(url: &str) -> bool {
+    // Browsers ignore whitespace and control characters inside a scheme ("java\tscript:").
+    let cleaned: String = url
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect();
+    match cleaned.find([':', '/', '?', '#']) {
+        Some(index) if cleaned[index..].starts_with(':') => matches!(
+            cleaned[..index].to_ascii_lowercase().as_str(),
+            "http" | "https" | "mailto"
+        ),
+        _ => true,
+    }
+}
+
+fn safe_url(url: CowStr<'_>) -> CowStr<'_> {
+    if is_safe_url(&url) { url } else { "#".into() }
+}
+
+/// Renders `markdown` to sanitized HTML.
+///
+/// Raw HTML blocks and inline tags are escaped and shown as text, and links and images with an
+/// unsafe scheme (e.g. `javascript:`, `data:`) are pointed at `#`.
+pub fn markdown_to_html(markdown: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let events = Parser::new_ext(markdown, options).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        Event::Start(Tag::HtmlBlock) => Event::Start(Tag::Paragraph),
+        Event::End(TagEnd::HtmlBlock) => Event::End(TagEnd::Paragraph),
+        Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Link {
+            link_type,
+            dest_url: safe_url(dest_url),
+            title,
+            id,
+        }),
+        Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Image {
+            link_type,
+            dest_url: safe_url(dest_url),
+            title,
+            id,
+        }),
+        other => other,
+    });
+
+    let mut html = String::new();
+    pulldown_cmark::html::push_html(&mut html, events);
+    html
+}
+
+/// The text and selection (byte offsets) after a [`MarkdownAction`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MarkdownEdit {
+    pub text: String,
+    pub selection: Range<usize>,
+}
+
+/// A formatting action of the [`MarkdownEditor`] toolbar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MarkdownAction {
+    Bold,
+    Italic,
+    Link,
+    List,
+    Code,
+}
+
+impl MarkdownAction {
+    /// All actions, in toolbar order.
+    pub const ALL: [MarkdownAction; 5] = [
+        MarkdownAction::Bold,
+        MarkdownAction::Italic,
+        MarkdownAction::Link,
+        MarkdownAction::List,
+        MarkdownAction::Code,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            MarkdownAction::Bold => "Bold",
+            MarkdownAction::Italic => "Italic",
+            MarkdownAction::Link => "Link",
+            MarkdownAction::List => "Bulleted list",
+            MarkdownAction::Code => "Code",
+        }
+    }
+
+    fn shortcut(self) -> &'static str {
+        match self {
+            MarkdownAction::Bold => "Ctrl+B",
+            MarkdownAction::Italic => "Ctrl+I",
+            MarkdownAction::Link => "Ctrl+K",
+            MarkdownAction::List => "Ctrl+Shift+8",
+            MarkdownAction::Code => "Ctrl+E",
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            MarkdownAction::Bold => "fa-solid fa-bold",
+            MarkdownAction::Italic => "fa-solid fa-italic",
+            MarkdownAction::Link => "fa-solid fa-link",
+            MarkdownAction::List => "fa-solid fa-list-ul",
+            MarkdownAction::Code => "fa-solid fa-code",
+        }
+    }
+
+    /// The action bound to Ctrl/Cmd + `key` (as reported by `KeyboardEvent.key`).
+    pub fn from_shortcut(key: &str, shift: bool) -> Option<Self> {
+        match (key.to_ascii_lowercase().as_str(), shift) {
+            ("b", false) => Some(MarkdownAction::Bold),
+            ("i", false) => Some(MarkdownAction::Italic),
+            ("k", false) => Some(MarkdownAction::Link),
+            ("e", false) => Some(MarkdownAction::Code),
+            ("8" | "*", true) => Some(MarkdownAction::List),
+            _ => None,
+        }
+    }
+
+    /// Applies the action to the `selection` (byte offsets) of `text`.
+    ///
+    /// Bold, italic and inline code wrap the selection, or unwrap it when it is already wrapped;
+    /// code spanning several lines becomes a fenced block. Link wraps the selection as link text
+    /// and selects the URL placeholder. List toggles a `- ` prefix on the selected lines.
+    pub fn apply(self, text: &str, selection: Range<usize>) -> MarkdownEdit {
+        let start = selection.start.min(text.len());
+        let end = selection.end.clamp(start, text.len());
+        match self {
+            MarkdownAction::Bold => wrap(text, start..end, "**"),
+            MarkdownAction::Italic => wrap(text, start..end, "_"),
+            MarkdownAction::Code if text[start..end].contains('\n') => fence(text, start..end),
+            MarkdownAction::Code => wrap(text, start..end, "`"),
+            MarkdownAction::Link => link(text, start..end),
+            MarkdownAction::List => list(text, start..end),
+        }
+    }
+}
+
+fn wrap(text: &str, selection: Range<usize>, marker: &str) -> MarkdownEdit {
+    let Range { start, end } = selection;
+    let len = marker.len();
+    if text[..start].ends_with(marker) && text[end..].starts_with(marker) {
+        return MarkdownEdit {
+            text: format!(
+                "{}{}{}",
+                &text[..start - len],
+                &text[start..end],
+                &text[end + len..]
+            ),
+            selection: start - len..end - len,
+        };
+    }
+    MarkdownEdit {
+        text: format!(
+            "{}{marker}{}{marker}{}",
+            &text[..start],
+            &text[start..end],
+            &text[end..]
+        ),
+        selection: start + len..end + len,
+    }
+}
+
+fn fence(text: &str, selection: Range<usize>) -> MarkdownEdit {
+    let Range { start, end } = selection;
+    let before = if start == 0 || text[..start].ends_with('\n') {
+        "```\n"
+    } else {
+        "\n```\n"
+    };
+    let after = if text[end..].is_empty() || text[end..].starts_with('\n') {
+        "\n```"
+    } else {
+        "\n```\n"
+    };
+    let inner = start + before.len();
+    MarkdownEdit {
+        text: format!(
+            "{}{before}{}{after}{}",
+            &text[..start],
+            &text[start..end],
+            &text[end..]
+        ),
+        selection: inner..inner + (end - start),
+    }
+}
+
+fn link(text: &str, selection: Range<usize>) -> MarkdownEdit {
+    let Range { start, end } = selection;
+    let selected = &text[start..end];
+    // A selected URL becomes the target; the caret goes into the empty link text.
+    if selected.starts_with("http://") || selected.starts_with("https://") {
+        return MarkdownEdit {
+            text: format!("{}[]({}){}", &text[..start], selected, &text[end..]),
+            selection: start + 1..start + 1,
+        };
+    }
+    let url = start + selected.len() + 3;
+    MarkdownEdit {
+        text: format!("{}[{}](url){}", &text[..start], selected, &text[end..]),
+        selection: url..url + 3,
+    }
+}
+
+fn list(text: &str, selection: Range<usize>) -> MarkdownEdit {
+    let Range { start, end } = selection;
+    let line_start = text[..start].rfind('\n').map_or(0, |index| index + 1);
+    let line_end = text[end..]
+        .find('\n')
+        .map_or(text.len(), |index| end + index);
+    let lines: Vec<&str> = text[line_start..line_end].split('\n').collect();
+    let listed = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .all(|line| line.starts_with("- "));
+    let block = lines
+        .iter()
+        .map(|line| match line.strip_prefix("- ") {
+            Some(rest) if listed => rest.to_string(),
+            _ if !listed && (lines.len() == 1 || !line.trim().is_empty()) => format!("- {}", line),
+            _ => line.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let selection = if start == end {
+        let caret = if listed {
+            start.saturating_sub(2).max(line_start)
+        } else {
+            start + 2
+        };
+        caret..caret
+    } else {
+        line_start..line_start + block.len()
+    };
+    MarkdownEdit {
+        text: format!("{}{}{}", &text[..line_start], block, &text[line_end..]),
+        selection,
+    }
+}
+
+/// Byte offset of the `units`-th UTF-16 code unit of `text` (DOM selections count UTF-16).
+fn byte_offset(text: &str, units: u32) -> usize {
+    let mut seen = 0;
+    for (index, ch) in text.char_indices() {
+        if seen >= units as usize {
+            return index;
+        }
+        seen += ch.len_utf16();
+    }
+    text.len()
+}
+
+fn utf16_offset(text: &str, byte: usize) -> u32 {
+    text[..byte].encode_utf16().count() as u32
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EditorTab {
+    Write,
+    Preview,
+}
+
+/// A markdown editor with Write/Preview tabs, a formatting toolbar and keyboard shortcuts.
+///
+/// The Write tab is a Bulma textarea with buttons for bold, italic, link, list and code
+/// (also on Ctrl/Cmd+B, I, K, Shift+8 and E). The Preview tab renders the sanitized HTML of
+/// [`markdown_to_html`] inside `Content`. The textarea stays mounted while previewing, so the
+/// selection and undo history survive switching tabs.
+///
+/// Controlled component: the markdown comes from `value`, changes are propagated via `update`.
+/// Inside a `Form`, the textarea gets `is-danger`/`is-success` from the validation state of the
+/// field with the same `name`.
+#[component]
+pub fn MarkdownEditor(
+    /// The `name` attribute of the textarea.
+    #[prop(into)]
+    name: Signal<String>,
+
+    /// The controlled markdown source.
+    #[prop(into)]
+    value: Signal<String>,
+
+    /// The callback to be used for propagating changes to the markdown.
+    update: Callback<String>,
+
+    /// The placeholder of the textarea.
+    #[prop(optional, into)]
+    placeholder: Signal<String>,
+
+    /// The number of rows of the textarea (default 8).
+    #[prop(optional)]
+    rows: Option<u32>,
+
+    /// Shown in the Preview tab when there is nothing to render.
+    #[prop(default = "Nothing to preview".into(), into)]
+    empty_preview: Signal<String>,
+
+    /// Extra classes to apply to the root element.
+    #[prop(optional, into)]
+    classes: Signal<String>,
+
+    /// Disable the textarea and the toolbar.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Optional test attribute (renders as data-* attribute) on the root <div>.
+    ///
+    /// When provided as a &str or String, this becomes `data-testid="value"`.
+    /// You can also pass a full `TestAttr` to override the attribute key.
+    #[prop(optional, into)]
+    test_attr: Option<TestAttr>,
+) -> impl IntoView {
+    let form = use_form_context();
+    let tab = RwSignal::new(EditorTab::Write);
+    let textarea_ref = NodeRef::<html::Textarea>::new();
+
+    // Keep the DOM in sync when the value changes from outside (e.g. the parent resets it).
+    Effect::new(move |_| {
+        let text = value.get();
+        if let Some(textarea) = textarea_ref.get()
+            && textarea.value() != text
+        {
+            textarea.set_value(&text);
+        }
+    });
+
+    let run_action = move |action: MarkdownAction| {
+        let Some(textarea) = textarea_ref.get_untracked() else {
+            return;
+        };
+        if disabled.get_untracked() {
+            return;
+        }
+        let text = textarea.value();
+        let start = textarea.selection_start().ok().flatten().unwrap_or(0);
+        let end = textarea.selection_end().ok().flatten().unwrap_or(start);
+        let edit = action.apply(&text, byte_offset(&text, start)..byte_offset(&text, end));
+        textarea.set_value(&edit.text);
+        let _ = textarea.set_selection_range(
+            utf16_offset(&edit.text, edit.selection.start),
+            utf16_offset(&edit.text, edit.selection.end),
+        );
+        let _ = textarea.focus();
+        update.run(edit.text);
+    };
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        if !(ev.ctrl_key() || ev.meta_key()) || ev.alt_key() {
+            return;
+        }
+        if let Some(action) = MarkdownAction::from_shortcut(&ev.key(), ev.shift_key()) {
+            ev.prevent_default();
+            run_action(action);
+        }
+    };
+
+    let textarea_class = move || {
+        let mut parts = vec!["textarea"];
+        if let Some(color) = form.and_then(|form| form.validation_class(&name.get())) {
+            parts.push(color);
+        }
+        parts.join(" ")
+    };
+    let root_class = move || {
+        let extra = classes.get();
+        if extra.trim().is_empty() {
+            "markdown-editor".to_string()
+        } else {
+            format!("markdown-editor {}", extra.trim())
+        }
+    };
+
+    let tab_item = move |which: EditorTab, label: &'static str| {
+        view! {
+            <li class=move || if tab.get() == which { "is-active" } else { "" }>
+                <a
+                    role="tab"
+                    aria-selected=move || if tab.get() == which { "true" } else { "false" }
+                    on:click=move |_| tab.set(which)
+                >
+                    {label}
+                </a>
+            </li>
+        }
+    };
+
+    let toolbar = MarkdownAction::ALL
+        .into_iter()
+        .map(|action| {
+            let title = format!("{} ({})", action.label(), action.shortcut());
+            view! {
+                <button
+                    type="button"
+                    class="button is-small is-white"
+                    title=title
+                    aria-label=action.label()
+                    disabled=move || disabled.get()
+                    // Keep the textarea selection: apply on mousedown, before focus moves.
+                    on:mousedown=move |ev| {
+                        ev.prevent_default();
+                        run_action(action);
+                    }
+                >
+                    <Icon size=Size::Small>
+                        <i class=action.icon()></i>
+                    </Icon>
+                </button>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let preview = move || {
+        (tab.get() == EditorTab::Preview).then(|| {
+            let source = value.get();
+            if source.trim().is_empty() {
+                view! { <p class="has-text-grey">{empty_preview.get()}</p> }.into_any()
+            } else {
+                let html = markdown_to_html(&source);
+                view! {
+                    <Content classes="markdown-preview">
+                        <div inner_html=html></div>
+                    </Content>
+                }
+                .into_any()
+            }
+        })
+    };
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    let initial_value = value.get_untracked();
+
+    view! {
+        <div class=root_class attr:data-testid=data_testid attr:data-cy=data_cy>
+            <Tabs boxed=true size=Size::Small classes="mb-0">
+                {tab_item(EditorTab::Write, "Write")}
+                {tab_item(EditorTab::Preview, "Preview")}
+            </Tabs>
+            <div class=move || {
+                if tab.get() == EditorTab::Write { "markdown-write" } else { "markdown-write is-hidden" }
+            }>
+                <div class="buttons has-addons mb-1" role="toolbar" aria-label="Formatting">
+                    {toolbar}
+                </div>
+                <textarea
+                    name=move || name.get()
+                    class=textarea_class
+                    placeholder=move || placeholder.get()
+                    rows=rows.unwrap_or(8).to_string()
+                    disabled=move || disabled.get()
+                    node_ref=textarea_ref
+                    on:input=move |ev| update.run(event_target_value(&ev))
+                    on:keydown=on_keydown
+                >
+                    {initial_value}
+                </textarea>
+            </div>
+            {preview}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::prelude::RenderHtml;
+
+    fn noop() -> Callback<String> {
+        Callback::new(|_v: String| {})
+    }
+
+    fn apply(action: MarkdownAction, text: &str, selection: Range<usize>) -> (String, String) {
+        let edit = action.apply(text, selection);
+        let selected = edit.text[edit.selection.clone()].to_string();
+        (edit.text, selected)
+    }
+
+    #[test]
+    fn markdown_renders_common_syntax() {
+        let html = markdown_to_html("# Title\n\n**bold** and _it_\n\n- one\n- two\n\n`code`");
+        assert!(html.contains("<h1>Title</h1>"), "got: {}", html);
+        assert!(
+            html.contains("<strong>bold</strong> and <em>it</em>"),
+            "got: {}",
+            html
+        );
+        assert!(
+            html.contains("<ul>\n<li>one</li>\n<li>two</li>\n</ul>"),
+            "got: {}",
+            html
+        );
+        assert!(html.contains("<code>code</code>"), "got: {}", html);
+    }
+
+    #[test]
+    fn markdown_escapes_raw_html_and_unsafe_urls() {
+        let html = markdown_to_html(
+            "<script>alert(1)</script>\n\nhi <img src=x onerror=alert(1)>\n\n\
+             [a](javascript:alert(1)) [b](JaVa\tScRiPt:x) ![c](data:text/html,x) \
+             [d](https://example.com) [e](/docs#top)",
+        );
+        assert!(
+            !html.contains("<script") && html.contains("&lt;script&gt;"),
+            "raw HTML must be escaped: {}",
+            html
+        );
+        assert!(!html.contains("<img src=\"x\""), "got: {}", html);
+        assert!(
+            !html.to_lowercase().contains("href=\"java") && !html.contains("src=\"data:"),
+            "unsafe schemes must not survive: {}",
+            html
+        );
+        assert!(
+            html.contains(r##"<a href="#">a</a>"##) && html.contains(r##"src="#""##),
+            "unsafe URLs point to #: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"href="https://example.com""#)
+                && html.contains(r##"href="/docs#top""##),
+            "safe URLs are kept: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn wrap_actions_toggle_markers() {
+        assert_eq!(
+            apply(MarkdownAction::Bold, "say hi", 4..6),
+            ("say **hi**".to_string(), "hi".to_string())
+        );
+        assert_eq!(
+            apply(MarkdownAction::Bold, "say **hi**", 6..8),
+            ("say hi".to_string(), "hi".to_string())
+        );
+        assert_eq!(
+            apply(MarkdownAction::Italic, "x", 1..1),
+            ("x__".to_string(), String::new())
+        );
+        assert_eq!(
+            apply(MarkdownAction::Code, "a\nb", 0..3),
+            ("```\na\nb\n```".to_string(), "a\nb".to_string())
+        );
+    }
+
+    #[test]
+    fn link_and_list_actions() {
+        assert_eq!(
+            apply(MarkdownAction::Link, "see docs", 4..8),
+            ("see [docs](url)".to_string(), "url".to_string())
+        );
+        let edit = MarkdownAction::Link.apply("https://x.dev", 0..13);
+        assert_eq!(edit.text, "[](https://x.dev)");
+        assert_eq!(edit.selection, 1..1);
+
+        assert_eq!(
+            apply(MarkdownAction::List, "one\ntwo\n\nthree", 0..7),
+            (
+                "- one\n- two\n\nthree".to_string(),
+                "- one\n- two".to_string()
+            )
+        );
+        assert_eq!(
+            apply(MarkdownAction::List, "- one\n- two", 2..11),
+            ("one\ntwo".to_string(), "one\ntwo".to_string())
+        );
+        assert_eq!(
+            MarkdownAction::from_shortcut("B", false),
+            Some(MarkdownAction::Bold)
+        );
+        assert_eq!(
+            MarkdownAction::from_shortcut("*", true),
+            Some(MarkdownAction::List)
+        );
+        assert_eq!(MarkdownAction::from_shortcut("b", true), None);
+    }
+
+    #[test]
+    fn editor_renders_tabs_toolbar_and_textarea() {
+        let html = view! {
+            <MarkdownEditor name="body" value="**hi**" update=noop() test_attr="md" />
+        }
+        .to_html();
+        assert!(
+            html.contains(r#"class="markdown-editor""#) && html.contains(r#"data-testid="md""#),
+            "expected root with test id: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"class="tabs mb-0 is-small is-boxed""#),
+            "expected boxed tabs: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"<li class="is-active">"#) && html.contains("Preview"),
+            "expected Write tab active: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"title="Bold (Ctrl+B)""#) && html.contains("fa-list-ul"),
+            "expected toolbar buttons: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"name="body""#) && html.contains(">**hi**</textarea>"),
+            "expected textarea with the markdown: {}",
+            html
+        );
+        assert!(
+            !html.contains("markdown-preview"),
+            "preview is rendered only on its tab: {}",
+            html
+        );
+    }
+}
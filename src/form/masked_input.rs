@@ -0,0 +1,460 @@
+/*!
+Masked text input: `MaskedInput` formats what the user types according to a [`Mask`].
+
+Pattern syntax
+- `9` a digit, `a` a letter, `*` a letter or digit.
+- `\` makes the next character literal (e.g. `\9`).
+- Any other character is a literal that is inserted automatically (e.g. `(`, `)`, `-`, ` `).
+
+Literals are only added once a following slot is filled, so deleting the last typed character also
+removes the separator before it. Characters that fit no slot are dropped, and typed literals are
+accepted where the mask has them.
+
+While typing, the caret stays after the same number of typed characters, even when the mask
+inserts literals in front of it. `update` receives a [`MaskedValue`] with the formatted text,
+the raw characters without literals, and whether every slot is filled.
+*/
+
+use std::fmt;
+
+use leptos::callback::Callback;
+use leptos::prelude::{
+    Callable, ClassAttribute, CustomAttribute, Get, GlobalAttributes, IntoView, OnAttribute,
+    PropAttribute, Signal, component, view,
+};
+use leptos::wasm_bindgen::JsCast;
+use leptos::web_sys::HtmlInputElement;
+
+use crate::form::context::use_form_context;
+use crate::util::{Size, TestAttr};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token {
+    Digit,
+    Letter,
+    Alphanumeric,
+    Literal(char),
+}
+
+impl Token {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Token::Digit => c.is_ascii_digit(),
+            Token::Letter => c.is_alphabetic(),
+            Token::Alphanumeric => c.is_alphanumeric(),
+            Token::Literal(_) => false,
+        }
+    }
+}
+
+/// A parsed input mask such as `(999) 999-9999`; see the module docs for the syntax.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mask {
+    pattern: String,
+    tokens: Vec<Token>,
+}
+
+/// The result of applying a [`Mask`] to some text.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MaskedValue {
+    /// The text as shown, with literals, e.g. `(555) 123-4`.
+    pub masked: String,
+    /// Only the characters typed into slots, e.g. `5551234`.
+    pub unmasked: String,
+    /// Whether every slot of the mask is filled.
+    pub complete: bool,
+}
+
+impl Mask {
+    /// US phone number: `(999) 999-9999`.
+    pub const PHONE_US: &'static str = "(999) 999-9999";
+    /// International phone number with country code: `+99 999 999 9999`.
+    pub const PHONE_INTL: &'static str = "+99 999 999 9999";
+    /// Payment card number: `9999 9999 9999 9999`.
+    pub const CARD: &'static str = "9999 9999 9999 9999";
+    /// Card expiry: `99/99`.
+    pub const CARD_EXPIRY: &'static str = "99/99";
+    /// ISO date: `9999-99-99`.
+    pub const DATE_ISO: &'static str = "9999-99-99";
+    /// US date: `99/99/9999`.
+    pub const DATE_US: &'static str = "99/99/9999";
+    /// 24-hour time: `99:99`.
+    pub const TIME: &'static str = "99:99";
+    /// US ZIP+4 code: `99999-9999`.
+    pub const ZIP_US: &'static str = "99999-9999";
+
+    /// Parses a mask pattern.
+    pub fn new(pattern: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+        let mut tokens = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            tokens.push(match c {
+                '9' => Token::Digit,
+                'a' => Token::Letter,
+                '*' => Token::Alphanumeric,
+                '\\' => Token::Literal(chars.next().unwrap_or('\\')),
+                other => Token::Literal(other),
+            });
+        }
+        Self { pattern, tokens }
+    }
+
+    /// The pattern this mask was parsed from.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// The mask with every slot shown as `_`, e.g. `(___) ___-____`.
+    pub fn placeholder(&self) -> String {
+        self.tokens
+            .iter()
+            .map(|token| match token {
+                Token::Literal(c) => *c,
+                _ => '_',
+            })
+            .collect()
+    }
+
+    /// Length of a completely filled value, in characters.
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Whether the pattern is empty.
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Whether every slot only takes digits (used to pick a numeric keyboard).
+    pub fn is_numeric(&self) -> bool {
+        self.tokens
+            .iter()
+            .all(|token| matches!(token, Token::Digit | Token::Literal(_)))
+    }
+
+    /// Formats `text` with this mask.
+    pub fn apply(&self, text: &str) -> MaskedValue {
+        let mut masked = String::new();
+        let mut unmasked = String::new();
+        let mut pending = String::new();
+        let mut input = text.chars().peekable();
+        let mut filled_all = true;
+
+        for token in &self.tokens {
+            match token {
+                Token::Literal(literal) => {
+                    if input.peek() == Some(literal) {
+                        input.next();
+                    }
+                    pending.push(*literal);
+                }
+                slot => {
+                    let next = loop {
+                        match input.next() {
+                            Some(c) if slot.accepts(c) => break Some(c),
+                            Some(_) => continue,
+                            None => break None,
+                        }
+                    };
+                    let Some(c) = next else {
+                        filled_all = false;
+                        break;
+                    };
+                    masked.push_str(&pending);
+                    pending.clear();
+                    masked.push(c);
+                    unmasked.push(c);
+                }
+            }
+        }
+
+        MaskedValue {
+            masked,
+            unmasked,
+            complete: filled_all,
+        }
+    }
+
+    /// Caret position (in UTF-16 units, as used by the DOM) in `masked` after `slots` typed
+    /// characters.
+    pub(crate) fn caret_after(&self, masked: &str, slots: usize) -> usize {
+        if slots == 0 {
+            return 0;
+        }
+        let mut seen = 0;
+        let mut offset = 0;
+        for (c, token) in masked.chars().zip(&self.tokens) {
+            offset += c.len_utf16();
+            if !matches!(token, Token::Literal(_)) {
+                seen += 1;
+                if seen == slots {
+                    return offset;
+                }
+            }
+        }
+        offset
+    }
+
+    /// Formats the DOM text `raw` whose caret is at `caret` (UTF-16 units) and returns the new
+    /// value with the matching caret position.
+    pub(crate) fn apply_with_caret(&self, raw: &str, caret: usize) -> (MaskedValue, usize) {
+        let mut units = 0;
+        let prefix: String = raw
+            .chars()
+            .take_while(|c| {
+                units += c.len_utf16();
+                units <= caret
+            })
+            .collect();
+        let slots = self.apply(&prefix).unmasked.chars().count();
+        let value = self.apply(raw);
+        let caret = self.caret_after(&value.masked, slots);
+        (value, caret)
+    }
+}
+
+impl fmt::Display for Mask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.pattern)
+    }
+}
+
+impl From<&str> for Mask {
+    fn from(pattern: &str) -> Self {
+        Mask::new(pattern)
+    }
+}
+
+impl From<String> for Mask {
+    fn from(pattern: String) -> Self {
+        Mask::new(pattern)
+    }
+}
+
+/// A text input that formats its value with a [`Mask`].
+///
+/// https://bulma.io/documentation/form/input/
+///
+/// Controlled component: `value` may be either the masked or the raw text (it is re-masked for
+/// display), and `update` receives a [`MaskedValue`] with both forms after every edit.
+///
+/// Inside a `Form`, the input gets `is-danger`/`is-success` from the validation state of
+/// the field with the same `name`.
+#[component]
+pub fn MaskedInput(
+    /// The `name` attribute for this form element.
+    #[prop(into)]
+    name: Signal<String>,
+
+    /// The controlled value, masked or raw.
+    #[prop(into)]
+    value: Signal<String>,
+
+    /// Called with the masked and unmasked value after each edit.
+    update: Callback<MaskedValue>,
+
+    /// The mask, e.g. `Mask::PHONE_US` or a custom pattern like `"aa-9999"`.
+    #[prop(into)]
+    mask: Mask,
+
+    /// Placeholder text. Defaults to the mask with `_` for each slot.
+    #[prop(optional, into)]
+    placeholder: Option<Signal<String>>,
+
+    /// Extra classes to apply to the input.
+    #[prop(optional, into)]
+    classes: Signal<String>,
+
+    /// The size of this component.
+    #[prop(optional)]
+    size: Option<Size>,
+
+    /// Disable this component.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Make this component read-only.
+    #[prop(optional, into)]
+    readonly: Signal<bool>,
+
+    /// The `autocomplete` attribute (e.g. `"tel"`, `"cc-number"`).
+    #[prop(optional, into)]
+    autocomplete: Option<String>,
+
+    /// Optional test attribute (renders as data-* attribute) on the <input>.
+    ///
+    /// When provided as a &str or String, this becomes `data-testid="value"`.
+    /// You can also pass a full `TestAttr` to override the attribute key.
+    #[prop(optional, into)]
+    test_attr: Option<TestAttr>,
+) -> impl IntoView {
+    let form = use_form_context();
+
+    let class = move || {
+        let mut parts = vec!["input".to_string()];
+        let extra = classes.get();
+        if !extra.trim().is_empty() {
+            parts.push(extra);
+        }
+        if let Some(size) = size
+            && size != Size::Normal
+        {
+            parts.push(size.bulma().to_string());
+        }
+        if let Some(color) = form.and_then(|form| form.validation_class(&name.get())) {
+            parts.push(color.to_string());
+        }
+        parts.join(" ")
+    };
+
+    let default_placeholder = mask.placeholder();
+    let placeholder = move || match placeholder {
+        Some(text) => text.get(),
+        None => default_placeholder.clone(),
+    };
+    let inputmode = mask.is_numeric().then_some("numeric");
+    let maxlength = mask.len().to_string();
+    let pattern = mask.pattern().to_string();
+
+    let display = {
+        let mask = mask.clone();
+        move || mask.apply(&value.get()).masked
+    };
+
+    let on_input = move |ev: leptos::ev::Event| {
+        let Some(input) = ev
+            .target()
+            .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+        else {
+            return;
+        };
+        let raw = input.value();
+        let caret = input
+            .selection_start()
+            .ok()
+            .flatten()
+            .map_or(raw.encode_utf16().count(), |caret| caret as usize);
+        let (next, caret) = mask.apply_with_caret(&raw, caret);
+        // Write the DOM value directly: rejected characters may leave the parent's value unchanged,
+        // in which case no re-render would remove them.
+        input.set_value(&next.masked);
+        let _ = input.set_selection_range(caret as u32, caret as u32);
+        update.run(next);
+    };
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    view! {
+        <input
+            name=move || name.get()
+            type="text"
+            class=class
+            placeholder=placeholder
+            value=display.clone()
+            prop:value=display
+            inputmode=inputmode
+            autocomplete=autocomplete
+            maxlength=maxlength
+            disabled=move || disabled.get()
+            readonly=move || readonly.get()
+            attr:data-mask=pattern
+            attr:data-testid=data_testid
+            attr:data-cy=data_cy
+            on:input=on_input
+        />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::prelude::RenderHtml;
+
+    #[test]
+    fn phone_mask_inserts_literals_lazily() {
+        let mask = Mask::new(Mask::PHONE_US);
+        assert_eq!(mask.apply("").masked, "");
+        assert_eq!(mask.apply("5").masked, "(5");
+        assert_eq!(mask.apply("555").masked, "(555");
+        assert_eq!(mask.apply("5551").masked, "(555) 1");
+        let full = mask.apply("5551234567");
+        assert_eq!(full.masked, "(555) 123-4567");
+        assert_eq!(full.unmasked, "5551234567");
+        assert!(full.complete);
+        assert!(!mask.apply("555").complete);
+    }
+
+    #[test]
+    fn mask_drops_characters_that_fit_no_slot() {
+        let mask = Mask::new(Mask::PHONE_US);
+        assert_eq!(mask.apply("(555) 12x3-4567 99").masked, "(555) 123-4567");
+        assert_eq!(mask.apply("(555) 123-4567").masked, "(555) 123-4567");
+    }
+
+    #[test]
+    fn custom_mask_with_letters_and_escapes() {
+        let mask = Mask::new(r"aa-\9**");
+        assert_eq!(mask.placeholder(), "__-9__");
+        let value = mask.apply("ab-9x7");
+        assert_eq!(value.masked, "ab-9x7");
+        assert_eq!(value.unmasked, "abx7");
+        assert!(value.complete);
+        assert!(!mask.is_numeric());
+        assert!(Mask::new(Mask::CARD).is_numeric());
+    }
+
+    #[test]
+    fn caret_follows_typed_characters() {
+        let mask = Mask::new(Mask::PHONE_US);
+        // Typing the 4th digit at the end: the caret moves past the inserted ") ".
+        assert_eq!(mask.apply_with_caret("(5551", 5), (mask.apply("5551"), 7));
+        // Inserting a digit in the middle keeps the caret right after it.
+        let (value, caret) = mask.apply_with_caret("(55915) 123", 4);
+        assert_eq!(value.masked, "(559) 151-23");
+        assert_eq!(caret, 4);
+        // Deleting the digit after a separator drops the separator too.
+        let (value, caret) = mask.apply_with_caret("(555) ", 6);
+        assert_eq!(value.masked, "(555");
+        assert_eq!(caret, 4);
+    }
+
+    #[test]
+    fn masked_input_renders_formatted_value_and_defaults() {
+        let html = view! {
+            <MaskedInput
+                name="phone"
+                value="5551234"
+                update=Callback::new(|_: MaskedValue| {})
+                mask=Mask::PHONE_US
+                test_attr="phone"
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"value="(555) 123-4""#),
+            "expected masked value; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"placeholder="(___) ___-____""#),
+            "expected mask placeholder; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"inputmode="numeric""#) && html.contains(r#"maxlength="14""#),
+            "expected numeric keyboard and max length; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"data-testid="phone""#),
+            "expected test id; got: {}",
+            html
+        );
+    }
+}
@@ -11,25 +11,71 @@ Note:
 
 pub mod autocomplete;
 pub mod checkbox;
+pub mod combobox;
+pub mod completion;
+pub mod context;
 pub mod control;
 pub mod field;
 pub mod file;
 pub mod input;
+pub mod markdown_editor;
+pub mod masked_input;
+pub mod model;
+pub mod number_input;
+pub mod password_input;
 pub mod radio;
+pub mod schema_form;
 pub mod select;
+pub mod slider;
+pub mod suggestion;
+pub mod switch;
 pub mod textarea;
+pub mod validation;
+
+pub use combobox::Combobox;
+pub use context::{Form, FormContext, FormValues, use_form, use_form_context};
+pub use markdown_editor::MarkdownEditor;
+pub use schema_form::SchemaForm;
+pub use slider::{RangeSlider, Slider};
+pub use switch::Switch;
 
 // Re-export common items here as they are implemented.
 pub mod prelude {
     pub use super::autocomplete::AutoComplete;
-    pub use super::checkbox::Checkbox;
+    pub use super::checkbox::{Checkbox, CheckboxGroup, GroupLayout};
+    pub use super::combobox::Combobox;
+    pub use super::completion::{
+        CompletionFuture, CompletionProvider, CompletionProviderRef, PhraseCompletions,
+    };
+    pub use super::context::{Form, FormContext, FormValues, use_form, use_form_context};
     pub use super::control::Control;
-    pub use super::field::{AddonsAlign, Field, GroupedAlign, LabelSize};
-    pub use super::file::File;
+    pub use super::field::{
+        AddonsAlign, CharCount, Field, FieldContext, GroupedAlign, LabelSize, use_field_context,
+    };
+    pub use super::file::{File, FileRejection, FileRejectionReason, LbcFile};
     pub use super::input::{Input, InputType};
-    pub use super::radio::Radio;
-    pub use super::select::{MultiSelect, Select};
+    pub use super::markdown_editor::{
+        MarkdownAction, MarkdownEdit, MarkdownEditor, markdown_to_html,
+    };
+    pub use super::masked_input::{Mask, MaskedInput, MaskedValue};
+    pub use super::model::LbcForm;
+    pub use super::number_input::NumberInput;
+    pub use super::password_input::{
+        EntropyEstimator, PasswordInput, PasswordStrength, StrengthEstimator, StrengthEstimatorRef,
+        StrengthLevel,
+    };
+    pub use super::radio::{Radio, RadioGroup};
+    pub use super::schema_form::{SchemaForm, schema_form_value, schema_form_values};
+    pub use super::select::{MultiSelect, Select, SelectOption};
+    pub use super::slider::{RangeSlider, Slider, SliderColor, SliderOutput};
+    pub use super::suggestion::{
+        LocalSource, SuggestFuture, Suggestion, SuggestionSource, SuggestionSourceRef, UrlSource,
+    };
+    pub use super::switch::{Switch, SwitchColor};
     pub use super::textarea::TextArea;
+    // The validator constructors (`required`, `email`, ...) stay under `lbc::form::validation`
+    // so their short names do not clash with user code.
+    pub use super::validation::{ValidateOn, Validator, ValidatorRef};
     // pub use super::label::Label;
     // pub use super::help::Help;
 }
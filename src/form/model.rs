@@ -0,0 +1,345 @@
+/*!
+Forms generated from structs with `#[derive(LbcForm)]`.
+
+The derive implements [`LbcForm`] (struct ⇄ [`FormValues`]) and generates a component,
+`{Struct}Form` by default, that renders each field as a `Field` with a matching control inside a
+`Form`, and emits the edited struct through `on_submit` (and optionally `on_change`):
+
+```ignore
+#[derive(Clone, LbcForm)]
+#[lbc(submit = "Save")]
+struct Profile {
+    #[lbc(label = "Full name", required, min_length = 2)]
+    name: String,
+    #[lbc(widget = "email", help = "We never share it", required, email)]
+    email: String,
+    #[lbc(min = 0, max = 150)]
+    age: Option<u32>,
+    #[lbc(widget = "select", options("admin", "editor", "viewer"))]
+    role: String,
+    newsletter: bool,
+    #[lbc(skip)]
+    id: u64,
+}
+
+view! { <ProfileForm initial=profile on_submit=Callback::new(move |p: Profile| save(p)) /> }
+```
+
+Struct attributes: `component = "Name"` (component name) and `submit = "Label"` (button text).
+
+Field attributes:
+- `label = "..."` (defaults to the field name, humanized), `help = "..."`, `placeholder = "..."`
+- `widget = "..."`: `text`, `email`, `password`, `tel`, `url`, `search`, `number`, `date`,
+  `time`, `datetime`, `color`, `textarea`, `checkbox`, `switch` or `select`. Defaults to
+  `checkbox` for `bool`, `select` when options are given, `number` for numeric types and `text`
+  otherwise.
+- `options("a", "b")` or `options_fn = path` (a `fn() -> Vec<SelectOption<String>>`) for selects.
+- Validators: `required`, `email`, `min_length = n`, `max_length = n`, `pattern = "regex"`
+  (checked at compile time), `min = x` / `max = y` (numeric range), `validate = path` (a
+  `fn(&str) -> Result<(), String>`).
+- `skip`: not rendered; the field keeps the value of `initial`.
+
+Field types are converted with `Display` and `FromStr`. `String` fields may be empty,
+`Option<T>` fields are `None` when empty, and every other type must parse. The generated form
+checks that on blur like any other validator.
+*/
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use leptos::callback::Callback;
+use leptos::prelude::{Callable, Effect, StoredValue, WithValue};
+use regex::Regex;
+
+use crate::form::context::{FormContext, FormValues};
+use crate::form::validation::{ValidatorRef, pattern};
+
+pub use lbc_derive::LbcForm;
+
+/// Error messages keyed by field name.
+pub type FieldErrors = BTreeMap<String, String>;
+
+/// A struct edited by a generated form; implement it with `#[derive(LbcForm)]`.
+pub trait LbcForm: Clone + Send + Sync + 'static {
+    /// The values of the form fields, keyed by field name.
+    fn to_form_values(&self) -> FormValues;
+
+    /// A copy of `self` with the fields read from `values`.
+    ///
+    /// Skipped fields and fields missing from `values` keep their current value. Returns the
+    /// messages of the fields that do not parse.
+    fn with_form_values(&self, values: &FormValues) -> Result<Self, FieldErrors>;
+}
+
+/// Parses a form value for a required field.
+pub fn parse_form_value<T: FromStr>(raw: &str) -> Result<T, String> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return Err("This field is required.".to_string());
+    }
+    raw.parse()
+        .map_err(|_| "Please enter a valid value.".to_string())
+}
+
+/// Parses a form value for an optional field; empty values are `None`.
+pub fn parse_optional_form_value<T: FromStr>(raw: &str) -> Result<Option<T>, String> {
+    if raw.trim().is_empty() {
+        Ok(None)
+    } else {
+        parse_form_value(raw).map(Some)
+    }
+}
+
+/// The value must parse as `T` (empty values are accepted, like the other built-ins).
+pub fn parses<T: FromStr>() -> ValidatorRef {
+    ValidatorRef::new(|value: &str| {
+        if value.trim().is_empty() {
+            Ok(())
+        } else {
+            parse_form_value::<T>(value).map(|_| ())
+        }
+    })
+}
+
+/// [`pattern`] from a regex source, as written in `#[lbc(pattern = "...")]`.
+///
+/// The derive rejects invalid regexes at compile time, so generated forms never hit the panic.
+///
+/// # Panics
+///
+/// When `source` is not a valid regular expression.
+pub fn pattern_validator(source: &str) -> ValidatorRef {
+    let regex = Regex::new(source)
+        .unwrap_or_else(|err| panic!("invalid #[lbc(pattern = {:?})]: {}", source, err));
+    pattern(regex)
+}
+
+/// A [`LbcForm`] value wired to a [`FormContext`]; used by the generated components.
+pub struct ModelBinding<T: LbcForm> {
+    form: FormContext,
+    base: StoredValue<T>,
+}
+
+impl<T: LbcForm> Clone for ModelBinding<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: LbcForm> Copy for ModelBinding<T> {}
+
+impl<T: LbcForm> ModelBinding<T> {
+    /// The edited value for the submitted `values`, or `None` after storing the parse errors in
+    /// the form.
+    pub fn submit(&self, values: &FormValues) -> Option<T> {
+        match self.base.with_value(|base| base.with_form_values(values)) {
+            Ok(value) => Some(value),
+            Err(errors) => {
+                for (name, error) in errors {
+                    self.form.set_error(name, Some(error));
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Registers the fields of `initial` in `form` and calls `on_change` with the edited value
+/// whenever a change leaves every field parseable.
+pub fn bind_model<T: LbcForm>(
+    form: FormContext,
+    initial: T,
+    on_change: Option<Callback<T>>,
+) -> ModelBinding<T> {
+    for (name, value) in initial.to_form_values() {
+        form.register(name, value);
+    }
+    let base = StoredValue::new(initial);
+    if let Some(on_change) = on_change {
+        Effect::new(move |previous: Option<()>| {
+            let values = form.values();
+            // The first run only subscribes; the initial value is not a change.
+            if previous.is_some()
+                && let Ok(value) = base.with_value(|base| base.with_form_values(&values))
+            {
+                on_change.run(value);
+            }
+        });
+    }
+    ModelBinding { form, base }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::form::context::use_form;
+    use crate::form::select::SelectOption;
+    use crate::form::validation::Validator;
+    use leptos::prelude::{Owner, RenderHtml, view};
+
+    fn no_digits(value: &str) -> Result<(), String> {
+        if value.chars().any(|c| c.is_ascii_digit()) {
+            Err("No digits, please.".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn plans() -> Vec<SelectOption<String>> {
+        vec![
+            SelectOption::new("free".to_string(), "Free"),
+            SelectOption::new("pro".to_string(), "Professional"),
+        ]
+    }
+
+    #[derive(Clone, Debug, PartialEq, LbcForm)]
+    #[lbc(component = "ProfileEditor", submit = "Save")]
+    struct Profile {
+        #[lbc(label = "Full name", required, min_length = 2, validate = no_digits)]
+        name: String,
+        #[lbc(
+            widget = "email",
+            help = "We never share it",
+            placeholder = "you@example.com",
+            email
+        )]
+        email: String,
+        #[lbc(min = 0, max = 150)]
+        age: Option<u32>,
+        score: f64,
+        #[lbc(options("admin", "viewer"))]
+        role: String,
+        #[lbc(widget = "select", options_fn = plans)]
+        plan: String,
+        #[lbc(widget = "textarea")]
+        about_me: String,
+        newsletter: bool,
+        #[lbc(widget = "switch", label = "Public profile")]
+        public: bool,
+        #[lbc(skip)]
+        id: u64,
+    }
+
+    fn profile() -> Profile {
+        Profile {
+            name: "Ada".to_string(),
+            email: "ada@example.com".to_string(),
+            age: None,
+            score: 1.5,
+            role: "viewer".to_string(),
+            plan: "pro".to_string(),
+            about_me: String::new(),
+            newsletter: true,
+            public: false,
+            id: 7,
+        }
+    }
+
+    #[test]
+    fn struct_round_trips_through_form_values() {
+        let values = profile().to_form_values();
+        assert_eq!(values.get("age"), Some(&String::new()));
+        assert_eq!(values.get("score"), Some(&"1.5".to_string()));
+        assert_eq!(values.get("newsletter"), Some(&"true".to_string()));
+        assert!(
+            !values.contains_key("id"),
+            "skipped fields are not in the form"
+        );
+        assert_eq!(profile().with_form_values(&values), Ok(profile()));
+
+        let mut edited = values.clone();
+        edited.insert("age".to_string(), "36".to_string());
+        edited.insert("public".to_string(), "true".to_string());
+        let next = profile().with_form_values(&edited).expect("valid values");
+        assert_eq!(next.age, Some(36));
+        assert!(next.public);
+        assert_eq!(next.id, 7, "skipped fields keep their value");
+    }
+
+    #[test]
+    fn unparseable_values_are_reported_per_field() {
+        let mut values = profile().to_form_values();
+        values.insert("age".to_string(), "old".to_string());
+        values.insert("score".to_string(), String::new());
+        let errors = profile().with_form_values(&values).expect_err("invalid");
+        assert_eq!(
+            errors.get("age").map(String::as_str),
+            Some("Please enter a valid value.")
+        );
+        assert_eq!(
+            errors.get("score").map(String::as_str),
+            Some("This field is required.")
+        );
+        assert!(parses::<u32>().validate("").is_ok());
+        assert!(parses::<u32>().validate("-1").is_err());
+    }
+
+    #[test]
+    fn generated_form_renders_fields_and_submits_the_edited_struct() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let form = use_form();
+            let html = view! {
+                <ProfileEditor
+                    initial=profile()
+                    on_submit=Callback::new(|_profile: Profile| {})
+                    form=form
+                />
+            }
+            .to_html();
+
+            assert!(
+                html.contains(">Full name</label>") && html.contains(">About me</label>"),
+                "expected explicit and humanized labels: {}",
+                html
+            );
+            assert!(
+                html.contains(r#"type="email""#)
+                    && html.contains(r#"placeholder="you@example.com""#)
+                    && html.contains("We never share it"),
+                "expected email input with placeholder and help: {}",
+                html
+            );
+            assert!(
+                html.contains(r#"type="number""#),
+                "expected number inputs: {}",
+                html
+            );
+            assert!(
+                html.contains(r#"<textarea name="about_me""#),
+                "expected a textarea: {}",
+                html
+            );
+            assert!(
+                html.contains(">admin</option>") && html.contains(">Professional</option>"),
+                "expected select options: {}",
+                html
+            );
+            assert!(
+                html.contains(r#"role="switch""#) && html.contains("Public profile"),
+                "expected a switch: {}",
+                html
+            );
+            assert!(
+                html.contains(r#"<button type="submit" class="button is-primary">Save</button>"#),
+                "expected the submit button: {}",
+                html
+            );
+
+            form.set_value("name", "Ada Lovelace");
+            form.set_value("age", "36");
+            let values = form.submit().expect("valid form");
+            let edited = profile().with_form_values(&values).expect("parses");
+            assert_eq!(edited.name, "Ada Lovelace");
+            assert_eq!(edited.age, Some(36));
+            assert_eq!(edited.id, 7);
+
+            form.set_value("name", "R2D2");
+            form.set_value("age", "200");
+            assert!(form.submit().is_none());
+            assert_eq!(form.error("name"), Some("No digits, please.".to_string()));
+            assert!(form.error("age").is_some(), "range validator applies");
+            assert!(pattern_validator("^a").validate("b").is_err());
+        });
+    }
+}
@@ -0,0 +1,520 @@
+/*!
+Typed numeric input: `NumberInput<T>`.
+
+Wraps [`Input`] with `type="number"`, parses the text into `T` and enforces `min`/`max`.
+With `stepper=true` it renders -/+ buttons as Bulma field addons.
+
+Bulma docs: https://bulma.io/documentation/form/general/#form-addons
+*/
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use leptos::callback::Callback;
+use leptos::ev::MouseEvent;
+use leptos::prelude::{
+    Callable, ClassAttribute, CustomAttribute, Effect, ElementChild, Get, GetUntracked, IntoAny,
+    IntoView, OnAttribute, RwSignal, Set, Signal, component, view,
+};
+
+use crate::elements::button::Button;
+use crate::form::control::Control;
+use crate::form::field::Field;
+use crate::form::input::{Input, InputType};
+use crate::util::{Size, TestAttr};
+
+/// Why the text of a [`NumberInput`] is not an acceptable value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The text is not a number of the expected type.
+    Invalid(String),
+    /// The number is below `min` (carries the bound as text).
+    BelowMin(String),
+    /// The number is above `max` (carries the bound as text).
+    AboveMax(String),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "Please enter a number."),
+            ParseError::Invalid(text) => write!(f, "'{}' is not a valid number.", text),
+            ParseError::BelowMin(min) => write!(f, "Must be at least {}.", min),
+            ParseError::AboveMax(max) => write!(f, "Must be at most {}.", max),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse `text` into `T` and check it against the optional bounds.
+pub fn parse_number<T>(text: &str, min: Option<&T>, max: Option<&T>) -> Result<T, ParseError>
+where
+    T: FromStr + Display + PartialOrd,
+{
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let number = text
+        .parse::<T>()
+        .map_err(|_| ParseError::Invalid(text.to_string()))?;
+    if let Some(min) = min.filter(|min| number < **min) {
+        return Err(ParseError::BelowMin(min.to_string()));
+    }
+    if let Some(max) = max.filter(|max| number > **max) {
+        return Err(ParseError::AboveMax(max.to_string()));
+    }
+    Ok(number)
+}
+
+/// Numbers a [`NumberInput`] can step through with its -/+ buttons.
+///
+/// Stepping saturates at the bounds of the type, so an unsigned value at 0 stays at 0.
+pub trait StepNumber: Sized {
+    /// `self + step`, saturating at the largest value of the type.
+    fn step_up(&self, step: &Self) -> Self;
+    /// `self - step`, saturating at the smallest value of the type.
+    fn step_down(&self, step: &Self) -> Self;
+}
+
+macro_rules! impl_step_number_int {
+    ($($t:ty),*) => {$(
+        impl StepNumber for $t {
+            fn step_up(&self, step: &Self) -> Self {
+                self.saturating_add(*step)
+            }
+            fn step_down(&self, step: &Self) -> Self {
+                self.saturating_sub(*step)
+            }
+        }
+    )*};
+}
+
+impl_step_number_int!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+macro_rules! impl_step_number_float {
+    ($($t:ty),*) => {$(
+        impl StepNumber for $t {
+            fn step_up(&self, step: &Self) -> Self {
+                self + step
+            }
+            fn step_down(&self, step: &Self) -> Self {
+                self - step
+            }
+        }
+    )*};
+}
+
+impl_step_number_float!(f32, f64);
+
+fn clamp<T: PartialOrd + Clone>(number: T, min: Option<&T>, max: Option<&T>) -> T {
+    match (min, max) {
+        (Some(min), _) if number < *min => min.clone(),
+        (_, Some(max)) if number > *max => max.clone(),
+        _ => number,
+    }
+}
+
+/// A numeric input that parses its value into `T`.
+///
+/// Controlled component:
+/// - `value` is the current number.
+/// - `update` receives only valid, in-range numbers.
+/// - `update_result` (optional) receives every parse result, including errors, on each input.
+///
+/// Out-of-range numbers are reported as errors while typing and clamped to `min`/`max`
+/// when the input loses focus. The -/+ buttons (`stepper=true`) step by `step`
+/// (default 1) and never leave the range or the bounds of `T`.
+#[component]
+pub fn NumberInput<T>(
+    /// The `name` attribute for this form element.
+    #[prop(into)]
+    name: Signal<String>,
+
+    /// The controlled value of this form element.
+    ///
+    /// Not `into`: pass a `Signal<T>` (e.g. `count.into()` or `Signal::stored(1)`) so `T` can be inferred.
+    value: Signal<T>,
+
+    /// Callback receiving valid, in-range numbers.
+    #[prop(optional)]
+    update: Option<Callback<T>>,
+
+    /// Callback receiving the parse result of every input, including errors.
+    #[prop(optional)]
+    update_result: Option<Callback<Result<T, ParseError>>>,
+
+    /// Smallest allowed value.
+    #[prop(optional)]
+    min: Option<T>,
+
+    /// Largest allowed value.
+    #[prop(optional)]
+    max: Option<T>,
+
+    /// Increment used by the stepper buttons and the `step` attribute. Defaults to 1.
+    #[prop(optional)]
+    step: Option<T>,
+
+    /// Render -/+ buttons as field addons.
+    #[prop(optional, into)]
+    stepper: Signal<bool>,
+
+    /// Extra classes to apply to the input.
+    #[prop(optional, into)]
+    classes: Signal<String>,
+
+    /// The placeholder value for this component.
+    #[prop(optional, into)]
+    placeholder: Signal<String>,
+
+    /// The size of this component (input and stepper buttons).
+    #[prop(optional)]
+    size: Option<Size>,
+
+    /// Disable this component.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Optional test attribute (renders as data-* attribute) on the wrapper <div>.
+    ///
+    /// When provided as a &str or String, this becomes `data-testid="value"`.
+    /// You can also pass a full `TestAttr` to override the attribute key.
+    #[prop(optional, into)]
+    test_attr: Option<TestAttr>,
+) -> impl IntoView
+where
+    T: FromStr + Display + PartialOrd + Clone + StepNumber,
+    T: Send + Sync + 'static,
+{
+    let step = step.or_else(|| "1".parse::<T>().ok());
+    let step_attr = step
+        .as_ref()
+        .map(ToString::to_string)
+        .unwrap_or_else(|| "1".to_string());
+    let min_attr = min.as_ref().map(ToString::to_string).unwrap_or_default();
+    let max_attr = max.as_ref().map(ToString::to_string).unwrap_or_default();
+
+    // The raw text is kept locally so partial input such as "-" or "1." is not overwritten.
+    let text = RwSignal::new(value.get_untracked().to_string());
+    {
+        let (min, max) = (min.clone(), max.clone());
+        Effect::new(move |_| {
+            let current = value.get();
+            let shown = parse_number(&text.get_untracked(), min.as_ref(), max.as_ref());
+            if shown.ok().is_none_or(|shown| shown != current) {
+                text.set(current.to_string());
+            }
+        });
+    }
+
+    let emit = move |number: T| {
+        if let Some(update) = update {
+            update.run(number);
+        }
+    };
+
+    let on_text = {
+        let (min, max) = (min.clone(), max.clone());
+        Callback::new(move |new_text: String| {
+            let result = parse_number(&new_text, min.as_ref(), max.as_ref());
+            text.set(new_text);
+            if let Some(update_result) = update_result {
+                update_result.run(result.clone());
+            }
+            if let Ok(number) = result {
+                emit(number);
+            }
+        })
+    };
+
+    let on_focusout = {
+        let (min, max) = (min.clone(), max.clone());
+        move |_ev: leptos::ev::FocusEvent| {
+            let current = text.get_untracked();
+            let Ok(number) = current.trim().parse::<T>() else {
+                return;
+            };
+            let clamped = clamp(number.clone(), min.as_ref(), max.as_ref());
+            if clamped != number {
+                text.set(clamped.to_string());
+                if let Some(update_result) = update_result {
+                    update_result.run(Ok(clamped.clone()));
+                }
+                emit(clamped);
+            }
+        }
+    };
+
+    let step_by = {
+        let (min, max, step) = (min.clone(), max.clone(), step.clone());
+        move |up: bool| {
+            let Some(step) = step.clone() else {
+                return;
+            };
+            let current = text
+                .get_untracked()
+                .trim()
+                .parse::<T>()
+                .unwrap_or_else(|_| value.get_untracked());
+            let next = if up {
+                current.step_up(&step)
+            } else {
+                current.step_down(&step)
+            };
+            let next = clamp(next, min.as_ref(), max.as_ref());
+            text.set(next.to_string());
+            if let Some(update_result) = update_result {
+                update_result.run(Ok(next.clone()));
+            }
+            emit(next);
+        }
+    };
+
+    // A button is disabled at `min`/`max` and where stepping saturates at the type's bounds.
+    let at_min = {
+        let (min, step) = (min.clone(), step.clone());
+        move || {
+            let current = value.get();
+            min.as_ref().is_some_and(|min| current <= *min)
+                || step
+                    .as_ref()
+                    .is_some_and(|step| current.step_down(step) >= current)
+        }
+    };
+    let at_max = {
+        let (max, step) = (max.clone(), step.clone());
+        move || {
+            let current = value.get();
+            max.as_ref().is_some_and(|max| current >= *max)
+                || step
+                    .as_ref()
+                    .is_some_and(|step| current.step_up(step) <= current)
+        }
+    };
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    let input = move || {
+        view! {
+            <Input
+                name=name
+                value=text
+                update=on_text
+                r#type=InputType::Number
+                classes=classes
+                placeholder=placeholder
+                size=size.unwrap_or(Size::Normal)
+                disabled=disabled
+                step=step_attr.clone()
+                min=min_attr.clone()
+                max=max_attr.clone()
+            />
+        }
+    };
+
+    move || {
+        if stepper.get() {
+            let step_down = step_by.clone();
+            let step_up = step_by.clone();
+            let at_min = at_min.clone();
+            let at_max = at_max.clone();
+            let input = input.clone();
+            view! {
+                <div
+                    class="number-input"
+                    attr:data-testid=data_testid.clone()
+                    attr:data-cy=data_cy.clone()
+                    on:focusout=on_focusout.clone()
+                >
+                    <Field addons=true>
+                        <Control>
+                            <Button
+                                r#type="button"
+                                size=size.unwrap_or(Size::Normal)
+                                disabled=Signal::derive(move || disabled.get() || at_min())
+                                on_click=Callback::new(move |_: MouseEvent| step_down(false))
+                            >
+                                "-"
+                            </Button>
+                        </Control>
+                        <Control expanded=true>{input()}</Control>
+                        <Control>
+                            <Button
+                                r#type="button"
+                                size=size.unwrap_or(Size::Normal)
+                                disabled=Signal::derive(move || disabled.get() || at_max())
+                                on_click=Callback::new(move |_: MouseEvent| step_up(true))
+                            >
+                                "+"
+                            </Button>
+                        </Control>
+                    </Field>
+                </div>
+            }
+            .into_any()
+        } else {
+            view! { <div
+                class="number-input"
+                attr:data-testid=data_testid.clone()
+                attr:data-cy=data_cy.clone()
+                on:focusout=on_focusout.clone()
+            >{input()}</div> }
+            .into_any()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::prelude::RenderHtml;
+
+    #[test]
+    fn number_input_renders_test_attr_on_wrapper() {
+        let html = view! {
+            <NumberInput name="qty" value=Signal::stored(0_u32) test_attr="qty-input" />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"data-testid="qty-input""#),
+            "expected data-testid on wrapper; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn parse_number_reports_typed_errors() {
+        assert_eq!(parse_number::<i32>(" 42 ", None, None), Ok(42));
+        assert_eq!(parse_number::<i32>("", None, None), Err(ParseError::Empty));
+        assert_eq!(
+            parse_number::<i32>("4.5", None, None),
+            Err(ParseError::Invalid("4.5".to_string()))
+        );
+        assert_eq!(
+            parse_number::<f64>("-1", Some(&0.0), None),
+            Err(ParseError::BelowMin("0".to_string()))
+        );
+        assert_eq!(
+            parse_number::<u8>("11", None, Some(&10)),
+            Err(ParseError::AboveMax("10".to_string()))
+        );
+    }
+
+    #[test]
+    fn clamp_limits_to_bounds() {
+        assert_eq!(clamp(5, Some(&1), Some(&3)), 3);
+        assert_eq!(clamp(-5, Some(&1), Some(&3)), 1);
+        assert_eq!(clamp(2, None, None), 2);
+    }
+
+    #[test]
+    fn stepping_saturates_at_the_type_bounds() {
+        assert_eq!(0_u32.step_down(&1), 0);
+        assert_eq!(250_u8.step_up(&10), 255);
+        assert_eq!(1.5_f64.step_down(&0.5), 1.0);
+
+        let html = view! {
+            <NumberInput name="qty" value=Signal::stored(0_u32) stepper=true />
+        }
+        .to_html();
+        assert!(
+            html.contains("disabled"),
+            "expected minus button disabled at 0 without min; got: {}",
+            html
+        );
+        let html = view! {
+            <NumberInput name="qty" value=Signal::stored(5_u32) stepper=true />
+        }
+        .to_html();
+        assert!(
+            !html.contains("disabled"),
+            "expected enabled buttons above 0; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn number_input_renders_typed_value_and_bounds() {
+        let html = view! {
+            <NumberInput name="qty" value=Signal::stored(3_i32) min=1 max=9 />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"type="number""#) && html.contains(r#"name="qty""#),
+            "expected named number input; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"min="1""#) && html.contains(r#"max="9""#),
+            "expected min/max attributes; got: {}",
+            html
+        );
+        assert!(
+            !html.contains("has-addons"),
+            "expected no stepper by default; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn number_input_stepper_renders_addon_buttons() {
+        let html = view! {
+            <NumberInput name="qty" value=Signal::stored(1.5_f64) step=0.5 min=1.5 stepper=true />
+        }
+        .to_html();
+
+        assert!(
+            html.contains("field has-addons"),
+            "expected addons field; got: {}",
+            html
+        );
+        assert!(
+            html.contains(">-<") && html.contains(">+<"),
+            "expected -/+ buttons; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"step="0.5""#),
+            "expected step attribute; got: {}",
+            html
+        );
+        assert!(
+            html.contains("disabled"),
+            "expected minus button disabled at min; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn number_input_renders_the_step_exactly() {
+        let html =
+            view! { <NumberInput name="rate" value=Signal::stored(0.3_f64) step=0.1 /> }.to_html();
+        assert!(
+            html.contains(r#"step="0.1""#),
+            "expected an unrounded decimal step; got: {}",
+            html
+        );
+
+        let html = view! {
+            <NumberInput name="id" value=Signal::stored(0_u64) step=9_007_199_254_740_993_u64 />
+        }
+        .to_html();
+        assert!(
+            html.contains(r#"step="9007199254740993""#),
+            "expected the exact integer step; got: {}",
+            html
+        );
+    }
+}
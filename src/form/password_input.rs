@@ -0,0 +1,572 @@
+/*!
+A password input with a show/hide toggle and a strength meter.
+
+The eye button is a Bulma addon control that switches the input between `type="password"` and
+`type="text"`. Below the field a [`Progress`] bar shows how strong the password is, colored
+`is-danger`, `is-warning` or `is-success`.
+
+Scoring is pluggable: pass any [`StrengthEstimator`] (a closure works) as `estimator`. The default
+is [`EntropyEstimator`], which estimates the entropy from the character classes used and the
+length, discounting repeated characters, runs like `abc` and very common passwords:
+
+```ignore
+view! { <PasswordInput name="password" value=password update=set_password autocomplete="new-password" /> }
+
+let policy = |password: &str| {
+    if password.len() >= 12 {
+        PasswordStrength::new(StrengthLevel::Strong, 1.0)
+    } else {
+        PasswordStrength::new(StrengthLevel::Weak, 0.2).with_feedback("Use at least 12 characters.")
+    }
+};
+view! { <PasswordInput name="password" value=password update=set_password estimator=policy /> }
+```
+
+Bulma docs: https://bulma.io/documentation/form/general/#form-addons
+*/
+
+use std::sync::Arc;
+
+use leptos::callback::Callback;
+use leptos::prelude::{
+    AriaAttributes, ClassAttribute, CustomAttribute, ElementChild, Get, IntoView, Memo,
+    OnAttribute, PropAttribute, RwSignal, Signal, Update, With, component, view,
+};
+
+use crate::elements::icon::Icon;
+use crate::elements::progress::Progress;
+use crate::form::control::Control;
+use crate::form::field::Field;
+use crate::form::input::{Input, InputType};
+use crate::util::{Size, TestAttr};
+
+/// How strong a password is, from weakest to strongest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum StrengthLevel {
+    /// Easy to guess; shown in red.
+    Weak,
+    /// Acceptable but could be better; shown in yellow.
+    Fair,
+    /// Hard to guess; shown in green.
+    Strong,
+}
+
+impl StrengthLevel {
+    /// Returns the Bulma color class for this level.
+    ///
+    /// - `StrengthLevel::Weak` => `"is-danger"`
+    /// - `StrengthLevel::Fair` => `"is-warning"`
+    /// - `StrengthLevel::Strong` => `"is-success"`
+    pub fn bulma(self) -> &'static str {
+        match self {
+            StrengthLevel::Weak => "is-danger",
+            StrengthLevel::Fair => "is-warning",
+            StrengthLevel::Strong => "is-success",
+        }
+    }
+
+    /// The label shown under the meter.
+    pub fn label(self) -> &'static str {
+        match self {
+            StrengthLevel::Weak => "Weak",
+            StrengthLevel::Fair => "Fair",
+            StrengthLevel::Strong => "Strong",
+        }
+    }
+}
+
+/// The result of a [`StrengthEstimator`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PasswordStrength {
+    /// How strong the password is.
+    pub level: StrengthLevel,
+    /// How far the meter is filled, from 0.0 to 1.0.
+    pub score: f32,
+    /// An optional hint shown after the label, e.g. "Use at least 8 characters."
+    pub feedback: Option<String>,
+}
+
+impl PasswordStrength {
+    /// A strength of `level` filling the meter to `score` (clamped to 0.0..=1.0).
+    pub fn new(level: StrengthLevel, score: f32) -> Self {
+        Self {
+            level,
+            score: score.clamp(0.0, 1.0),
+            feedback: None,
+        }
+    }
+
+    /// Adds a hint shown after the label.
+    pub fn with_feedback(mut self, feedback: impl Into<String>) -> Self {
+        self.feedback = Some(feedback.into());
+        self
+    }
+}
+
+/// Scores passwords for [`PasswordInput`]'s strength meter.
+pub trait StrengthEstimator: Send + Sync + 'static {
+    /// Returns the strength of `password`.
+    fn estimate(&self, password: &str) -> PasswordStrength;
+}
+
+impl<F> StrengthEstimator for F
+where
+    F: Fn(&str) -> PasswordStrength + Send + Sync + 'static,
+{
+    fn estimate(&self, password: &str) -> PasswordStrength {
+        self(password)
+    }
+}
+
+/// A shared, type-erased estimator as accepted by `PasswordInput`'s `estimator` prop.
+#[derive(Clone)]
+pub struct StrengthEstimatorRef(Arc<dyn StrengthEstimator>);
+
+impl StrengthEstimatorRef {
+    pub fn new<E: StrengthEstimator>(estimator: E) -> Self {
+        Self(Arc::new(estimator))
+    }
+
+    /// Returns the strength of `password`.
+    pub fn estimate(&self, password: &str) -> PasswordStrength {
+        self.0.estimate(password)
+    }
+}
+
+impl<E: StrengthEstimator> From<E> for StrengthEstimatorRef {
+    fn from(estimator: E) -> Self {
+        StrengthEstimatorRef::new(estimator)
+    }
+}
+
+impl std::fmt::Debug for StrengthEstimatorRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("StrengthEstimatorRef")
+    }
+}
+
+/// Passwords scored as zero bits whatever their length.
+const COMMON_PASSWORDS: &[&str] = &[
+    "123123",
+    "111111",
+    "123456",
+    "1234567",
+    "12345678",
+    "123456789",
+    "1234567890",
+    "abc123",
+    "admin",
+    "dragon",
+    "football",
+    "iloveyou",
+    "letmein",
+    "monkey",
+    "passw0rd",
+    "password",
+    "password1",
+    "qwerty",
+    "qwertyuiop",
+    "sunshine",
+    "welcome",
+];
+
+fn is_common(password: &str) -> bool {
+    COMMON_PASSWORDS.contains(&password.to_lowercase().as_str())
+}
+
+/// A character class: its members and how many characters it has.
+type CharacterClass = (fn(char) -> bool, u32);
+
+/// Size of the alphabet `password` draws from and the number of character classes it uses.
+fn character_pool(password: &str) -> (u32, u32) {
+    let classes: [CharacterClass; 5] = [
+        (|c| c.is_ascii_lowercase(), 26),
+        (|c| c.is_ascii_uppercase(), 26),
+        (|c| c.is_ascii_digit(), 10),
+        (|c| c.is_ascii_punctuation() || c == ' ', 33),
+        (|c| !c.is_ascii(), 100),
+    ];
+    classes
+        .iter()
+        .filter(|(matches, _)| password.chars().any(matches))
+        .fold((0, 0), |(pool, used), (_, size)| (pool + size, used + 1))
+}
+
+/// Estimated entropy of `password` in bits.
+///
+/// Each character adds `log2(pool)` bits, where the pool is the combined size of the character
+/// classes used (lower case, upper case, digits, symbols, other). Characters repeating the
+/// previous one or continuing a run such as `abc` or `321` add nothing, and common passwords
+/// score zero.
+pub fn entropy_bits(password: &str) -> f64 {
+    if password.is_empty() || is_common(password) {
+        return 0.0;
+    }
+    let (pool, _) = character_pool(password);
+    let mut effective = 0u32;
+    let mut previous: Option<(char, Option<i64>)> = None;
+    for c in password.chars() {
+        let step = previous.map(|(p, _)| c as i64 - p as i64);
+        let predictable = match (step, previous) {
+            (Some(0), _) => true,
+            (Some(step), Some((_, Some(last)))) => step.abs() == 1 && step == last,
+            _ => false,
+        };
+        if !predictable {
+            effective += 1;
+        }
+        previous = Some((c, step));
+    }
+    effective as f64 * (pool as f64).log2()
+}
+
+/// The default [`StrengthEstimator`], based on [`entropy_bits`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EntropyEstimator {
+    /// Bits needed for [`StrengthLevel::Fair`].
+    pub fair_bits: f64,
+    /// Bits needed for [`StrengthLevel::Strong`]; the meter is full from here on.
+    pub strong_bits: f64,
+    /// Passwords shorter than this get a "use at least" hint.
+    pub min_length: usize,
+}
+
+impl Default for EntropyEstimator {
+    fn default() -> Self {
+        Self {
+            fair_bits: 36.0,
+            strong_bits: 60.0,
+            min_length: 8,
+        }
+    }
+}
+
+impl StrengthEstimator for EntropyEstimator {
+    fn estimate(&self, password: &str) -> PasswordStrength {
+        let bits = entropy_bits(password);
+        let level = if bits >= self.strong_bits {
+            StrengthLevel::Strong
+        } else if bits >= self.fair_bits {
+            StrengthLevel::Fair
+        } else {
+            StrengthLevel::Weak
+        };
+        let strength = PasswordStrength::new(level, (bits / self.strong_bits) as f32);
+        let (_, classes) = character_pool(password);
+        if password.is_empty() {
+            strength
+        } else if is_common(password) {
+            strength.with_feedback("This password is too common.")
+        } else if password.chars().count() < self.min_length {
+            strength.with_feedback(format!("Use at least {} characters.", self.min_length))
+        } else if level != StrengthLevel::Strong && classes < 3 {
+            strength.with_feedback("Mix upper and lower case letters, numbers and symbols.")
+        } else {
+            strength
+        }
+    }
+}
+
+/// A password input with a show/hide toggle and a strength meter.
+///
+/// Controlled component: `value` is the current password and `update` receives every edit.
+/// The meter is hidden while the input is empty and can be turned off with
+/// `show_strength=false` (e.g. on login pages).
+#[component]
+pub fn PasswordInput(
+    /// The `name` attribute for this form element.
+    #[prop(into)]
+    name: Signal<String>,
+
+    /// The controlled value of this form element.
+    #[prop(into)]
+    value: Signal<String>,
+
+    /// The callback used to propagate changes to the parent.
+    update: Callback<String>,
+
+    /// Scores the password for the meter. Defaults to [`EntropyEstimator`].
+    #[prop(optional, into)]
+    estimator: Option<StrengthEstimatorRef>,
+
+    /// Show the strength meter below the input.
+    #[prop(default = true.into(), into)]
+    show_strength: Signal<bool>,
+
+    /// The `autocomplete` attribute, e.g. `"new-password"` on signup pages.
+    #[prop(optional, into)]
+    autocomplete: Signal<String>,
+
+    /// Accessible label of the toggle while the password is hidden.
+    #[prop(default = "Show password".to_string().into(), into)]
+    show_label: Signal<String>,
+
+    /// Accessible label of the toggle while the password is shown.
+    #[prop(default = "Hide password".to_string().into(), into)]
+    hide_label: Signal<String>,
+
+    /// Extra classes to apply to the input.
+    #[prop(optional, into)]
+    classes: Signal<String>,
+
+    /// The placeholder value for this component.
+    #[prop(optional, into)]
+    placeholder: Signal<String>,
+
+    /// The size of this component (input and toggle button).
+    #[prop(optional)]
+    size: Option<Size>,
+
+    /// Disable this component.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Optional test attribute (renders as data-* attribute) on the wrapper <div>.
+    ///
+    /// When provided as a &str or String, this becomes `data-testid="value"`.
+    /// You can also pass a full `TestAttr` to override the attribute key.
+    #[prop(optional, into)]
+    test_attr: Option<TestAttr>,
+) -> impl IntoView {
+    let estimator = estimator.unwrap_or_else(|| EntropyEstimator::default().into());
+    let size = size.unwrap_or(Size::Normal);
+    let revealed = RwSignal::new(false);
+    let strength = Memo::new(move |_| estimator.estimate(&value.get()));
+
+    // The input is re-created when toggled; focus is on the toggle at that point anyway.
+    let input = move || {
+        let input_type = if revealed.get() {
+            InputType::Text
+        } else {
+            InputType::Password
+        };
+        view! {
+            <Input
+                name=name
+                value=value
+                update=update
+                r#type=input_type
+                classes=classes
+                placeholder=placeholder
+                autocomplete=autocomplete
+                size=size
+                disabled=disabled
+            />
+        }
+    };
+
+    let toggle_class = move || {
+        let size = size.bulma();
+        if size.is_empty() {
+            "button".to_string()
+        } else {
+            format!("button {}", size)
+        }
+    };
+
+    let meter = move || {
+        (show_strength.get() && value.with(|value| !value.is_empty())).then(|| {
+            view! {
+                <div class="password-strength">
+                    <Progress
+                        classes=Signal::derive(move || {
+                            format!("is-small mb-1 {}", strength.with(|s| s.level.bulma()))
+                        })
+                        value=Signal::derive(move || strength.with(|s| s.score))
+                    />
+                    <p
+                        class=move || format!("help {}", strength.with(|s| s.level.bulma()))
+                        aria-live="polite"
+                    >
+                        {move || {
+                            strength
+                                .with(|s| match &s.feedback {
+                                    Some(feedback) => format!("{}. {}", s.level.label(), feedback),
+                                    None => s.level.label().to_string(),
+                                })
+                        }}
+                    </p>
+                </div>
+            }
+        })
+    };
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    view! {
+        <div class="password-input" attr:data-testid=data_testid attr:data-cy=data_cy>
+            <Field addons=true>
+                <Control expanded=true>{input}</Control>
+                <Control>
+                    <button
+                        type="button"
+                        class=toggle_class
+                        aria-label=move || {
+                            if revealed.get() { hide_label.get() } else { show_label.get() }
+                        }
+                        aria-pressed=move || revealed.get().to_string()
+                        prop:disabled=move || disabled.get()
+                        on:click=move |_| revealed.update(|revealed| *revealed = !*revealed)
+                    >
+                        <Icon>
+                            <i class=move || {
+                                if revealed.get() {
+                                    "fa-solid fa-eye-slash"
+                                } else {
+                                    "fa-solid fa-eye"
+                                }
+                            }></i>
+                        </Icon>
+                    </button>
+                </Control>
+            </Field>
+            {meter}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::prelude::{Owner, RenderHtml};
+
+    #[test]
+    fn entropy_estimator_grades_passwords() {
+        let estimator = EntropyEstimator::default();
+
+        let empty = estimator.estimate("");
+        assert_eq!(empty, PasswordStrength::new(StrengthLevel::Weak, 0.0));
+
+        let common = estimator.estimate("Password");
+        assert_eq!(common.level, StrengthLevel::Weak);
+        assert_eq!(
+            common.feedback.as_deref(),
+            Some("This password is too common.")
+        );
+
+        let short = estimator.estimate("x7!");
+        assert_eq!(short.level, StrengthLevel::Weak);
+        assert_eq!(
+            short.feedback.as_deref(),
+            Some("Use at least 8 characters.")
+        );
+
+        assert!(
+            entropy_bits("aaaaaaaaaa") < entropy_bits("ab"),
+            "repeats add nothing"
+        );
+        assert!(
+            entropy_bits("abcdefgh") == entropy_bits("ab"),
+            "runs add nothing"
+        );
+
+        let fair = estimator.estimate("sunflower7");
+        assert_eq!(fair.level, StrengthLevel::Fair);
+        assert!(fair.score > 0.5 && fair.score < 1.0);
+        assert_eq!(
+            fair.feedback.as_deref(),
+            Some("Mix upper and lower case letters, numbers and symbols.")
+        );
+
+        let strong = estimator.estimate("Tr0ub4dor&3");
+        assert_eq!(strong.level, StrengthLevel::Strong);
+        assert_eq!(strong.score, 1.0);
+        assert_eq!(strong.feedback, None);
+        assert_eq!(
+            estimator.estimate("correct horse battery staple").level,
+            StrengthLevel::Strong
+        );
+    }
+
+    #[test]
+    fn closures_are_estimators() {
+        let by_length = StrengthEstimatorRef::from(|password: &str| {
+            if password.len() >= 12 {
+                PasswordStrength::new(StrengthLevel::Strong, 1.0)
+            } else {
+                PasswordStrength::new(StrengthLevel::Weak, 2.0).with_feedback("Too short.")
+            }
+        });
+        assert_eq!(
+            by_length.estimate("aaaaaaaaaaaa").level,
+            StrengthLevel::Strong
+        );
+        let weak = by_length.estimate("Tr0ub4dor&3");
+        assert_eq!(weak.score, 1.0, "scores are clamped");
+        assert_eq!(weak.feedback.as_deref(), Some("Too short."));
+        assert_eq!(format!("{:?}", by_length), "StrengthEstimatorRef");
+    }
+
+    #[test]
+    fn password_input_renders_toggle_and_meter() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let html = view! {
+                <PasswordInput
+                    name="password"
+                    value="sunflower7"
+                    update=Callback::new(|_: String| {})
+                    autocomplete="new-password"
+                    test_attr="signup-password"
+                />
+            }
+            .to_html();
+
+            assert!(
+                html.contains(r#"type="password""#)
+                    && html.contains(r#"autocomplete="new-password""#),
+                "expected a masked input: {}",
+                html
+            );
+            assert!(
+                html.contains("has-addons")
+                    && html.contains(r#"aria-label="Show password""#)
+                    && html.contains(r#"aria-pressed="false""#)
+                    && html.contains("fa-eye"),
+                "expected the eye toggle addon: {}",
+                html
+            );
+            assert!(
+                html.contains("progress is-small mb-1 is-warning")
+                    && html.contains(r#"class="help is-warning""#)
+                    && html.contains("Fair. Mix upper"),
+                "expected a fair strength meter: {}",
+                html
+            );
+            assert!(
+                html.contains(r#"data-testid="signup-password""#),
+                "expected the test attribute: {}",
+                html
+            );
+
+            let empty = view! {
+                <PasswordInput name="password" value="" update=Callback::new(|_: String| {}) />
+            }
+            .to_html();
+            assert!(
+                !empty.contains("<progress"),
+                "no meter while empty: {}",
+                empty
+            );
+
+            let custom = view! {
+                <PasswordInput
+                    name="password"
+                    value="hunter2"
+                    update=Callback::new(|_: String| {})
+                    estimator=|_: &str| PasswordStrength::new(StrengthLevel::Strong, 1.0)
+                />
+            }
+            .to_html();
+            assert!(
+                custom.contains("is-success") && custom.contains(">Strong<"),
+                "expected the custom estimator's result: {}",
+                custom
+            );
+        });
+    }
+}
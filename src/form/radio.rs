@@ -1,9 +1,16 @@
+use crate::form::checkbox::GroupLayout;
+use crate::form::combobox::move_highlight;
+use crate::form::select::{SelectOption, option_dom_value};
 use crate::util::TestAttr;
 use leptos::callback::Callable;
+use leptos::ev::KeyboardEvent;
+use leptos::html;
 use leptos::prelude::{
-    Callback, Children, ClassAttribute, CustomAttribute, ElementChild, GetUntracked, IntoView,
-    OnAttribute, Signal, component, event_target_value, view,
+    AriaAttributes, Callback, Children, ClassAttribute, CollectView, CustomAttribute, ElementChild,
+    Get, GetUntracked, GlobalAttributes, IntoView, NodeRef, NodeRefAttribute, OnAttribute,
+    PropAttribute, Signal, With, WithUntracked, component, event_target_value, view,
 };
+use leptos::wasm_bindgen::JsCast;
 
 /// The mutually exclusive radio buttons in their native format.
 ///
@@ -97,6 +104,189 @@ pub fn Radio(
     }
 }
 
+/// The option a radio group moves to for a navigation key, skipping disabled options.
+///
+/// Right/Down go to the next option and Left/Up to the previous one, wrapping around; Home and
+/// End jump to the first and last option. Returns `None` for keys the group does not handle.
+pub(crate) fn radio_key_target(
+    current: Option<usize>,
+    enabled: &[bool],
+    key: &str,
+) -> Option<usize> {
+    let key = match key {
+        "ArrowDown" | "ArrowRight" => "ArrowDown",
+        "ArrowUp" | "ArrowLeft" => "ArrowUp",
+        "Home" | "End" => key,
+        _ => return None,
+    };
+    move_highlight(current, enabled, key)
+}
+
+/// A group of mutually exclusive radio buttons bound to a typed value.
+///
+/// https://bulma.io/documentation/form/radio/
+///
+/// Controlled component: `value` is the selected option value and `update` receives the value
+/// of the option the user picks. The group owns the shared `name`.
+///
+/// **Native form posts:** each radio submits its option index as its `value` unless
+/// `form_value` is given; a plain HTML form submission (one not handled by `Form`) then sends
+/// `0`, `1`, ... instead of your value. Pass `form_value` to submit a string form of it.
+///
+/// Follows the WAI-ARIA radio group pattern: the container has `role="radiogroup"`, only the
+/// selected (or first enabled) radio is in the tab order, and the arrow keys move the selection
+/// to the next/previous enabled option, wrapping around.
+#[component]
+pub fn RadioGroup<T>(
+    /// The `name` attribute shared by the radios.
+    #[prop(into)]
+    name: String,
+
+    /// The options to render; build them from `(value, label)` pairs with
+    /// `SelectOption::from_pairs`.
+    #[prop(into)]
+    options: Signal<Vec<SelectOption<T>>>,
+
+    /// The selected value.
+    #[prop(into)]
+    value: Signal<T>,
+
+    /// Called with the value of the option the user picks.
+    update: Callback<T>,
+
+    /// Maps an option value to the radio `value` a native form post submits. Values must map
+    /// to distinct strings. Defaults to the option index.
+    #[prop(optional, into)]
+    form_value: Option<Callback<T, String>>,
+
+    /// Horizontal (`GroupLayout::Inline`, default) or vertical (`GroupLayout::Stacked`) layout.
+    #[prop(optional)]
+    layout: GroupLayout,
+
+    /// Accessible name of the group (`aria-label`).
+    #[prop(optional, into)]
+    label: Option<String>,
+
+    /// Extra classes for the group container.
+    #[prop(optional, into)]
+    classes: Signal<String>,
+
+    /// Disable every radio in the group.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Optional test attribute (renders as data-* attribute) on the group container.
+    ///
+    /// When provided as a &str or String, this becomes `data-testid="value"`.
+    /// You can also pass a full `TestAttr` to override the attribute key.
+    #[prop(optional, into)]
+    test_attr: Option<TestAttr>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let root = NodeRef::<html::Div>::new();
+
+    let class = move || {
+        let base = layout.class("radios");
+        let extra = classes.get();
+        if extra.trim().is_empty() {
+            base
+        } else {
+            format!("{} {}", base, extra)
+        }
+    };
+
+    let selected_index = move || {
+        let current = value.get();
+        options.with(|options| options.iter().position(|option| option.value == current))
+    };
+
+    let pick = move |index: usize| {
+        if let Some(option) = options.with_untracked(|options| options.get(index).cloned())
+            && !option.disabled
+        {
+            update.run(option.value);
+        }
+    };
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        if disabled.get_untracked() {
+            return;
+        }
+        let current = {
+            let current = value.get_untracked();
+            options.with_untracked(|options| options.iter().position(|o| o.value == current))
+        };
+        let enabled: Vec<bool> =
+            options.with_untracked(|options| options.iter().map(|o| !o.disabled).collect());
+        let Some(target) = radio_key_target(current, &enabled, &ev.key()) else {
+            return;
+        };
+        ev.prevent_default();
+        pick(target);
+        if let Some(root) = root.get_untracked()
+            && let Ok(Some(input)) =
+                root.query_selector(&format!("label:nth-child({}) > input", target + 1))
+            && let Ok(input) = input.dyn_into::<leptos::web_sys::HtmlElement>()
+        {
+            let _ = input.focus();
+        }
+    };
+
+    let radios = move || {
+        let selected = selected_index();
+        let group_disabled = disabled.get();
+        options.with(|options| {
+            // Roving tab stop: the selected radio, or the first enabled one.
+            let tab_stop = selected.or_else(|| options.iter().position(|o| !o.disabled));
+            options
+                .iter()
+                .enumerate()
+                .map(|(index, option)| {
+                    let checked = selected == Some(index);
+                    view! {
+                        <label class="radio">
+                            <input
+                                r#type="radio"
+                                name=name.clone()
+                                value=option_dom_value(form_value, index, option)
+                                checked=checked
+                                prop:checked=checked
+                                disabled=option.disabled || group_disabled
+                                tabindex=if tab_stop == Some(index) { "0" } else { "-1" }
+                                on:change=move |_| pick(index)
+                            />
+                            {option.label.clone()}
+                        </label>
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    view! {
+        <div
+            node_ref=root
+            class=class
+            role="radiogroup"
+            aria-label=label
+            aria-disabled=move || disabled.get().then_some("true")
+            attr:data-testid=data_testid
+            attr:data-cy=data_cy
+            on:keydown=on_keydown
+        >
+            {radios}
+        </div>
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,6 +329,130 @@ mod tests {
         );
     }
 
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Plan {
+        Free,
+        Pro,
+        Team,
+    }
+
+    fn plans() -> Vec<SelectOption<Plan>> {
+        let mut options = SelectOption::from_pairs([(Plan::Free, "Free"), (Plan::Pro, "Pro")]);
+        options.push(SelectOption::new(Plan::Team, "Team").with_disabled(true));
+        options
+    }
+
+    #[test]
+    fn radio_group_checks_the_typed_value() {
+        let html = view! {
+            <RadioGroup
+                name="plan"
+                options=plans()
+                value=Plan::Pro
+                update=Callback::new(|_: Plan| {})
+                label="Plan"
+                test_attr="plans"
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"role="radiogroup""#) && html.contains(r#"aria-label="Plan""#),
+            "expected an ARIA radiogroup; got: {}",
+            html
+        );
+        assert_eq!(
+            html.matches(r#"name="plan""#).count(),
+            3,
+            "expected the group name on every radio; got: {}",
+            html
+        );
+        let pro = &html[html.find(r#"value="1""#).expect("Pro radio")..];
+        let pro = &pro[..pro.find('>').unwrap()];
+        assert!(
+            pro.contains("checked") && pro.contains(r#"tabindex="0""#),
+            "expected Pro checked and focusable; got: {}",
+            html
+        );
+        let team = &html[html.find(r#"value="2""#).expect("Team radio")..];
+        let team = &team[..team.find('>').unwrap()];
+        assert!(
+            team.contains("disabled") && team.contains(r#"tabindex="-1""#),
+            "expected Team disabled and out of the tab order; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"data-testid="plans""#),
+            "expected test id on group; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn radio_group_submits_form_value_strings_when_given() {
+        let html = view! {
+            <RadioGroup
+                name="plan"
+                options=plans()
+                value=Plan::Pro
+                update=Callback::new(|_: Plan| {})
+                form_value=|plan: Plan| format!("{:?}", plan).to_lowercase()
+            />
+        }
+        .to_html();
+
+        let pro = &html[html.find(r#"value="pro""#).expect("Pro radio")..];
+        let pro = &pro[..pro.find('>').unwrap()];
+        assert!(
+            pro.contains("checked"),
+            "expected Pro checked; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"value="free""#) && !html.contains(r#"value="0""#),
+            "expected string values instead of indices; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn radio_group_vertical_layout_and_group_disabled() {
+        let html = view! {
+            <RadioGroup
+                name="plan"
+                options=plans()
+                value=Plan::Free
+                update=Callback::new(|_: Plan| {})
+                layout=GroupLayout::Stacked
+                disabled=true
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"class="radios is-flex-direction-column"#),
+            "expected vertical layout; got: {}",
+            html
+        );
+        assert_eq!(
+            html.matches("disabled").count(),
+            4,
+            "expected every radio disabled plus aria-disabled; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn radio_key_target_wraps_and_skips_disabled() {
+        let enabled = [true, false, true];
+        assert_eq!(radio_key_target(Some(0), &enabled, "ArrowRight"), Some(2));
+        assert_eq!(radio_key_target(Some(2), &enabled, "ArrowDown"), Some(0));
+        assert_eq!(radio_key_target(Some(0), &enabled, "ArrowLeft"), Some(2));
+        assert_eq!(radio_key_target(None, &enabled, "ArrowDown"), Some(0));
+        assert_eq!(radio_key_target(Some(2), &enabled, "Home"), Some(0));
+        assert_eq!(radio_key_target(Some(0), &enabled, "Tab"), None);
+    }
+
     #[test]
     fn radio_respects_disabled() {
         let html =
@@ -0,0 +1,1027 @@
+/*!
+Forms rendered from a JSON Schema, for forms defined at runtime (e.g. by backend configuration).
+
+`SchemaForm` takes a schema describing an object and renders one control per property:
+
+- `string` → `Input`; `format` picks the input type (`email`, `uri`, `date`, `time`, `date-time`,
+  `password`)
+- `integer` and `number` → a number `Input`
+- `enum` (of any type) → `Select`
+- `boolean` → `Checkbox`
+- `array` → a repeatable group of its `items`, with add and remove buttons
+- `object` → a `Field horizontal` section holding its properties
+
+```ignore
+let schema = serde_json::json!({
+    "type": "object",
+    "required": ["name"],
+    "properties": {
+        "name": { "type": "string", "title": "Full name", "minLength": 2 },
+        "role": { "enum": ["admin", "editor", "viewer"], "default": "viewer" },
+        "tags": { "type": "array", "items": { "type": "string" }, "maxItems": 5 },
+    },
+});
+view! { <SchemaForm schema=schema on_submit=Callback::new(move |value: Value| save(value)) /> }
+```
+
+`title` and `description` become labels and help texts, `default` fills in values missing from
+`initial`. These constraints are checked like `Field` validators: `required`, `minLength`,
+`maxLength`, `pattern`, `format: "email"`, `minimum`, `maximum`, `exclusiveMinimum`,
+`exclusiveMaximum`, `multipleOf`, `minItems` and `maxItems`. Other keywords (`$ref`, `oneOf`,
+...) and properties of other types are ignored.
+
+Fields are named by the JSON pointer of their value (`/address/city`, `/tags/0`), the paths schema
+validators report errors at, so server-side errors can be shown with `FormContext::set_error`.
+An array's own field holds its number of items. Empty optional values are left out of the emitted
+value.
+*/
+
+use leptos::callback::Callback;
+use leptos::prelude::{
+    AnyView, AriaAttributes, Callable, ClassAttribute, CollectView, CustomAttribute, Effect,
+    ElementChild, Get, IntoAny, IntoView, OnAttribute, PropAttribute, Signal, StoredValue,
+    WithValue, component, untrack, view,
+};
+use regex::Regex;
+use serde_json::{Map, Number, Value};
+
+use crate::form::checkbox::Checkbox;
+use crate::form::context::{Form, FormContext, FormValues};
+use crate::form::control::Control;
+use crate::form::field::Field;
+use crate::form::input::{Input, InputType};
+use crate::form::model::FieldErrors;
+use crate::form::select::{Select, SelectOption};
+use crate::form::validation::{
+    ValidatorRef, custom, email, max_length, min_length, pattern, range, required,
+};
+use crate::util::TestAttr;
+
+/// The schema of array items without `items`.
+static ANY: Value = Value::Null;
+
+/// The type of value a schema describes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Object,
+    Array,
+    String,
+    Integer,
+    Number,
+    Boolean,
+    Unsupported,
+}
+
+fn kind(schema: &Value) -> Kind {
+    let declared = match schema.get("type") {
+        Some(Value::String(name)) => Some(name.as_str()),
+        // `["string", "null"]`: nullable values are edited like the other type.
+        Some(Value::Array(names)) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|name| *name != "null"),
+        _ => None,
+    };
+    match declared {
+        Some("object") => Kind::Object,
+        Some("array") => Kind::Array,
+        Some("string") => Kind::String,
+        Some("integer") => Kind::Integer,
+        Some("number") => Kind::Number,
+        Some("boolean") => Kind::Boolean,
+        Some(_) => Kind::Unsupported,
+        None if schema.get("properties").is_some() => Kind::Object,
+        None if schema.get("items").is_some() => Kind::Array,
+        None if schema.get("enum").is_some() => Kind::String,
+        None => Kind::Unsupported,
+    }
+}
+
+fn properties(schema: &Value) -> impl Iterator<Item = (&String, &Value)> {
+    schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+}
+
+fn is_required(schema: &Value, key: &str) -> bool {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .is_some_and(|names| names.iter().any(|name| name.as_str() == Some(key)))
+}
+
+fn items(schema: &Value) -> &Value {
+    schema.get("items").unwrap_or(&ANY)
+}
+
+fn text(schema: &Value, keyword: &str) -> Option<String> {
+    schema
+        .get(keyword)
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+fn number(schema: &Value, keyword: &str) -> Option<f64> {
+    schema.get(keyword).and_then(Value::as_f64)
+}
+
+fn count(schema: &Value, keyword: &str) -> Option<usize> {
+    schema
+        .get(keyword)
+        .and_then(Value::as_u64)
+        .map(|count| count as usize)
+}
+
+/// "first_name" -> "First name".
+fn humanize(key: &str) -> String {
+    let words = key.replace(['_', '-'], " ");
+    let mut chars = words.trim().chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Appends an object key or array index to a JSON pointer.
+fn pointer(parent: &str, key: &str) -> String {
+    format!("{}/{}", parent, key.replace('~', "~0").replace('/', "~1"))
+}
+
+/// The form value of a JSON scalar.
+fn form_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn item_count(raw: &str) -> usize {
+    raw.parse().unwrap_or(0)
+}
+
+/// The form values for `data`, or for the schema's defaults where `data` has no value.
+pub fn schema_form_values(schema: &Value, data: Option<&Value>) -> FormValues {
+    let mut values = FormValues::new();
+    collect_form_values(schema, "", data, &mut values);
+    values
+}
+
+fn collect_form_values(schema: &Value, path: &str, data: Option<&Value>, values: &mut FormValues) {
+    let data = data
+        .filter(|data| !data.is_null())
+        .or_else(|| schema.get("default"));
+    match kind(schema) {
+        Kind::Object => {
+            for (key, property) in properties(schema) {
+                let data = data.and_then(|data| data.get(key));
+                collect_form_values(property, &pointer(path, key), data, values);
+            }
+        }
+        Kind::Array => {
+            let rows = data
+                .and_then(Value::as_array)
+                .map_or(&[][..], Vec::as_slice);
+            let rows_count = rows
+                .len()
+                .max(count(schema, "minItems").unwrap_or_default());
+            values.insert(path.to_string(), rows_count.to_string());
+            for index in 0..rows_count {
+                let item_path = pointer(path, &index.to_string());
+                collect_form_values(items(schema), &item_path, rows.get(index), values);
+            }
+        }
+        Kind::Boolean => {
+            let checked = data.and_then(Value::as_bool).unwrap_or_default();
+            values.insert(path.to_string(), checked.to_string());
+        }
+        Kind::String | Kind::Integer | Kind::Number => {
+            values.insert(path.to_string(), data.map(form_text).unwrap_or_default());
+        }
+        Kind::Unsupported => {}
+    }
+}
+
+/// The JSON value edited in a form with `values`.
+///
+/// Returns the messages of the values that do not convert to the schema's types.
+pub fn schema_form_value(schema: &Value, values: &FormValues) -> Result<Value, FieldErrors> {
+    let mut errors = FieldErrors::new();
+    let value = collect_value(schema, "", values, &mut errors).unwrap_or(Value::Null);
+    if errors.is_empty() {
+        Ok(value)
+    } else {
+        Err(errors)
+    }
+}
+
+fn collect_value(
+    schema: &Value,
+    path: &str,
+    values: &FormValues,
+    errors: &mut FieldErrors,
+) -> Option<Value> {
+    let raw = values.get(path).map_or("", String::as_str);
+    let kind = kind(schema);
+    if let Some(options) = schema.get("enum").and_then(Value::as_array)
+        && !matches!(kind, Kind::Object | Kind::Array)
+    {
+        if raw.is_empty() {
+            return None;
+        }
+        let found = options.iter().find(|option| form_text(option) == raw);
+        if found.is_none() {
+            errors.insert(path.to_string(), "Please select an option.".to_string());
+        }
+        return found.cloned();
+    }
+    match kind {
+        Kind::Object => {
+            let mut object = Map::new();
+            for (key, property) in properties(schema) {
+                if let Some(value) = collect_value(property, &pointer(path, key), values, errors) {
+                    object.insert(key.clone(), value);
+                }
+            }
+            Some(Value::Object(object))
+        }
+        Kind::Array => Some(Value::Array(
+            (0..item_count(raw))
+                .filter_map(|index| {
+                    let item_path = pointer(path, &index.to_string());
+                    collect_value(items(schema), &item_path, values, errors)
+                })
+                .collect(),
+        )),
+        Kind::Boolean => Some(Value::Bool(raw == "true")),
+        Kind::String => (!raw.is_empty()).then(|| Value::String(raw.to_string())),
+        Kind::Integer | Kind::Number if raw.trim().is_empty() => None,
+        Kind::Integer => match raw.trim().parse::<i64>() {
+            Ok(integer) => Some(Value::from(integer)),
+            Err(_) => {
+                errors.insert(path.to_string(), WHOLE_NUMBER.to_string());
+                None
+            }
+        },
+        Kind::Number => match raw.trim().parse().ok().and_then(Number::from_f64) {
+            Some(number) => Some(Value::Number(number)),
+            None => {
+                errors.insert(path.to_string(), NUMBER.to_string());
+                None
+            }
+        },
+        Kind::Unsupported => None,
+    }
+}
+
+const WHOLE_NUMBER: &str = "Please enter a whole number.";
+const NUMBER: &str = "Please enter a number.";
+
+/// A numeric check that accepts empty values and leaves unparseable ones to the type check.
+fn numeric(check: impl Fn(f64) -> Result<(), String> + Send + Sync + 'static) -> ValidatorRef {
+    custom(move |value: &str| match value.trim().parse::<f64>() {
+        Ok(number) => check(number),
+        Err(_) => Ok(()),
+    })
+}
+
+/// The validators for the constraints of `schema`.
+fn validators(schema: &Value, required_value: bool) -> Vec<ValidatorRef> {
+    let mut validators = Vec::new();
+    let kind = kind(schema);
+    if required_value && !matches!(kind, Kind::Boolean | Kind::Array | Kind::Object) {
+        validators.push(required());
+    }
+    match kind {
+        Kind::String => {
+            if let Some(min) = count(schema, "minLength") {
+                validators.push(min_length(min));
+            }
+            if let Some(max) = count(schema, "maxLength") {
+                validators.push(max_length(max));
+            }
+            // The schema's regex dialect is ECMA 262; patterns `regex` cannot compile are skipped.
+            if let Some(regex) = schema
+                .get("pattern")
+                .and_then(Value::as_str)
+                .and_then(|source| Regex::new(source).ok())
+            {
+                validators.push(pattern(regex));
+            }
+            if text(schema, "format").as_deref() == Some("email") {
+                validators.push(email());
+            }
+        }
+        Kind::Integer | Kind::Number => {
+            let integer = kind == Kind::Integer;
+            validators.push(custom(move |value: &str| {
+                let value = value.trim();
+                let parses = if integer {
+                    value.parse::<i64>().is_ok()
+                } else {
+                    value.parse::<f64>().is_ok_and(f64::is_finite)
+                };
+                if value.is_empty() || parses {
+                    Ok(())
+                } else if integer {
+                    Err(WHOLE_NUMBER.to_string())
+                } else {
+                    Err(NUMBER.to_string())
+                }
+            }));
+            let (minimum, maximum) = (number(schema, "minimum"), number(schema, "maximum"));
+            if minimum.is_some() || maximum.is_some() {
+                validators.push(range(minimum, maximum));
+            }
+            if let Some(bound) = number(schema, "exclusiveMinimum") {
+                validators.push(numeric(move |number| {
+                    if number > bound {
+                        Ok(())
+                    } else {
+                        Err(format!("Must be greater than {}.", bound))
+                    }
+                }));
+            }
+            if let Some(bound) = number(schema, "exclusiveMaximum") {
+                validators.push(numeric(move |number| {
+                    if number < bound {
+                        Ok(())
+                    } else {
+                        Err(format!("Must be less than {}.", bound))
+                    }
+                }));
+            }
+            if let Some(step) = number(schema, "multipleOf").filter(|step| *step > 0.0) {
+                validators.push(numeric(move |number| {
+                    let ratio = number / step;
+                    if (ratio - ratio.round()).abs() < 1e-9 {
+                        Ok(())
+                    } else {
+                        Err(format!("Must be a multiple of {}.", step))
+                    }
+                }));
+            }
+        }
+        Kind::Array => {
+            let (min, max) = (count(schema, "minItems"), count(schema, "maxItems"));
+            if min.is_some() || max.is_some() {
+                validators.push(custom(move |value: &str| {
+                    let rows = item_count(value);
+                    match (min, max) {
+                        (Some(min), _) if rows < min => Err(format!("Add at least {}.", min)),
+                        (_, Some(max)) if rows > max => Err(format!("Add at most {}.", max)),
+                        _ => Ok(()),
+                    }
+                }));
+            }
+        }
+        Kind::Object | Kind::Boolean | Kind::Unsupported => {}
+    }
+    validators
+}
+
+/// The names of the values of rows `index..` of `array`, and their values once row `index` is
+/// removed.
+fn shift_rows(values: &FormValues, array: &str, index: usize) -> (Vec<String>, FormValues) {
+    let prefix = format!("{}/", array);
+    let mut names = Vec::new();
+    let mut shifted = FormValues::new();
+    for (name, value) in values {
+        let Some(rest) = name.strip_prefix(&prefix) else {
+            continue;
+        };
+        let (row, tail) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        let Ok(row) = row.parse::<usize>() else {
+            continue;
+        };
+        if row < index {
+            continue;
+        }
+        names.push(name.clone());
+        if row > index {
+            shifted.insert(format!("{}{}{}", prefix, row - 1, tail), value.clone());
+        }
+    }
+    (names, shifted)
+}
+
+fn add_row(form: FormContext, array: &str, item_schema: &Value) {
+    let rows = item_count(&untrack(|| form.value(array)));
+    let mut values = FormValues::new();
+    collect_form_values(
+        item_schema,
+        &pointer(array, &rows.to_string()),
+        None,
+        &mut values,
+    );
+    for (name, value) in values {
+        form.register(name, value);
+    }
+    form.set_value(array, (rows + 1).to_string());
+}
+
+fn remove_row(form: FormContext, array: &str, index: usize) {
+    let values = untrack(|| form.values());
+    let rows = item_count(values.get(array).map_or("", String::as_str));
+    let (names, shifted) = shift_rows(&values, array, index);
+    for name in &names {
+        if shifted.contains_key(name) {
+            form.set_error(name.clone(), None);
+        } else {
+            form.unregister(name);
+        }
+    }
+    for (name, value) in shifted {
+        form.set_value(name, value);
+    }
+    form.set_value(array, rows.saturating_sub(1).to_string());
+}
+
+/// A `Field` around `control`, with the parts the schema provides.
+fn field_view(
+    name: String,
+    label: Option<String>,
+    help: Option<String>,
+    validators: Vec<ValidatorRef>,
+    control: AnyView,
+) -> AnyView {
+    match (label, help) {
+        (Some(label), Some(help)) => view! {
+            <Field name=name label=label help=help validators=validators>{control}</Field>
+        }
+        .into_any(),
+        (Some(label), None) => view! {
+            <Field name=name label=label validators=validators>{control}</Field>
+        }
+        .into_any(),
+        (None, Some(help)) => view! {
+            <Field name=name help=help validators=validators>{control}</Field>
+        }
+        .into_any(),
+        (None, None) => view! {
+            <Field name=name validators=validators>{control}</Field>
+        }
+        .into_any(),
+    }
+}
+
+fn input_type(schema: &Value) -> InputType {
+    match text(schema, "format").as_deref() {
+        Some("email") => InputType::Email,
+        Some("uri" | "url") => InputType::Url,
+        Some("date") => InputType::Date,
+        Some("time") => InputType::Time,
+        Some("date-time") => InputType::DateTimeLocal,
+        Some("password") => InputType::Password,
+        _ => InputType::Text,
+    }
+}
+
+fn render_properties(form: FormContext, schema: &Value, path: &str) -> Vec<AnyView> {
+    properties(schema)
+        .map(|(key, property)| {
+            let label = text(property, "title").unwrap_or_else(|| humanize(key));
+            render_value(
+                form,
+                property,
+                pointer(path, key),
+                Some(label),
+                is_required(schema, key),
+            )
+        })
+        .collect()
+}
+
+fn render_value(
+    form: FormContext,
+    schema: &Value,
+    path: String,
+    label: Option<String>,
+    required_value: bool,
+) -> AnyView {
+    let help = text(schema, "description");
+    let validators = validators(schema, required_value);
+    let kind = kind(schema);
+
+    if let Some(options) = schema.get("enum").and_then(Value::as_array)
+        && !matches!(kind, Kind::Object | Kind::Array)
+    {
+        let options = options
+            .iter()
+            .map(|option| SelectOption::new(form_text(option), form_text(option)))
+            .collect::<Vec<_>>();
+        let (value, update) = form.bind(path.clone());
+        let name = path.clone();
+        let control = view! {
+            <Control>
+                <Select
+                    name=name
+                    value=value
+                    update=update
+                    options=options
+                    placeholder="Select an option"
+                />
+            </Control>
+        }
+        .into_any();
+        return field_view(path, label, help, validators, control);
+    }
+
+    match kind {
+        Kind::Object => {
+            let fields = render_properties(form, schema, &path);
+            let label = label.unwrap_or_default();
+            match help {
+                Some(help) => {
+                    view! { <Field label=label help=help horizontal=true>{fields}</Field> }
+                        .into_any()
+                }
+                None => view! { <Field label=label horizontal=true>{fields}</Field> }.into_any(),
+            }
+        }
+        Kind::Array => render_array(form, schema, path, label, help, validators),
+        Kind::Boolean => {
+            let (checked, update) = form.bind_bool(path.clone());
+            let text = label.unwrap_or_default();
+            let name = path.clone();
+            let control = view! {
+                <Control>
+                    <Checkbox name=name checked=checked update=update>{text}</Checkbox>
+                </Control>
+            }
+            .into_any();
+            field_view(path, None, help, validators, control)
+        }
+        Kind::Integer | Kind::Number => {
+            let (value, update) = form.bind(path.clone());
+            let min = number(schema, "minimum").map(|min| min.to_string());
+            let max = number(schema, "maximum").map(|max| max.to_string());
+            let step = number(schema, "multipleOf").unwrap_or(1.0);
+            let name = path.clone();
+            let control = view! {
+                <Control>
+                    <Input
+                        name=name
+                        value=value
+                        update=update
+                        r#type=InputType::Number
+                        step=step
+                        min=min.unwrap_or_default()
+                        max=max.unwrap_or_default()
+                    />
+                </Control>
+            }
+            .into_any();
+            field_view(path, label, help, validators, control)
+        }
+        Kind::String => {
+            let (value, update) = form.bind(path.clone());
+            let (name, input_type) = (path.clone(), input_type(schema));
+            let control = view! {
+                <Control>
+                    <Input name=name value=value update=update r#type=input_type />
+                </Control>
+            }
+            .into_any();
+            field_view(path, label, help, validators, control)
+        }
+        Kind::Unsupported => ().into_any(),
+    }
+}
+
+fn render_array(
+    form: FormContext,
+    schema: &Value,
+    path: String,
+    label: Option<String>,
+    help: Option<String>,
+    validators: Vec<ValidatorRef>,
+) -> AnyView {
+    let item_label = text(items(schema), "title").unwrap_or_else(|| "Item".to_string());
+    let min_items = count(schema, "minItems").unwrap_or_default();
+    let max_items = count(schema, "maxItems");
+    let item_schema = StoredValue::new(items(schema).clone());
+    let rows = {
+        let path = path.clone();
+        Signal::derive(move || item_count(&form.value(&path)))
+    };
+
+    let row_views = {
+        let path = path.clone();
+        let item_label = item_label.clone();
+        move || {
+            (0..rows.get())
+                .map(|index| {
+                    let title = format!("{} {}", item_label, index + 1);
+                    let row_path = pointer(&path, &index.to_string());
+                    let content = item_schema.with_value(|item_schema| match kind(item_schema) {
+                        Kind::Object => render_properties(form, item_schema, &row_path).into_any(),
+                        _ => render_value(form, item_schema, row_path, None, true),
+                    });
+                    let array = path.clone();
+                    view! {
+                        <div class="box schema-form-item">
+                            <div class="is-flex is-justify-content-space-between is-align-items-center mb-3">
+                                <strong>{title.clone()}</strong>
+                                <button
+                                    type="button"
+                                    class="delete"
+                                    aria-label=format!("Remove {}", title)
+                                    prop:disabled=move || rows.get() <= min_items
+                                    on:click=move |_| remove_row(form, &array, index)
+                                ></button>
+                            </div>
+                            {content}
+                        </div>
+                    }
+                })
+                .collect_view()
+        }
+    };
+
+    let add = {
+        let path = path.clone();
+        move |_| item_schema.with_value(|item_schema| add_row(form, &path, item_schema))
+    };
+    let control = view! {
+        <div class="schema-form-array">
+            {row_views}
+            <button
+                type="button"
+                class="button is-small"
+                prop:disabled=move || max_items.is_some_and(|max| rows.get() >= max)
+                on:click=add
+            >
+                {format!("Add {}", item_label.to_lowercase())}
+            </button>
+        </div>
+    }
+    .into_any();
+    field_view(path, label, help, validators, control)
+}
+
+/// A form rendered from a JSON Schema; emits the edited value as JSON.
+///
+/// The schema's root must describe an object. The form's state is built once from `schema`; to
+/// render another schema, render another `SchemaForm` (e.g. inside a `move ||` block).
+#[component]
+pub fn SchemaForm(
+    /// The JSON Schema of the edited value.
+    #[prop(into)]
+    schema: Value,
+
+    /// The value the form starts with; the schema's `default`s fill in what it lacks.
+    #[prop(optional)]
+    initial: Option<Value>,
+
+    /// Called with the edited value when the form is submitted without errors.
+    on_submit: Callback<Value>,
+
+    /// Called with the edited value after each change that leaves every value convertible.
+    #[prop(optional)]
+    on_change: Option<Callback<Value>>,
+
+    /// Text of the submit button.
+    #[prop(default = "Submit".to_string().into(), into)]
+    submit_label: Signal<String>,
+
+    /// The state container; a new one is created when omitted.
+    #[prop(optional)]
+    form: Option<FormContext>,
+
+    /// Extra classes for the wrapping <div>.
+    #[prop(optional, into)]
+    classes: Signal<String>,
+
+    /// Optional test attribute (renders as data-* attribute) on the wrapping <div>.
+    ///
+    /// When provided as a &str or String, this becomes `data-testid="value"`.
+    /// You can also pass a full `TestAttr` to override the attribute key.
+    #[prop(optional, into)]
+    test_attr: Option<TestAttr>,
+) -> impl IntoView {
+    let form = form.unwrap_or_default();
+    for (name, value) in schema_form_values(&schema, initial.as_ref()) {
+        form.register(name, value);
+    }
+    let schema = StoredValue::new(schema);
+
+    if let Some(on_change) = on_change {
+        Effect::new(move |previous: Option<()>| {
+            let values = form.values();
+            // The first run only subscribes; the initial value is not a change.
+            if previous.is_some()
+                && let Ok(value) = schema.with_value(|schema| schema_form_value(schema, &values))
+            {
+                on_change.run(value);
+            }
+        });
+    }
+
+    let submit = Callback::new(move |values: FormValues| {
+        match schema.with_value(|schema| schema_form_value(schema, &values)) {
+            Ok(value) => on_submit.run(value),
+            Err(errors) => {
+                for (name, error) in errors {
+                    form.set_error(name, Some(error));
+                }
+            }
+        }
+    });
+
+    let class = move || {
+        let extra = classes.get();
+        if extra.trim().is_empty() {
+            "schema-form".to_string()
+        } else {
+            format!("schema-form {}", extra)
+        }
+    };
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    view! {
+        <div
+            class=class
+            attr:data-testid=move || data_testid.clone()
+            attr:data-cy=move || data_cy.clone()
+        >
+            <Form form=form on_submit=submit>
+                {schema.with_value(|schema| render_properties(form, schema, ""))}
+                <div class="field">
+                    <div class="control">
+                        <button type="submit" class="button is-primary">
+                            {submit_label}
+                        </button>
+                    </div>
+                </div>
+            </Form>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::form::context::use_form;
+    use crate::form::validation::{ValidateOn, run_validators};
+    use leptos::prelude::{Owner, RenderHtml};
+    use serde_json::json;
+
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "required": ["name", "role"],
+            "properties": {
+                "name": { "type": "string", "title": "Full name", "minLength": 2 },
+                "email": { "type": "string", "format": "email", "description": "Work address" },
+                "role": { "enum": ["admin", "viewer"], "default": "viewer" },
+                "age": { "type": "integer", "minimum": 0, "exclusiveMaximum": 150 },
+                "score": { "type": "number", "multipleOf": 0.5 },
+                "newsletter": { "type": "boolean", "default": true },
+                "address": {
+                    "type": "object",
+                    "required": ["city"],
+                    "properties": {
+                        "city": { "type": "string" },
+                        "zip_code": { "type": "string", "pattern": "^[0-9]{5}$" },
+                    },
+                },
+                "tags": {
+                    "type": "array",
+                    "items": { "type": "string", "title": "Tag" },
+                    "maxItems": 2,
+                },
+                "contacts": {
+                    "type": "array",
+                    "minItems": 1,
+                    "items": {
+                        "type": "object",
+                        "properties": { "phone": { "type": "string" } },
+                    },
+                },
+            },
+        })
+    }
+
+    #[test]
+    fn json_round_trips_through_form_values() {
+        let data = json!({
+            "name": "Ada",
+            "age": 36,
+            "address": { "city": "London" },
+            "tags": ["math", "engines"],
+        });
+        let values = schema_form_values(&schema(), Some(&data));
+        assert_eq!(values.get("/name"), Some(&"Ada".to_string()));
+        assert_eq!(values.get("/role"), Some(&"viewer".to_string()), "default");
+        assert_eq!(values.get("/newsletter"), Some(&"true".to_string()));
+        assert_eq!(values.get("/address/city"), Some(&"London".to_string()));
+        assert_eq!(values.get("/tags"), Some(&"2".to_string()), "item count");
+        assert_eq!(values.get("/tags/1"), Some(&"engines".to_string()));
+        assert_eq!(values.get("/contacts"), Some(&"1".to_string()), "minItems");
+
+        assert_eq!(
+            schema_form_value(&schema(), &values),
+            Ok(json!({
+                "name": "Ada",
+                "role": "viewer",
+                "age": 36,
+                "newsletter": true,
+                "address": { "city": "London" },
+                "tags": ["math", "engines"],
+                "contacts": [{}],
+            }))
+        );
+
+        let mut edited = values.clone();
+        edited.insert("/score".to_string(), "2.5".to_string());
+        edited.insert("/age".to_string(), "old".to_string());
+        let errors = schema_form_value(&schema(), &edited).expect_err("age does not parse");
+        assert_eq!(
+            errors.get("/age").map(String::as_str),
+            Some("Please enter a whole number.")
+        );
+        edited.insert("/age".to_string(), String::new());
+        let value = schema_form_value(&schema(), &edited).expect("valid");
+        assert_eq!(value["score"], json!(2.5));
+        assert!(
+            value.get("age").is_none(),
+            "empty optional values are left out"
+        );
+        assert_eq!(pointer("/a", "b/c~d"), "/a/b~1c~0d");
+    }
+
+    #[test]
+    fn schema_constraints_become_validators() {
+        let check = |schema: Value, value: &str| run_validators(&validators(&schema, false), value);
+        let age = json!({ "type": "integer", "minimum": 0, "exclusiveMaximum": 150 });
+        assert_eq!(check(age.clone(), "1.5"), Err(WHOLE_NUMBER.to_string()));
+        assert_eq!(
+            check(age.clone(), "-1"),
+            Err("Must be at least 0.".to_string())
+        );
+        assert_eq!(
+            check(age.clone(), "150"),
+            Err("Must be less than 150.".to_string())
+        );
+        assert_eq!(check(age, ""), Ok(()));
+
+        let score = json!({ "type": "number", "multipleOf": 0.5 });
+        assert_eq!(check(score.clone(), "2.5"), Ok(()));
+        assert_eq!(
+            check(score, "2.2"),
+            Err("Must be a multiple of 0.5.".to_string())
+        );
+
+        let zip = json!({ "type": "string", "pattern": "^[0-9]{5}$", "maxLength": 5 });
+        assert!(check(zip.clone(), "1234").is_err());
+        assert_eq!(check(zip, "12345"), Ok(()));
+        assert!(check(json!({ "type": "string", "format": "email" }), "nope").is_err());
+
+        let tags = json!({ "type": "array", "minItems": 1, "maxItems": 2 });
+        assert_eq!(check(tags.clone(), "0"), Err("Add at least 1.".to_string()));
+        assert_eq!(check(tags, "3"), Err("Add at most 2.".to_string()));
+
+        let name = json!({ "type": "string" });
+        assert!(run_validators(&validators(&name, true), "").is_err());
+    }
+
+    #[test]
+    fn removing_a_row_shifts_the_rows_after_it() {
+        let values = FormValues::from([
+            ("/tags".to_string(), "3".to_string()),
+            ("/tags/0".to_string(), "a".to_string()),
+            ("/tags/1".to_string(), "b".to_string()),
+            ("/tags/2".to_string(), "c".to_string()),
+            ("/tagsets".to_string(), "x".to_string()),
+        ]);
+        let (names, shifted) = shift_rows(&values, "/tags", 1);
+        assert_eq!(names, ["/tags/1", "/tags/2"]);
+        assert_eq!(
+            shifted,
+            FormValues::from([("/tags/1".to_string(), "c".to_string())])
+        );
+
+        let owner = Owner::new();
+        owner.with(|| {
+            let form = use_form();
+            for (name, value) in values {
+                form.register(name, value);
+            }
+            form.set_validators("/tags/2", ValidateOn::Blur, vec![required()]);
+            remove_row(form, "/tags", 1);
+            assert_eq!(form.value("/tags"), "2");
+            assert_eq!(form.value("/tags/1"), "c");
+            assert!(!form.is_registered("/tags/2"));
+
+            add_row(
+                form,
+                "/tags",
+                &json!({ "type": "string", "default": "new" }),
+            );
+            assert_eq!(form.value("/tags"), "3");
+            assert_eq!(form.value("/tags/2"), "new");
+        });
+    }
+
+    #[test]
+    fn number_step_renders_multiple_of_exactly() {
+        let schema = json!({
+            "type": "object",
+            "properties": { "rate": { "type": "number", "multipleOf": 0.123456789 } },
+        });
+        let html =
+            view! { <SchemaForm schema=schema on_submit=Callback::new(|_value: Value| {}) /> }
+                .to_html();
+        assert!(
+            html.contains(r#"step="0.123456789""#),
+            "expected an unrounded step: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn schema_form_renders_controls_and_validates_on_submit() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let form = use_form();
+            let html = view! {
+                <SchemaForm
+                    schema=schema()
+                    initial=json!({ "tags": ["math", "engines"] })
+                    on_submit=Callback::new(|_value: Value| {})
+                    submit_label="Save"
+                    form=form
+                    test_attr="profile"
+                />
+            }
+            .to_html();
+
+            assert!(
+                html.contains(r#"data-testid="profile" class="schema-form""#),
+                "expected the wrapper: {}",
+                html
+            );
+            assert!(
+                html.contains(">Full name</label>") && html.contains(">Zip code</label>"),
+                "expected titles and humanized labels: {}",
+                html
+            );
+            assert!(
+                html.contains(r#"type="email""#) && html.contains("Work address"),
+                "expected an email input with help: {}",
+                html
+            );
+            assert!(
+                html.contains(">admin</option>") && html.contains(">viewer</option>"),
+                "expected a select for the enum: {}",
+                html
+            );
+            assert!(
+                html.contains(r#"type="checkbox""#) && html.contains("Newsletter"),
+                "expected a checkbox: {}",
+                html
+            );
+            assert!(
+                html.contains(r#"<div class="field-label"><label class="label">Address</label></div><div class="field-body">"#),
+                "expected a horizontal section for the nested object: {}",
+                html
+            );
+            assert!(
+                html.contains("<strong>Tag 2</strong>")
+                    && html.contains(r#"aria-label="Remove Tag 1""#)
+                    && html.contains(">Add tag</button>"),
+                "expected repeatable tag rows: {}",
+                html
+            );
+            assert!(
+                html.contains(r#"<button type="submit" class="button is-primary">Save</button>"#),
+                "expected the submit button: {}",
+                html
+            );
+
+            assert!(form.submit().is_none(), "name and city are required");
+            assert!(form.error("/name").is_some());
+            assert!(form.error("/address/city").is_some());
+            assert_eq!(form.error("/role"), None, "has a default");
+
+            form.set_value("/name", "Ada");
+            form.set_value("/address/city", "London");
+            form.set_value("/address/zip_code", "1234");
+            assert!(form.submit().is_none());
+            assert!(form.error("/address/zip_code").is_some());
+
+            form.set_value("/address/zip_code", "12345");
+            let values = form.submit().expect("valid form");
+            let value = schema_form_value(&schema(), &values).expect("converts");
+            assert_eq!(value["address"], json!({ "city": "London", "zip_code": "12345" }));
+        });
+    }
+}
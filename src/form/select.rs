@@ -1,27 +1,12 @@
+use crate::form::combobox::Combobox;
+use crate::form::context::use_form_context;
 use crate::util::{Size, TestAttr};
 use leptos::callback::{Callable, Callback};
-use leptos::prelude::Effect;
 use leptos::prelude::event_target_value;
 use leptos::prelude::{
-    Children, ClassAttribute, CustomAttribute, ElementChild, Get, GetUntracked, IntoView, NodeRef,
-    NodeRefAttribute, OnAttribute, PropAttribute, Signal, component, view,
+    ClassAttribute, CollectView, CustomAttribute, ElementChild, Get, GetUntracked, IntoAny,
+    IntoView, OnAttribute, PropAttribute, Signal, With, component, view,
 };
-use leptos::wasm_bindgen::JsCast;
-use leptos::web_sys::{HtmlOptionElement, HtmlSelectElement};
-
-fn event_target_values(ev: &leptos::ev::Event) -> Vec<String> {
-    let target = ev.target().expect("event should have a target");
-    let select = target.unchecked_into::<HtmlSelectElement>();
-    let options = select.selected_options();
-    let len = options.length();
-    let mut values = Vec::with_capacity(len as usize);
-    for i in 0..len {
-        let option = options.item(i).expect("should have item");
-        let option = option.unchecked_into::<HtmlOptionElement>();
-        values.push(option.value());
-    }
-    values
-}
 
 fn size_class(size: Size) -> &'static str {
     match size {
@@ -32,28 +17,156 @@ fn size_class(size: Size) -> &'static str {
     }
 }
 
-/// A wrapper around an HTML select tag.
+/// One entry of a [`Select`]: the typed value, its label and optional optgroup.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectOption<T> {
+    pub value: T,
+    pub label: String,
+    /// Label of the `<optgroup>` this option belongs to. Consecutive options with the same
+    /// group are rendered inside one optgroup.
+    pub group: Option<String>,
+    pub disabled: bool,
+}
+
+impl<T> SelectOption<T> {
+    pub fn new(value: T, label: impl Into<String>) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            group: None,
+            disabled: false,
+        }
+    }
+
+    /// Places this option in the optgroup with the given label.
+    pub fn with_group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    /// Renders this option as disabled.
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Builds options from `(value, label)` pairs.
+    pub fn from_pairs<L: Into<String>>(pairs: impl IntoIterator<Item = (T, L)>) -> Vec<Self> {
+        pairs
+            .into_iter()
+            .map(|(value, label)| Self::new(value, label))
+            .collect()
+    }
+
+    /// Builds options in one optgroup from `(value, label)` pairs.
+    pub fn group<L: Into<String>>(
+        group: impl Into<String>,
+        pairs: impl IntoIterator<Item = (T, L)>,
+    ) -> Vec<Self> {
+        let group = group.into();
+        pairs
+            .into_iter()
+            .map(|(value, label)| Self::new(value, label).with_group(group.clone()))
+            .collect()
+    }
+}
+
+impl<T, L: Into<String>> From<(T, L)> for SelectOption<T> {
+    fn from((value, label): (T, L)) -> Self {
+        Self::new(value, label)
+    }
+}
+
+/// The DOM `value` of the option at `index`: `form_value` of its value when given, otherwise
+/// the index itself.
+pub(crate) fn option_dom_value<T: Clone + 'static>(
+    form_value: Option<Callback<T, String>>,
+    index: usize,
+    option: &SelectOption<T>,
+) -> String {
+    match form_value {
+        Some(form_value) => form_value.run(option.value.clone()),
+        None => index.to_string(),
+    }
+}
+
+/// Splits options into runs sharing the same optgroup, keeping each option's index.
+fn group_runs<T>(options: &[SelectOption<T>]) -> Vec<(Option<String>, Vec<usize>)> {
+    let mut runs: Vec<(Option<String>, Vec<usize>)> = Vec::new();
+    for (index, option) in options.iter().enumerate() {
+        match runs.last_mut() {
+            Some((group, indices)) if *group == option.group => indices.push(index),
+            _ => runs.push((option.group.clone(), vec![index])),
+        }
+    }
+    runs
+}
+
+fn wrapper_class(
+    base: &[&str],
+    extra: &str,
+    size: Option<Size>,
+    loading: bool,
+    validation: Option<&str>,
+) -> String {
+    let mut parts: Vec<&str> = base.to_vec();
+    if !extra.trim().is_empty() {
+        parts.push(extra);
+    }
+    if let Some(size) = size {
+        parts.push(size_class(size));
+    }
+    if loading {
+        parts.push("is-loading");
+    }
+    if let Some(color) = validation {
+        parts.push(color);
+    }
+    parts.join(" ")
+}
+
+/// A wrapper around an HTML select tag with a typed option list.
 ///
 /// https://bulma.io/documentation/form/select/
 ///
 /// All LBC form components are controlled components. The value comes from a parent,
-/// and changes are propagated via the `update` callback.
+/// and changes are propagated via the `update` callback, which receives the `T` of the
+/// chosen option.
+///
+/// **Native form posts:** by default options are rendered with their index as the DOM value,
+/// so `T` never has to round-trip through a string, but a plain HTML form submission (one not
+/// handled by `Form`) then sends `0`, `1`, `2`, ... instead of your values; only `update` and
+/// `FormContext` see the real `T`. Pass `form_value` (e.g. `|plan: Plan| plan.to_string()`)
+/// to render and submit a string form of each value instead.
+///
+/// Inside a `Form`, the wrapper gets `is-danger`/`is-success` from the validation state of
+/// the field with the same `name`.
 ///
 #[component]
-pub fn Select(
+pub fn Select<T>(
     /// The `name` attribute for this form element.
     #[prop(into)]
     name: Signal<String>,
 
     /// The controlled value of this form element.
     #[prop(into)]
-    value: Signal<String>,
+    value: Signal<T>,
 
     /// The callback to be used for propagating changes to this element's value.
-    update: Callback<String>,
+    update: Callback<T>,
 
-    /// The `option` and `optgroup` tags of this select component.
-    children: Children,
+    /// The options of this select; use `SelectOption::group` (or `with_group`) for optgroups.
+    #[prop(into)]
+    options: Signal<Vec<SelectOption<T>>>,
+
+    /// Maps an option value to its DOM `value`, which a native form post submits. Values must
+    /// map to distinct strings. Defaults to the option index.
+    #[prop(optional, into)]
+    form_value: Option<Callback<T, String>>,
+
+    /// Text of a leading, non-selectable entry shown while `value` matches no option.
+    #[prop(optional, into)]
+    placeholder: Option<Signal<String>>,
 
     /// Extra classes to apply to the Bulma "select" wrapper.
     #[prop(optional, into)]
@@ -77,26 +190,77 @@ pub fn Select(
     /// You can also pass a full `TestAttr` to override the attribute key (e.g., `data-cy`).
     #[prop(optional, into)]
     test_attr: Option<TestAttr>,
-) -> impl IntoView {
-    // Compute wrapper attributes once (safe mode).
-    let mut wrapper_classes = vec!["select".to_string()];
+) -> impl IntoView
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let form = use_form_context();
+    let class = move || {
+        let validation = form.and_then(|form| form.validation_class(&name.get()));
+        wrapper_class(&["select"], &classes.get(), size, loading.get(), validation)
+    };
 
-    let extra = classes.get_untracked();
-    if !extra.trim().is_empty() {
-        wrapper_classes.push(extra);
-    }
-    if let Some(sz) = size {
-        wrapper_classes.push(size_class(sz).to_string());
-    }
-    if loading.get_untracked() {
-        wrapper_classes.push("is-loading".to_string());
-    }
+    let selected_index = move || {
+        let current = value.get();
+        options.with(|options| options.iter().position(|option| option.value == current))
+    };
 
-    let wrapper_class = wrapper_classes.join(" ");
+    let on_change = move |ev| {
+        let text = event_target_value(&ev);
+        let picked = options.with(|options| {
+            options
+                .iter()
+                .enumerate()
+                .find(|(index, option)| option_dom_value(form_value, *index, option) == text)
+                .map(|(_, option)| option.value.clone())
+        });
+        if let Some(picked) = picked {
+            update.run(picked);
+        }
+    };
 
-    let name_value = name.get_untracked();
-    let _initial_value = value.get_untracked();
-    let is_disabled = disabled.get_untracked();
+    let render_options = move || {
+        let selected = selected_index();
+        let placeholder = placeholder.map(|text| {
+            view! {
+                <option value="" disabled=true selected=selected.is_none()>
+                    {text.get()}
+                </option>
+            }
+        });
+        let groups = options.with(|options| {
+            group_runs(options)
+                .into_iter()
+                .map(|(group, indices)| {
+                    let items = indices
+                        .into_iter()
+                        .map(|index| {
+                            let option = &options[index];
+                            view! {
+                                <option
+                                    value=option_dom_value(form_value, index, option)
+                                    disabled=option.disabled
+                                    selected=selected == Some(index)
+                                >
+                                    {option.label.clone()}
+                                </option>
+                            }
+                        })
+                        .collect_view();
+                    match group {
+                        Some(label) => {
+                            view! { <optgroup label=label>{items}</optgroup> }.into_any()
+                        }
+                        None => items.into_any(),
+                    }
+                })
+                .collect_view()
+        });
+        view! {
+            {placeholder}
+            {groups}
+        }
+    };
 
     let (data_testid, data_cy) = match &test_attr {
         Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
@@ -106,28 +270,42 @@ pub fn Select(
 
     view! {
         <div
-            class=wrapper_class
+            class=class
             attr:data-testid=data_testid
             attr:data-cy=data_cy
         >
             <select
-                name=name_value
-                disabled=is_disabled
-                on:change=move |v| update.run(event_target_value(&v))
-                prop:value=value
+                name=move || name.get()
+                disabled=move || disabled.get()
+                on:change=on_change
+                prop:value=move || {
+                    selected_index()
+                        .and_then(|index| {
+                            options.with(|options| {
+                                options.get(index).map(|option| option_dom_value(form_value, index, option))
+                            })
+                        })
+                        .unwrap_or_default()
+                }
             >
-                {children()}
+                {render_options}
             </select>
         </div>
     }
 }
 
-/// A wrapper around an HTML select tag with the `multiple=true` attribute.
+/// A chip-style multi-select.
 ///
-/// https://bulma.io/documentation/form/select/
+/// Chosen values are shown as removable `Tag`s inside a `Tags` group, and new values are
+/// picked from a filterable dropdown (see [`Combobox`] in multiple mode). Each chosen value is
+/// also submitted as a hidden `<input name=... value=...>`, like the options of a native
+/// `<select multiple>`.
 ///
 /// Controlled component: values come from a parent; updates are sent via `update`.
 ///
+/// Inside a `Form`, the input gets `is-danger`/`is-success` from the validation state of the
+/// field with the same `name`.
+///
 #[component]
 pub fn MultiSelect(
     /// The `name` attribute for this form element.
@@ -141,10 +319,19 @@ pub fn MultiSelect(
     /// The callback to be used for propagating changes to this form element's value.
     update: Callback<Vec<String>>,
 
-    /// The `option` and `optgroup` tags of this select component.
-    children: Children,
+    /// The options to choose from; use `SelectOption::group` (or `with_group`) for headings.
+    #[prop(into)]
+    options: Signal<Vec<SelectOption<String>>>,
 
-    /// Extra classes to apply to the Bulma "select" wrapper.
+    /// DOM id of the filter input. Defaults to `{name}-multi-select`.
+    #[prop(optional, into)]
+    id: Option<String>,
+
+    /// Placeholder to show in the filter input.
+    #[prop(optional, into)]
+    placeholder: Signal<String>,
+
+    /// Extra classes to apply to the filter input.
     #[prop(optional, into)]
     classes: Signal<String>,
 
@@ -152,7 +339,7 @@ pub fn MultiSelect(
     #[prop(optional)]
     size: Option<Size>,
 
-    /// Size of the list to display. Defaults to 4.
+    /// Number of options visible before the dropdown scrolls. Defaults to 8.
     #[prop(optional)]
     list_size: Option<u32>,
 
@@ -171,28 +358,21 @@ pub fn MultiSelect(
     #[prop(optional, into)]
     test_attr: Option<TestAttr>,
 ) -> impl IntoView {
-    // Compute wrapper attributes once (safe mode).
-    let mut wrapper_classes = vec!["select".to_string(), "is-multiple".to_string()];
-
-    let extra = classes.get_untracked();
-    if !extra.trim().is_empty() {
-        wrapper_classes.push(extra);
-    }
-    if let Some(sz) = size {
-        wrapper_classes.push(size_class(sz).to_string());
-    }
-    if loading.get_untracked() {
-        wrapper_classes.push("is-loading".to_string());
-    }
-
-    let wrapper_class = wrapper_classes.join(" ");
+    let form = use_form_context();
+    let id = id.unwrap_or_else(|| format!("{}-multi-select", name.get_untracked()));
 
-    let name_value = name.get_untracked();
-    let is_disabled = disabled.get_untracked();
-    let size_attr = list_size.unwrap_or(4).to_string();
+    let input_classes = Signal::derive(move || {
+        let validation = form.and_then(|form| form.validation_class(&name.get()));
+        wrapper_class(&[], &classes.get(), size, false, validation)
+    });
 
-    // Initial value snapshot (not reactive) to avoid tachys property binding.
-    let _initial_values = value.get_untracked();
+    let hidden_inputs = move || {
+        value
+            .get()
+            .into_iter()
+            .map(|v| view! { <input r#type="hidden" name=move || name.get() value=v /> })
+            .collect_view()
+    };
 
     let (data_testid, data_cy) = match &test_attr {
         Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
@@ -200,37 +380,282 @@ pub fn MultiSelect(
         _ => (None, None),
     };
 
-    let select_ref = NodeRef::<leptos::html::Select>::new();
-    Effect::new(move |_| {
-        if let Some(select) = select_ref.get() {
-            let values = value.get();
-            let options = select.get_elements_by_tag_name("option");
-            for i in 0..options.length() {
-                let option = options
-                    .item(i)
-                    .unwrap()
-                    .unchecked_into::<HtmlOptionElement>();
-                option.set_selected(values.contains(&option.value()));
-            }
-        }
-    });
-
     view! {
         <div
-            class=wrapper_class
+            class="multi-select"
             attr:data-testid=data_testid
             attr:data-cy=data_cy
         >
-            <select
-                node_ref=select_ref
+            <Combobox
+                id=id
+                options=options
+                value=value
+                update=update
                 multiple=true
-                size=size_attr
-                name=name_value
-                disabled=is_disabled
-                on:change=move |v| update.run(event_target_values(&v))
-            >
-                {children()}
-            </select>
+                value_label=|raw: String| raw
+                placeholder=placeholder
+                classes=input_classes
+                list_size=list_size.unwrap_or(8)
+                loading=loading
+                disabled=disabled
+            />
+            {hidden_inputs}
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::prelude::{RenderHtml, RwSignal, Set};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Color {
+        Red,
+        Green,
+        Blue,
+    }
+
+    fn colors() -> Vec<SelectOption<Color>> {
+        SelectOption::from_pairs([
+            (Color::Red, "Red"),
+            (Color::Green, "Green"),
+            (Color::Blue, "Blue"),
+        ])
+    }
+
+    #[test]
+    fn select_marks_the_current_typed_value() {
+        let html = view! {
+            <Select
+                name="color"
+                value=Color::Green
+                update=Callback::new(|_: Color| {})
+                options=colors()
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"<option value="1" selected>Green</option>"#),
+            "expected the Green option (index 1) selected; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"<option value="0">Red</option>"#),
+            "expected unselected Red; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn select_submits_form_value_strings_when_given() {
+        let html = view! {
+            <Select
+                name="color"
+                value=Color::Blue
+                update=Callback::new(|_: Color| {})
+                options=colors()
+                form_value=|color: Color| format!("{:?}", color).to_lowercase()
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"<option value="red">Red</option>"#)
+                && html.contains(r#"<option value="blue" selected>Blue</option>"#),
+            "expected string option values; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn select_renders_optgroups_for_consecutive_groups() {
+        let mut options = SelectOption::group("Warm", [(1u32, "Red"), (2, "Orange")]);
+        options.extend(SelectOption::group("Cold", [(3u32, "Blue")]));
+        options.push(SelectOption::new(4u32, "Other").with_disabled(true));
+
+        let html = view! {
+            <Select name="n" value=3u32 update=Callback::new(|_: u32| {}) options=options />
+        }
+        .to_html();
+
+        let warm = &html[html
+            .find(r#"<optgroup label="Warm">"#)
+            .expect("Warm optgroup")..];
+        let warm = &warm[..warm.find("</optgroup>").unwrap()];
+        assert!(
+            warm.contains(r#"<option value="0">Red</option>"#)
+                && warm.contains(r#"<option value="1">Orange</option>"#),
+            "expected Red and Orange in Warm; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"<optgroup label="Cold"><option value="2" selected>Blue</option>"#),
+            "expected Cold optgroup with selection; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"<option value="3" disabled>Other</option>"#),
+            "expected ungrouped disabled option; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn select_placeholder_selected_when_no_option_matches() {
+        let nothing: Option<Color> = None;
+        let html = view! {
+            <Select
+                name="maybe"
+                value=nothing
+                update=Callback::new(|_: Option<Color>| {})
+                options=SelectOption::from_pairs([(Some(Color::Red), "Red"), (Some(Color::Blue), "Blue")])
+                placeholder="Pick a color"
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"<option value="" disabled selected>Pick a color</option>"#),
+            "expected selected placeholder; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn select_wrapper_classes_follow_signals() {
+        let loading = RwSignal::new(false);
+        let disabled = RwSignal::new(false);
+        loading.set(true);
+        disabled.set(true);
+
+        let html = view! {
+            <Select
+                name="color"
+                value=Color::Red
+                update=Callback::new(|_: Color| {})
+                options=colors()
+                classes="is-fullwidth"
+                size=Size::Small
+                loading=loading
+                disabled=disabled
+                test_attr="color-select"
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"class="select is-fullwidth is-small is-loading""#),
+            "expected wrapper classes from signals; got: {}",
+            html
+        );
+        assert!(
+            html.contains("disabled"),
+            "expected disabled select; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"data-testid="color-select""#),
+            "expected test id on wrapper; got: {}",
+            html
+        );
+    }
+
+    fn letters() -> Vec<SelectOption<String>> {
+        SelectOption::from_pairs(
+            [("a", "Alpha"), ("b", "Bravo"), ("c", "Charlie")].map(|(v, l)| (v.to_string(), l)),
+        )
+    }
+
+    #[test]
+    fn multi_select_shows_chosen_values_as_removable_tags() {
+        let html = view! {
+            <MultiSelect
+                name="letters"
+                value=vec!["c".to_string(), "a".to_string()]
+                update=Callback::new(|_: Vec<String>| {})
+                options=letters()
+                test_attr="letters"
+            />
+        }
+        .to_html();
+
+        let tags = &html[html.find(r#"class="tags"#).expect("tags group")..];
+        let charlie = tags.find("Charlie").expect("Charlie tag");
+        let alpha = tags.find("Alpha").expect("Alpha tag");
+        assert!(
+            charlie < alpha,
+            "expected tags in value order; got: {}",
+            html
+        );
+        assert_eq!(
+            tags.matches(r#"class="delete is-small""#).count(),
+            2,
+            "expected a delete button per tag; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"id="letters-multi-select""#) && html.contains(r#"role="combobox""#),
+            "expected the filter combobox with a name-derived id; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"data-testid="letters""#),
+            "expected test id on wrapper; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn multi_select_submits_hidden_inputs_per_value() {
+        let html = view! {
+            <MultiSelect
+                name="letters"
+                value=vec!["b".to_string(), "c".to_string()]
+                update=Callback::new(|_: Vec<String>| {})
+                options=letters()
+                size=Size::Small
+                list_size=3
+            />
+        }
+        .to_html();
+
+        for value in ["b", "c"] {
+            assert!(
+                html.contains(&format!(
+                    r#"<input type="hidden" name="letters" value="{}""#,
+                    value
+                )),
+                "expected hidden input for {}; got: {}",
+                value,
+                html
+            );
+        }
+        assert!(
+            html.contains(r#"class="input is-small""#),
+            "expected sized input; got: {}",
+            html
+        );
+        assert!(
+            html.contains("max-height: 6rem"),
+            "expected list_size to bound the dropdown; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn group_runs_keep_indices() {
+        let mut options = SelectOption::group("A", [(1, "a"), (2, "b")]);
+        options.push(SelectOption::new(3, "c"));
+        options.extend(SelectOption::group("A", [(4, "d")]));
+        assert_eq!(
+            group_runs(&options),
+            vec![
+                (Some("A".to_string()), vec![0, 1]),
+                (None, vec![2]),
+                (Some("A".to_string()), vec![3]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,826 @@
+/*!
+Range sliders for `f64` values: `Slider` (one thumb) and `RangeSlider` (a min–max pair).
+
+Both render native `<input type="range">` elements styled with the `bulma-slider` extension
+classes (`slider`, colors, sizes, `is-circle`, `is-fullwidth`). Without the extension stylesheet
+they fall back to the browser's range input.
+
+- `output` shows the current value next to the slider (`<output>`) or as a tooltip above the thumb.
+- `ticks` adds tick marks every `ticks` units: a `<datalist>` for the native marks plus labels
+  positioned under the track.
+- Values are snapped to `step` and clamped to `min..=max` before `update` is called.
+
+`RangeSlider` overlays one input per thumb (labelled "Minimum" and "Maximum") on a single track,
+with a filled segment between the thumbs. Only the thumbs take pointer events, so either can be
+dragged along the shared track; moving one past the other stops it at the other's value.
+*/
+
+use leptos::callback::Callback;
+use leptos::prelude::{
+    AriaAttributes, Callable, ClassAttribute, CollectView, CustomAttribute, ElementChild, Get,
+    GetUntracked, GlobalAttributes, IntoAny, IntoView, OnAttribute, PropAttribute, Signal,
+    StyleAttribute, component, event_target_value, view,
+};
+
+use crate::util::{Size, TestAttr};
+
+/// Available color variants for a [`Slider`] or [`RangeSlider`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SliderColor {
+    Black,
+    Danger,
+    Dark,
+    Info,
+    Light,
+    Link,
+    Primary,
+    Success,
+    Warning,
+    White,
+}
+
+impl SliderColor {
+    /// Returns the Bulma CSS class for this `SliderColor`.
+    fn bulma(self) -> &'static str {
+        match self {
+            SliderColor::Black => "is-black",
+            SliderColor::Danger => "is-danger",
+            SliderColor::Dark => "is-dark",
+            SliderColor::Info => "is-info",
+            SliderColor::Light => "is-light",
+            SliderColor::Link => "is-link",
+            SliderColor::Primary => "is-primary",
+            SliderColor::Success => "is-success",
+            SliderColor::Warning => "is-warning",
+            SliderColor::White => "is-white",
+        }
+    }
+
+    /// Returns the Bulma background helper used for the filled segment of a [`RangeSlider`].
+    fn background(self) -> &'static str {
+        match self {
+            SliderColor::Black => "has-background-black",
+            SliderColor::Danger => "has-background-danger",
+            SliderColor::Dark => "has-background-dark",
+            SliderColor::Info => "has-background-info",
+            SliderColor::Light => "has-background-light",
+            SliderColor::Link => "has-background-link",
+            SliderColor::Primary => "has-background-primary",
+            SliderColor::Success => "has-background-success",
+            SliderColor::Warning => "has-background-warning",
+            SliderColor::White => "has-background-white",
+        }
+    }
+}
+
+/// Overlays the two inputs of a [`RangeSlider`] on one track.
+///
+/// The native tracks are hidden and ignore the pointer; only the thumbs receive events, so
+/// whichever thumb is under the pointer moves. The track line is drawn by the container and the
+/// selected segment by `.range-slider-fill`.
+const RANGE_SLIDER_STYLE: &str = r#"
+.slider-field.is-range .range-slider-track {
+    position: relative;
+    height: 2rem;
+}
+.slider-field.is-range .range-slider-track::before {
+    content: "";
+    position: absolute;
+    left: 0;
+    right: 0;
+    top: 50%;
+    height: 0.25rem;
+    transform: translateY(-50%);
+    border-radius: 0.25rem;
+    background: #dbdbdb;
+}
+.slider-field.is-range .slider-thumb {
+    position: absolute;
+    inset: 0;
+    pointer-events: none;
+}
+.slider-field.is-range .range-slider-fill {
+    position: absolute;
+    top: 50%;
+    height: 0.25rem;
+    transform: translateY(-50%);
+    border-radius: 0.25rem;
+    pointer-events: none;
+}
+.slider-field.is-range input[type="range"] {
+    position: absolute;
+    inset: 0;
+    width: 100%;
+    height: 100%;
+    margin: 0;
+    background: transparent;
+    pointer-events: none;
+    -webkit-appearance: none;
+    appearance: none;
+}
+.slider-field.is-range input[type="range"]::-webkit-slider-runnable-track {
+    background: transparent;
+    box-shadow: none;
+    border: 0;
+}
+.slider-field.is-range input[type="range"]::-moz-range-track {
+    background: transparent;
+    box-shadow: none;
+    border: 0;
+}
+.slider-field.is-range input[type="range"]::-webkit-slider-thumb {
+    pointer-events: auto;
+    cursor: grab;
+}
+.slider-field.is-range input[type="range"]::-moz-range-thumb {
+    pointer-events: auto;
+    cursor: grab;
+}
+"#;
+
+/// How a slider shows its current value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SliderOutput {
+    /// Do not show the value.
+    #[default]
+    Hidden,
+    /// An `<output>` box next to the slider.
+    Output,
+    /// A tag above the thumb.
+    Tooltip,
+}
+
+/// Decimal places needed to show multiples of `step` (e.g. `0.25` needs 2).
+fn step_decimals(step: f64) -> usize {
+    let text = step.abs().to_string();
+    text.split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
+
+fn round_to(value: f64, decimals: usize) -> f64 {
+    let factor = 10f64.powi(decimals as i32);
+    (value * factor).round() / factor
+}
+
+/// Clamps `value` to `min..=max` and rounds it to the nearest multiple of `step` above `min`.
+pub fn snap(value: f64, min: f64, max: f64, step: f64) -> f64 {
+    if max <= min {
+        return min;
+    }
+    let value = value.clamp(min, max);
+    if step <= 0.0 {
+        return value;
+    }
+    let snapped = min + ((value - min) / step).round() * step;
+    round_to(snapped, step_decimals(step).max(step_decimals(min))).min(max)
+}
+
+/// Formats `value` with as many decimals as `step` has.
+pub fn format_value(value: f64, step: f64) -> String {
+    format!("{:.*}", step_decimals(step), value)
+}
+
+/// Tick positions from `min` to `max` (inclusive) every `every` units.
+pub(crate) fn tick_values(min: f64, max: f64, every: f64) -> Vec<f64> {
+    if every <= 0.0 || max < min {
+        return Vec::new();
+    }
+    let decimals = step_decimals(every).max(step_decimals(min));
+    let count = ((max - min) / every + 1e-9).floor() as usize;
+    (0..=count)
+        .map(|index| round_to(min + index as f64 * every, decimals))
+        .collect()
+}
+
+/// Position of `value` along the track, in percent.
+pub(crate) fn percent(value: f64, min: f64, max: f64) -> f64 {
+    if max <= min {
+        return 0.0;
+    }
+    ((value - min) / (max - min) * 100.0).clamp(0.0, 100.0)
+}
+
+/// The new `(low, high)` pair after one thumb moved; the moved thumb stops at the other one.
+pub(crate) fn move_thumb(current: (f64, f64), moved_high: bool, value: f64) -> (f64, f64) {
+    let (low, high) = current;
+    if moved_high {
+        (low, value.max(low))
+    } else {
+        (value.min(high), high)
+    }
+}
+
+fn slider_class(
+    color: Option<SliderColor>,
+    size: Option<Size>,
+    circle: bool,
+    fullwidth: bool,
+    output: SliderOutput,
+    extra: &str,
+) -> String {
+    let mut parts = vec!["slider"];
+    if let Some(color) = color {
+        parts.push(color.bulma());
+    }
+    if let Some(size) = size
+        && size != Size::Normal
+    {
+        parts.push(size.bulma());
+    }
+    if circle {
+        parts.push("is-circle");
+    }
+    if fullwidth {
+        parts.push("is-fullwidth");
+    }
+    if output == SliderOutput::Output {
+        parts.push("has-output");
+    }
+    if !extra.trim().is_empty() {
+        parts.push(extra.trim());
+    }
+    parts.join(" ")
+}
+
+/// One `<input type="range">` with its optional value output.
+#[component]
+fn SliderThumb(
+    id: String,
+    name: String,
+    value: Signal<f64>,
+    on_change: Callback<f64>,
+    min: Signal<f64>,
+    max: Signal<f64>,
+    step: Signal<f64>,
+    class: Signal<String>,
+    output: SliderOutput,
+    disabled: Signal<bool>,
+    list: Option<String>,
+    label: Option<String>,
+    style: Signal<String>,
+) -> impl IntoView {
+    let text = move || format_value(value.get(), step.get());
+    let on_input = move |ev| {
+        if let Ok(raw) = event_target_value(&ev).parse::<f64>() {
+            on_change.run(snap(
+                raw,
+                min.get_untracked(),
+                max.get_untracked(),
+                step.get_untracked(),
+            ));
+        }
+    };
+
+    let value_view = match output {
+        SliderOutput::Hidden => None,
+        SliderOutput::Output => Some(view! { <output r#for=id.clone()>{text}</output> }.into_any()),
+        SliderOutput::Tooltip => Some(
+            view! {
+                <output
+                    r#for=id.clone()
+                    class="tag is-dark slider-tooltip"
+                    style=move || {
+                        format!(
+                            "position: absolute; bottom: 100%; left: {}%; transform: translateX(-50%)",
+                            percent(value.get(), min.get(), max.get()),
+                        )
+                    }
+                >
+                    {text}
+                </output>
+            }
+            .into_any(),
+        ),
+    };
+
+    view! {
+        <div class="slider-thumb" style=move || style.get()>
+            <input
+                id=id.clone()
+                r#type="range"
+                class=move || class.get()
+                name=name
+                min=move || min.get().to_string()
+                max=move || max.get().to_string()
+                step=move || step.get().to_string()
+                value=move || value.get().to_string()
+                prop:value=move || value.get().to_string()
+                list=list
+                aria-label=label
+                aria-valuetext=text
+                disabled=move || disabled.get()
+                on:input=on_input
+            />
+            {value_view}
+        </div>
+    }
+}
+
+/// Tick marks: a `<datalist>` for native marks and labels positioned under the track.
+fn ticks_view(list_id: String, min: f64, max: f64, every: f64, step: f64) -> impl IntoView {
+    let values = tick_values(min, max, every);
+    let options = values
+        .iter()
+        .map(|tick| view! { <option value=tick.to_string()></option> })
+        .collect_view();
+    let labels = values
+        .into_iter()
+        .map(|tick| {
+            view! {
+                <span
+                    class="slider-tick"
+                    style=format!(
+                        "position: absolute; left: {}%; transform: translateX(-50%)",
+                        percent(tick, min, max),
+                    )
+                >
+                    {format_value(tick, step)}
+                </span>
+            }
+        })
+        .collect_view();
+    view! {
+        <datalist id=list_id>{options}</datalist>
+        <div
+            class="slider-ticks is-size-7 has-text-grey"
+            style="position: relative; height: 1.5em"
+            aria-hidden="true"
+        >
+            {labels}
+        </div>
+    }
+}
+
+/// A slider for one `f64` value.
+///
+/// Controlled component: `value` is the current value and `update` receives the new value
+/// (snapped to `step` and clamped to `min..=max`) while the thumb moves.
+#[component]
+pub fn Slider(
+    /// The `name` attribute for this form element.
+    #[prop(into)]
+    name: String,
+
+    /// The controlled value.
+    #[prop(into)]
+    value: Signal<f64>,
+
+    /// Called with the new value while the user drags or uses the arrow keys.
+    update: Callback<f64>,
+
+    /// DOM id of the input. Defaults to `{name}-slider`.
+    #[prop(optional, into)]
+    id: Option<String>,
+
+    /// Lowest value. Defaults to 0.
+    #[prop(default = 0.0.into(), into)]
+    min: Signal<f64>,
+
+    /// Highest value. Defaults to 100.
+    #[prop(default = 100.0.into(), into)]
+    max: Signal<f64>,
+
+    /// Granularity of the value. Defaults to 1.
+    #[prop(default = 1.0.into(), into)]
+    step: Signal<f64>,
+
+    /// How to show the current value.
+    #[prop(optional)]
+    output: SliderOutput,
+
+    /// Show a tick mark with a label every `ticks` units.
+    #[prop(optional)]
+    ticks: Option<f64>,
+
+    /// Optional color of the slider.
+    #[prop(optional)]
+    color: Option<SliderColor>,
+
+    /// Optional size of the slider.
+    #[prop(optional)]
+    size: Option<Size>,
+
+    /// Render a round thumb.
+    #[prop(optional)]
+    circle: bool,
+
+    /// Stretch the slider to the width of its container.
+    #[prop(optional)]
+    fullwidth: bool,
+
+    /// Accessible name of the slider (`aria-label`), when there is no visible `<label for>`.
+    #[prop(optional, into)]
+    label: Option<String>,
+
+    /// Additional CSS classes to append to the input's "slider" classes.
+    #[prop(optional, into)]
+    classes: Signal<String>,
+
+    /// Disable this component.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Optional test attribute (renders as data-* attribute) on the wrapper <div>.
+    ///
+    /// When provided as a &str or String, this becomes `data-testid="value"`.
+    /// You can also pass a full `TestAttr` to override the attribute key.
+    #[prop(optional, into)]
+    test_attr: Option<TestAttr>,
+) -> impl IntoView {
+    let id = id.unwrap_or_else(|| format!("{}-slider", name));
+    let list_id = format!("{}-ticks", id);
+    let class = Signal::derive(move || {
+        slider_class(color, size, circle, fullwidth, output, &classes.get())
+    });
+
+    let has_ticks = ticks.is_some();
+    let ticks = ticks.map(|every| {
+        let list_id = list_id.clone();
+        move || ticks_view(list_id.clone(), min.get(), max.get(), every, step.get())
+    });
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    view! {
+        <div class="slider-field" attr:data-testid=data_testid attr:data-cy=data_cy>
+            <SliderThumb
+                id=id
+                name=name
+                value=value
+                on_change=update
+                min=min
+                max=max
+                step=step
+                class=class
+                output=output
+                disabled=disabled
+                list=has_ticks.then_some(list_id)
+                label=label
+                style=Signal::stored("position: relative".to_string())
+            />
+            {ticks}
+        </div>
+    }
+}
+
+/// A slider for a `(low, high)` pair of `f64` values, e.g. a min–max filter.
+///
+/// Controlled component: `value` is the current pair and `update` receives the new pair. The
+/// thumbs cannot cross, so `low <= high` always holds for values coming from the slider.
+#[component]
+pub fn RangeSlider(
+    /// The `name` attribute shared by both inputs.
+    #[prop(into)]
+    name: String,
+
+    /// The controlled `(low, high)` pair.
+    #[prop(into)]
+    value: Signal<(f64, f64)>,
+
+    /// Called with the new pair while either thumb moves.
+    update: Callback<(f64, f64)>,
+
+    /// Prefix of the input ids (`{id}-min` / `{id}-max`). Defaults to `{name}-slider`.
+    #[prop(optional, into)]
+    id: Option<String>,
+
+    /// Lowest value. Defaults to 0.
+    #[prop(default = 0.0.into(), into)]
+    min: Signal<f64>,
+
+    /// Highest value. Defaults to 100.
+    #[prop(default = 100.0.into(), into)]
+    max: Signal<f64>,
+
+    /// Granularity of the values. Defaults to 1.
+    #[prop(default = 1.0.into(), into)]
+    step: Signal<f64>,
+
+    /// How to show the current values. `Output` shows one `<output>` with "low – high" after
+    /// the track; `Tooltip` shows a tag above each thumb.
+    #[prop(optional)]
+    output: SliderOutput,
+
+    /// Show a tick mark with a label every `ticks` units.
+    #[prop(optional)]
+    ticks: Option<f64>,
+
+    /// Optional color of the slider.
+    #[prop(optional)]
+    color: Option<SliderColor>,
+
+    /// Optional size of the slider.
+    #[prop(optional)]
+    size: Option<Size>,
+
+    /// Render round thumbs.
+    #[prop(optional)]
+    circle: bool,
+
+    /// Stretch the slider to the width of its container.
+    #[prop(optional)]
+    fullwidth: bool,
+
+    /// Accessible names of the two thumbs. Defaults to ("Minimum", "Maximum").
+    #[prop(optional)]
+    labels: Option<(String, String)>,
+
+    /// Additional CSS classes to append to the inputs' "slider" classes.
+    #[prop(optional, into)]
+    classes: Signal<String>,
+
+    /// Disable this component.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Optional test attribute (renders as data-* attribute) on the wrapper <div>.
+    ///
+    /// When provided as a &str or String, this becomes `data-testid="value"`.
+    /// You can also pass a full `TestAttr` to override the attribute key.
+    #[prop(optional, into)]
+    test_attr: Option<TestAttr>,
+) -> impl IntoView {
+    let id = id.unwrap_or_else(|| format!("{}-slider", name));
+    let list_id = format!("{}-ticks", id);
+    let (low_label, high_label) =
+        labels.unwrap_or_else(|| ("Minimum".to_string(), "Maximum".to_string()));
+    // The thumbs share one track, so the `<output>` box is rendered once for both of them.
+    let thumb_output = match output {
+        SliderOutput::Tooltip => SliderOutput::Tooltip,
+        _ => SliderOutput::Hidden,
+    };
+    let class = Signal::derive(move || {
+        slider_class(color, size, circle, fullwidth, thumb_output, &classes.get())
+    });
+
+    let low = Signal::derive(move || value.get().0);
+    let high = Signal::derive(move || value.get().1);
+    let move_low =
+        Callback::new(move |v: f64| update.run(move_thumb(value.get_untracked(), false, v)));
+    let move_high =
+        Callback::new(move |v: f64| update.run(move_thumb(value.get_untracked(), true, v)));
+
+    let fill_class = format!(
+        "range-slider-fill {}",
+        color.map_or("has-background-grey", SliderColor::background)
+    );
+    let fill_style = move || {
+        let (low, high) = value.get();
+        let (min, max) = (min.get(), max.get());
+        let left = percent(low, min, max);
+        format!(
+            "left: {}%; width: {}%",
+            left,
+            percent(high, min, max) - left
+        )
+    };
+    // Raise the low thumb once it is in the upper half, so that when both thumbs meet at the
+    // top it can still be dragged back down (and the high thumb at the bottom can be dragged up).
+    let low_style = Signal::derive(move || {
+        let (low, _) = value.get();
+        let raised = low > (min.get() + max.get()) / 2.0;
+        format!("z-index: {}", if raised { 2 } else { 1 })
+    });
+    let combined = (output == SliderOutput::Output).then(|| {
+        let for_ids = format!("{id}-min {id}-max");
+        view! {
+            <output r#for=for_ids class="range-slider-output">
+                {move || {
+                    let (low, high) = value.get();
+                    let step = step.get();
+                    format!("{} – {}", format_value(low, step), format_value(high, step))
+                }}
+            </output>
+        }
+    });
+
+    let has_ticks = ticks.is_some();
+    let ticks = ticks.map(|every| {
+        let list_id = list_id.clone();
+        move || ticks_view(list_id.clone(), min.get(), max.get(), every, step.get())
+    });
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    view! {
+        <div
+            class="slider-field is-range"
+            role="group"
+            attr:data-testid=data_testid
+            attr:data-cy=data_cy
+        >
+            <style>{RANGE_SLIDER_STYLE}</style>
+            <div class="range-slider-track">
+                <div class=fill_class style=fill_style aria-hidden="true"></div>
+                <SliderThumb
+                id=format!("{}-min", id)
+                name=name.clone()
+                value=low
+                on_change=move_low
+                min=min
+                max=max
+                step=step
+                class=class
+                output=thumb_output
+                disabled=disabled
+                list=has_ticks.then(|| list_id.clone())
+                label=Some(low_label)
+                style=low_style
+            />
+            <SliderThumb
+                id=format!("{}-max", id)
+                name=name
+                value=high
+                on_change=move_high
+                min=min
+                max=max
+                step=step
+                class=class
+                output=thumb_output
+                disabled=disabled
+                list=has_ticks.then_some(list_id)
+                label=Some(high_label)
+                style=Signal::stored("z-index: 1".to_string())
+            />
+            </div>
+            {combined}
+            {ticks}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::prelude::RenderHtml;
+
+    #[test]
+    fn snap_clamps_and_rounds_to_step() {
+        assert_eq!(snap(47.0, 0.0, 100.0, 5.0), 45.0);
+        assert_eq!(snap(48.0, 0.0, 100.0, 5.0), 50.0);
+        assert_eq!(snap(-3.0, 0.0, 100.0, 5.0), 0.0);
+        assert_eq!(snap(0.30000000000000004, 0.0, 1.0, 0.1), 0.3);
+        assert_eq!(snap(9.9, 1.0, 10.0, 4.0), 9.0);
+        assert_eq!(snap(7.0, 5.0, 5.0, 1.0), 5.0);
+    }
+
+    #[test]
+    fn format_value_uses_step_decimals() {
+        assert_eq!(format_value(3.0, 1.0), "3");
+        assert_eq!(format_value(0.5, 0.25), "0.50");
+        assert_eq!(format_value(2.0, 0.1), "2.0");
+    }
+
+    #[test]
+    fn tick_values_cover_the_range() {
+        assert_eq!(
+            tick_values(0.0, 100.0, 25.0),
+            vec![0.0, 25.0, 50.0, 75.0, 100.0]
+        );
+        assert_eq!(tick_values(0.0, 1.0, 0.3), vec![0.0, 0.3, 0.6, 0.9]);
+        assert!(tick_values(0.0, 10.0, 0.0).is_empty());
+    }
+
+    #[test]
+    fn thumbs_do_not_cross() {
+        assert_eq!(move_thumb((20.0, 60.0), false, 80.0), (60.0, 60.0));
+        assert_eq!(move_thumb((20.0, 60.0), true, 10.0), (20.0, 20.0));
+        assert_eq!(move_thumb((20.0, 60.0), true, 70.0), (20.0, 70.0));
+    }
+
+    #[test]
+    fn slider_renders_range_input_with_bounds_and_output() {
+        let html = view! {
+            <Slider
+                name="volume"
+                value=30.0
+                update=Callback::new(|_: f64| {})
+                min=0.0
+                max=50.0
+                step=10.0
+                output=SliderOutput::Output
+                color=SliderColor::Primary
+                size=Size::Small
+                circle=true
+                test_attr="volume"
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"id="volume-slider""#) && html.contains(r#"type="range""#),
+            "expected a range input; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"class="slider is-primary is-small is-circle has-output""#),
+            "expected slider classes; got: {}",
+            html
+        );
+        for attr in [r#"min="0""#, r#"max="50""#, r#"step="10""#, r#"value="30""#] {
+            assert!(html.contains(attr), "expected {}; got: {}", attr, html);
+        }
+        assert!(
+            html.contains(r#"<output for="volume-slider">30</output>"#),
+            "expected value output; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"data-testid="volume""#),
+            "expected test id; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn slider_renders_ticks_and_tooltip() {
+        let html = view! {
+            <Slider
+                name="t"
+                value=50.0
+                update=Callback::new(|_: f64| {})
+                ticks=50.0
+                output=SliderOutput::Tooltip
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"list="t-slider-ticks""#)
+                && html.contains(r#"<datalist id="t-slider-ticks">"#),
+            "expected a datalist for tick marks; got: {}",
+            html
+        );
+        assert_eq!(
+            html.matches(r#"class="slider-tick""#).count(),
+            3,
+            "expected tick labels at 0, 50 and 100; got: {}",
+            html
+        );
+        assert!(
+            html.contains("slider-tooltip") && html.contains("left: 50%"),
+            "expected a tooltip over the thumb; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn range_slider_renders_two_labelled_thumbs() {
+        let html = view! {
+            <RangeSlider
+                name="price"
+                value=(10.0, 40.0)
+                update=Callback::new(|_: (f64, f64)| {})
+                output=SliderOutput::Output
+            />
+        }
+        .to_html();
+
+        assert!(
+            html.contains(r#"id="price-slider-min""#) && html.contains(r#"id="price-slider-max""#),
+            "expected two thumbs; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"aria-label="Minimum""#) && html.contains(r#"aria-label="Maximum""#),
+            "expected labelled thumbs; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"value="10""#) && html.contains(r#"value="40""#),
+            "expected both values; got: {}",
+            html
+        );
+        assert_eq!(
+            html.matches(r#"class="range-slider-track""#).count(),
+            1,
+            "expected both thumbs on one track; got: {}",
+            html
+        );
+        assert!(
+            html.contains(
+                r#"class="range-slider-fill has-background-grey" style="left: 10%; width: 30%;""#
+            ),
+            "expected the filled segment between the thumbs; got: {}",
+            html
+        );
+        assert!(
+            html.contains(">10 – 40</output>") && html.matches("<output").count() == 1,
+            "expected one output for both values; got: {}",
+            html
+        );
+        assert!(
+            html.contains("pointer-events: auto"),
+            "expected the overlay stylesheet; got: {}",
+            html
+        );
+    }
+}
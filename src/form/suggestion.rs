@@ -0,0 +1,562 @@
+/*!
+Suggestion sources for typeahead components such as `AutoComplete`.
+
+A [`SuggestionSource`] turns the typed text into a list of [`Suggestion`]s asynchronously. Plug in
+your own API client, a [`LocalSource`] (in-memory index) or a [`UrlSource`] (JSON over HTTP):
+
+```ignore
+let source = LocalSource::new(["Rust", "Ruby", "Go"]);
+view! { <AutoComplete id="langs".to_string() source=source on_update=on_add on_remove=on_remove /> }
+
+// Any async closure works as well:
+let api = move |query: String| async move { client.search(&query).await.map_err(|e| e.to_string()) };
+view! { <AutoComplete id="users".to_string() source=api debounce_ms=300 ... /> }
+```
+
+The component debounces input before asking the source, shows a loading indicator while a lookup
+is in flight, cancels lookups that were superseded by newer input, and shows the error message
+when a lookup fails. Cancelling drops the source's future; [`UrlSource`] aborts its HTTP request
+when that happens.
+*/
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
+
+use leptos::prelude::{GetUntracked, RwSignal, Set, StoredValue, UpdateValue};
+
+/// A single suggestion: the `value` is emitted through the callbacks, the `label` is displayed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Suggestion {
+    pub value: String,
+    pub label: String,
+}
+
+impl Suggestion {
+    /// A suggestion whose label is its value.
+    pub fn new(value: impl Into<String>) -> Self {
+        let value = value.into();
+        Self {
+            label: value.clone(),
+            value,
+        }
+    }
+
+    /// A suggestion with a distinct display label.
+    pub fn with_label(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+        }
+    }
+}
+
+impl From<&str> for Suggestion {
+    fn from(value: &str) -> Self {
+        Suggestion::new(value)
+    }
+}
+
+impl From<String> for Suggestion {
+    fn from(value: String) -> Self {
+        Suggestion::new(value)
+    }
+}
+
+/// The future returned by [`SuggestionSource::suggest`].
+///
+/// Not `Send`: browser futures (fetch, timers) are single-threaded.
+pub type SuggestFuture = Pin<Box<dyn Future<Output = Result<Vec<Suggestion>, String>>>>;
+
+/// Produces suggestions for the typed text.
+pub trait SuggestionSource: Send + Sync + 'static {
+    /// Returns the suggestions for `query`, or an error message.
+    ///
+    /// The future is dropped when the lookup is superseded; release pending work on drop.
+    fn suggest(&self, query: &str) -> SuggestFuture;
+}
+
+impl<F, Fut> SuggestionSource for F
+where
+    F: Fn(String) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<Vec<Suggestion>, String>> + 'static,
+{
+    fn suggest(&self, query: &str) -> SuggestFuture {
+        Box::pin(self(query.to_string()))
+    }
+}
+
+/// A shared, type-erased source as accepted by `AutoComplete`'s `source` prop.
+#[derive(Clone)]
+pub struct SuggestionSourceRef(Arc<dyn SuggestionSource>);
+
+impl SuggestionSourceRef {
+    pub fn new<S: SuggestionSource>(source: S) -> Self {
+        Self(Arc::new(source))
+    }
+
+    /// Returns the suggestions for `query`, or an error message.
+    pub fn suggest(&self, query: &str) -> SuggestFuture {
+        self.0.suggest(query)
+    }
+}
+
+impl<S: SuggestionSource> From<S> for SuggestionSourceRef {
+    fn from(source: S) -> Self {
+        SuggestionSourceRef::new(source)
+    }
+}
+
+impl std::fmt::Debug for SuggestionSourceRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SuggestionSourceRef")
+    }
+}
+
+fn same_text(a: &str, b: &str, case_sensitive: bool) -> bool {
+    if case_sensitive {
+        a == b
+    } else {
+        a.to_lowercase() == b.to_lowercase()
+    }
+}
+
+/// True when `selected` already holds `value`.
+pub(crate) fn contains_value(selected: &[Suggestion], value: &str, case_sensitive: bool) -> bool {
+    selected
+        .iter()
+        .any(|item| same_text(&item.value, value, case_sensitive))
+}
+
+/// Items whose label contains `query`, excluding already selected values.
+pub(crate) fn filter_suggestions(
+    items: &[Suggestion],
+    query: &str,
+    case_sensitive: bool,
+    selected: &[Suggestion],
+) -> Vec<Suggestion> {
+    let query = query.trim();
+    let needle = if case_sensitive {
+        query.to_string()
+    } else {
+        query.to_lowercase()
+    };
+    items
+        .iter()
+        .filter(|item| !contains_value(selected, &item.value, case_sensitive))
+        .filter(|item| {
+            if case_sensitive {
+                item.label.contains(&needle)
+            } else {
+                item.label.to_lowercase().contains(&needle)
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+/// An in-memory index filtered by substring match on the label.
+#[derive(Clone, Debug, Default)]
+pub struct LocalSource {
+    items: Vec<Suggestion>,
+    case_sensitive: bool,
+    limit: Option<usize>,
+}
+
+impl LocalSource {
+    pub fn new<I, S>(items: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Suggestion>,
+    {
+        Self {
+            items: items.into_iter().map(Into::into).collect(),
+            case_sensitive: false,
+            limit: None,
+        }
+    }
+
+    /// Match case sensitively (default: insensitive).
+    pub fn with_case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Return at most `limit` suggestions.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+impl SuggestionSource for LocalSource {
+    fn suggest(&self, query: &str) -> SuggestFuture {
+        let mut found = filter_suggestions(&self.items, query, self.case_sensitive, &[]);
+        if let Some(limit) = self.limit {
+            found.truncate(limit);
+        }
+        Box::pin(async move { Ok(found) })
+    }
+}
+
+/// Fetches `url + query` (URI-encoded) and reads a JSON array of strings or objects.
+///
+/// The request is aborted (through an `AbortController`) when the lookup is superseded.
+/// For objects, `item_text` names the field to display and `item_value` the field to emit
+/// (falls back to the text).
+#[derive(Clone, Debug, Default)]
+pub struct UrlSource {
+    url: String,
+    auth_header: String,
+    item_text: String,
+    item_value: String,
+}
+
+impl UrlSource {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Send this value as the `Authorization` header.
+    pub fn with_auth_header(mut self, auth_header: impl Into<String>) -> Self {
+        self.auth_header = auth_header.into();
+        self
+    }
+
+    /// Object fields used for the label and the value of each suggestion.
+    pub fn with_item_fields(
+        mut self,
+        item_text: impl Into<String>,
+        item_value: impl Into<String>,
+    ) -> Self {
+        self.item_text = item_text.into();
+        self.item_value = item_value.into();
+        self
+    }
+}
+
+impl SuggestionSource for UrlSource {
+    fn suggest(&self, query: &str) -> SuggestFuture {
+        let source = self.clone();
+        let query = query.to_string();
+        Box::pin(async move { fetch_suggestions(&source, &query).await })
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn fetch_suggestions(source: &UrlSource, query: &str) -> Result<Vec<Suggestion>, String> {
+    use leptos::wasm_bindgen::{JsCast, JsValue};
+    use leptos::web_sys::{AbortController, Request, RequestInit, Response};
+    use wasm_bindgen_futures::JsFuture;
+
+    let js_error = |err: JsValue| format!("{:?}", err);
+
+    // Aborts the request when this future is dropped, i.e. when the lookup is superseded.
+    struct AbortOnDrop(AbortController);
+    impl Drop for AbortOnDrop {
+        fn drop(&mut self) {
+            self.0.abort();
+        }
+    }
+    let abort = AbortOnDrop(AbortController::new().map_err(js_error)?);
+
+    let encoded: String = js_sys::encode_uri_component(query).into();
+    let init = RequestInit::new();
+    init.set_method("GET");
+    init.set_signal(Some(&abort.0.signal()));
+    let request = Request::new_with_str_and_init(&format!("{}{}", source.url, encoded), &init)
+        .map_err(js_error)?;
+    if !source.auth_header.trim().is_empty() {
+        request
+            .headers()
+            .set("Authorization", &source.auth_header)
+            .map_err(js_error)?;
+    }
+
+    let response: Response = JsFuture::from(leptos::prelude::window().fetch_with_request(&request))
+        .await
+        .map_err(js_error)?
+        .unchecked_into();
+    if !response.ok() {
+        return Err(format!("Failed to fetch data ({})", response.status()));
+    }
+    let json = JsFuture::from(response.json().map_err(js_error)?)
+        .await
+        .map_err(js_error)?;
+
+    let field = |item: &JsValue, name: &str| -> Option<String> {
+        let value = js_sys::Reflect::get(item, &JsValue::from_str(name)).ok()?;
+        value
+            .as_string()
+            .or_else(|| value.as_f64().map(|number| number.to_string()))
+    };
+
+    Ok(js_sys::Array::from(&json)
+        .iter()
+        .filter_map(|item| {
+            if let Some(text) = item.as_string() {
+                return Some(Suggestion::new(text));
+            }
+            let label = field(&item, &source.item_text)?;
+            let value = field(&item, &source.item_value).unwrap_or_else(|| label.clone());
+            Some(Suggestion::with_label(value, label))
+        })
+        .collect())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn fetch_suggestions(source: &UrlSource, _query: &str) -> Result<Vec<Suggestion>, String> {
+    Err(format!("fetching {} requires a browser", source.url))
+}
+
+/// Reactive state of the lookups started by a typeahead.
+///
+/// Every lookup gets a generation number; only the latest one may write its results. Starting a
+/// new lookup wakes the previous one, which then drops the source's future.
+#[derive(Clone, Copy)]
+pub(crate) struct Lookup {
+    generation: RwSignal<u64>,
+    /// Waker of the lookup in flight, woken when it is superseded.
+    waker: StoredValue<Option<Waker>>,
+    pub loading: RwSignal<bool>,
+    pub error: RwSignal<Option<String>>,
+    pub results: RwSignal<Vec<Suggestion>>,
+}
+
+impl Lookup {
+    pub fn new() -> Self {
+        Self {
+            generation: RwSignal::new(0),
+            waker: StoredValue::new(None),
+            loading: RwSignal::new(false),
+            error: RwSignal::new(None),
+            results: RwSignal::new(Vec::new()),
+        }
+    }
+
+    /// Invalidates earlier lookups and returns the generation of the next one.
+    pub fn begin(&self) -> u64 {
+        let next = self.generation.get_untracked() + 1;
+        self.generation.set(next);
+        self.waker.update_value(|waker| {
+            if let Some(waker) = waker.take() {
+                waker.wake();
+            }
+        });
+        next
+    }
+
+    pub fn is_current(&self, generation: u64) -> bool {
+        self.generation.get_untracked() == generation
+    }
+
+    /// Cancels pending lookups and clears results, loading and error state.
+    pub fn clear(&self) {
+        self.begin();
+        self.loading.set(false);
+        self.error.set(None);
+        self.results.set(Vec::new());
+    }
+
+    /// Asks `source` for `query`, unless a newer lookup has started in the meantime.
+    pub async fn run(self, source: SuggestionSourceRef, query: String, generation: u64) {
+        if !self.is_current(generation) {
+            return;
+        }
+        self.loading.set(true);
+        let Some(result) = (Cancellable {
+            lookup: self,
+            generation,
+            inner: source.suggest(&query),
+        })
+        .await
+        else {
+            return;
+        };
+        self.loading.set(false);
+        match result {
+            Ok(list) => {
+                self.error.set(None);
+                self.results.set(list);
+            }
+            Err(err) => {
+                self.error.set(Some(err));
+                self.results.set(Vec::new());
+            }
+        }
+    }
+}
+
+/// Resolves to `None`, dropping `inner`, as soon as `generation` is superseded.
+struct Cancellable {
+    lookup: Lookup,
+    generation: u64,
+    inner: SuggestFuture,
+}
+
+impl Future for Cancellable {
+    type Output = Option<Result<Vec<Suggestion>, String>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if !self.lookup.is_current(self.generation) {
+            return Poll::Ready(None);
+        }
+        let waker = cx.waker().clone();
+        self.lookup.waker.update_value(|slot| *slot = Some(waker));
+        self.inner.as_mut().poll(cx).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    fn items(labels: &[&str]) -> Vec<Suggestion> {
+        labels.iter().map(|label| Suggestion::new(*label)).collect()
+    }
+
+    #[test]
+    fn filter_matches_substrings_and_skips_selected() {
+        let all = items(&["Rust", "Ruby", "Go", "TypeScript"]);
+        let selected = items(&["Ruby"]);
+
+        let found = filter_suggestions(&all, "ru", false, &selected);
+        assert_eq!(found, items(&["Rust"]));
+
+        let found = filter_suggestions(&all, "", false, &[]);
+        assert_eq!(found.len(), 4, "empty query shows everything");
+    }
+
+    #[test]
+    fn filter_honours_case_sensitivity() {
+        let all = items(&["Rust", "rusty"]);
+        assert_eq!(filter_suggestions(&all, "rust", false, &[]).len(), 2);
+        assert_eq!(
+            filter_suggestions(&all, "rust", true, &[]),
+            items(&["rusty"])
+        );
+        assert!(contains_value(&items(&["Rust"]), "rust", false));
+        assert!(!contains_value(&items(&["Rust"]), "rust", true));
+    }
+
+    #[test]
+    fn local_source_filters_and_limits() {
+        let source = LocalSource::new(["Rust", "Ruby", "Go"]).with_limit(1);
+        let found = block_on(source.suggest("r"));
+        assert_eq!(found, Ok(items(&["Rust"])));
+    }
+
+    #[test]
+    fn lookup_stores_results_and_errors_from_a_stub_source() {
+        let lookup = Lookup::new();
+        let loading = lookup.loading;
+        // The stub reports whether the loading flag was set while it ran.
+        let stub = SuggestionSourceRef::new(move |query: String| {
+            let was_loading = loading.get_untracked();
+            async move {
+                if query == "fail" {
+                    Err("backend down".to_string())
+                } else {
+                    Ok(vec![Suggestion::new(format!("{}:{}", query, was_loading))])
+                }
+            }
+        });
+
+        let generation = lookup.begin();
+        block_on(lookup.run(stub.clone(), "ru".to_string(), generation));
+        assert_eq!(lookup.results.get_untracked(), items(&["ru:true"]));
+        assert!(!lookup.loading.get_untracked());
+        assert_eq!(lookup.error.get_untracked(), None);
+
+        let generation = lookup.begin();
+        block_on(lookup.run(stub, "fail".to_string(), generation));
+        assert!(lookup.results.get_untracked().is_empty());
+        assert_eq!(
+            lookup.error.get_untracked(),
+            Some("backend down".to_string())
+        );
+    }
+
+    #[test]
+    fn lookup_drops_stale_results() {
+        let lookup = Lookup::new();
+        let stub =
+            SuggestionSourceRef::new(
+                |query: String| async move { Ok(vec![Suggestion::new(query)]) },
+            );
+
+        let stale = lookup.begin();
+        let current = lookup.begin();
+        block_on(lookup.run(stub.clone(), "old".to_string(), stale));
+        assert!(
+            lookup.results.get_untracked().is_empty(),
+            "superseded lookup must not write"
+        );
+
+        block_on(lookup.run(stub.clone(), "new".to_string(), current));
+        assert_eq!(lookup.results.get_untracked(), items(&["new"]));
+
+        lookup.clear();
+        assert!(!lookup.is_current(current));
+        assert!(lookup.results.get_untracked().is_empty());
+    }
+
+    #[test]
+    fn superseded_lookup_drops_the_pending_request() {
+        struct Guard(Arc<AtomicBool>);
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        let dropped = Arc::new(AtomicBool::new(false));
+        let flag = dropped.clone();
+        // Never answers, like a slow backend; the guard records when the future is dropped.
+        let slow = SuggestionSourceRef::new(move |_query: String| {
+            let guard = Guard(flag.clone());
+            async move {
+                std::future::pending::<()>().await;
+                drop(guard);
+                Ok(Vec::new())
+            }
+        });
+
+        let lookup = Lookup::new();
+        let generation = lookup.begin();
+        let mut run = Box::pin(lookup.run(slow, "ru".to_string(), generation));
+        let woken = Arc::new(WakeFlag::default());
+        let waker = Waker::from(woken.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        assert!(run.as_mut().poll(&mut cx).is_pending());
+        assert!(!dropped.load(Ordering::SeqCst));
+
+        lookup.begin();
+        assert!(
+            woken.0.load(Ordering::SeqCst),
+            "a new lookup wakes the old one"
+        );
+        assert!(run.as_mut().poll(&mut cx).is_ready());
+        assert!(
+            dropped.load(Ordering::SeqCst),
+            "the stale request is dropped"
+        );
+        assert!(lookup.results.get_untracked().is_empty());
+    }
+
+    #[derive(Default)]
+    struct WakeFlag(AtomicBool);
+
+    impl std::task::Wake for WakeFlag {
+        fn wake(self: Arc<Self>) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use crate::util::{Size, TestAttr};
+use leptos::callback::Callback;
+use leptos::prelude::*;
+
+/// Available color variants for a [`Switch`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwitchColor {
+    Black,
+    Danger,
+    Dark,
+    Info,
+    Light,
+    Link,
+    Primary,
+    Success,
+    Warning,
+    White,
+}
+
+impl SwitchColor {
+    /// Returns the Bulma CSS class for this `SwitchColor`.
+    fn bulma(self) -> &'static str {
+        match self {
+            SwitchColor::Black => "is-black",
+            SwitchColor::Danger => "is-danger",
+            SwitchColor::Dark => "is-dark",
+            SwitchColor::Info => "is-info",
+            SwitchColor::Light => "is-light",
+            SwitchColor::Link => "is-link",
+            SwitchColor::Primary => "is-primary",
+            SwitchColor::Success => "is-success",
+            SwitchColor::Warning => "is-warning",
+            SwitchColor::White => "is-white",
+        }
+    }
+}
+
+fn switch_class(
+    color: Option<SwitchColor>,
+    size: Option<Size>,
+    rounded: bool,
+    outlined: bool,
+    thin: bool,
+    extra: &str,
+) -> String {
+    let mut parts = vec!["switch"];
+    if let Some(color) = color {
+        parts.push(color.bulma());
+    }
+    if let Some(size) = size
+        && size != Size::Normal
+    {
+        parts.push(size.bulma());
+    }
+    if rounded {
+        parts.push("is-rounded");
+    }
+    if outlined {
+        parts.push("is-outlined");
+    }
+    if thin {
+        parts.push("is-thin");
+    }
+    if !extra.trim().is_empty() {
+        parts.push(extra.trim());
+    }
+    parts.join(" ")
+}
+
+/// An on/off toggle, styled with the `bulma-switch` extension classes.
+///
+/// https://wikiki.github.io/form/switch/
+///
+/// Renders a real `<input type="checkbox" role="switch">` followed by its `<label>`, so it is
+/// announced as a switch, submits like a checkbox and shows its state in SSR output. Without the
+/// extension stylesheet it degrades to a plain checkbox.
+///
+/// Controlled component, with the same contract as `Checkbox`:
+/// - `checked` is the current value (supports static bool or reactive signal).
+/// - `update` is an optional callback invoked with the next value when the user toggles it.
+///
+#[component]
+pub fn Switch(
+    /// The `name` attribute for this form element.
+    #[prop(into)]
+    name: String,
+
+    /// The controlled value of this form element.
+    ///
+    /// Accepts a bool or a reactive signal.
+    #[prop(into)]
+    checked: Signal<bool>,
+
+    /// Optional callback to propagate changes to the parent with the new value.
+    #[prop(optional)]
+    update: Option<Callback<bool>>,
+
+    /// DOM id of the input, referenced by the label. Defaults to `{name}-switch`.
+    #[prop(optional, into)]
+    id: Option<String>,
+
+    /// Optional color of the switch.
+    #[prop(optional)]
+    color: Option<SwitchColor>,
+
+    /// Optional size of the switch.
+    #[prop(optional)]
+    size: Option<Size>,
+
+    /// Render a pill-shaped track and round knob.
+    #[prop(optional)]
+    rounded: bool,
+
+    /// Render only the outline of the track in the switch color.
+    #[prop(optional)]
+    outlined: bool,
+
+    /// Render a thin track with the knob overlapping it.
+    #[prop(optional)]
+    thin: bool,
+
+    /// Additional CSS classes to append to the input's "switch" classes.
+    #[prop(optional, into)]
+    classes: Signal<String>,
+
+    /// Disable this component.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Optional test attribute (renders as data-* attribute) on the wrapper <div>.
+    ///
+    /// When provided as a &str or String, this becomes `data-testid="value"`.
+    /// You can also pass a full `TestAttr` to override the attribute key.
+    #[prop(optional, into)]
+    test_attr: Option<TestAttr>,
+
+    /// Label/content shown next to the switch.
+    #[prop(optional)]
+    children: Option<Children>,
+) -> impl IntoView {
+    let id = id.unwrap_or_else(|| format!("{}-switch", name));
+
+    let class = move || switch_class(color, size, rounded, outlined, thin, &classes.get());
+
+    let (data_testid, data_cy) = match &test_attr {
+        Some(attr) if attr.key == "data-testid" => (Some(attr.value.clone()), None),
+        Some(attr) if attr.key == "data-cy" => (None, Some(attr.value.clone())),
+        _ => (None, None),
+    };
+
+    view! {
+        <div
+            class="field"
+            attr:data-testid=data_testid
+            attr:data-cy=data_cy
+        >
+            <input
+                id=id.clone()
+                type="checkbox"
+                role="switch"
+                class=class
+                name=name
+                // The attribute shows the state in SSR output; the property keeps it in sync later.
+                checked=move || checked.get()
+                prop:checked=move || checked.get()
+                disabled=move || disabled.get()
+                on:change=move |ev| {
+                    if let Some(cb) = &update {
+                        cb.run(event_target_checked(&ev));
+                    }
+                }
+            />
+            <label for=id>{children.map(|children| children())}</label>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::prelude::RenderHtml;
+
+    #[test]
+    fn switch_renders_checkbox_with_switch_role() {
+        let html = view! { <Switch name="wifi" checked=true>"Wi-Fi"</Switch> }.to_html();
+        assert!(
+            html.contains(r#"id="wifi-switch""#)
+                && html.contains(r#"type="checkbox""#)
+                && html.contains(r#"role="switch""#),
+            "expected a checkbox input with role=switch in: {}",
+            html
+        );
+        assert!(
+            html.contains("checked"),
+            "expected checked state in SSR output: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"<label for="wifi-switch">Wi-Fi</label>"#),
+            "expected label bound to the input in: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn switch_unchecked_has_no_checked_attribute() {
+        let html = view! { <Switch name="wifi" checked=false /> }.to_html();
+        assert!(!html.contains("checked"), "expected unchecked in: {}", html);
+    }
+
+    #[test]
+    fn switch_applies_style_modifiers() {
+        let html = view! {
+            <Switch
+                name="s"
+                id="custom"
+                checked=false
+                color=SwitchColor::Success
+                size=Size::Small
+                rounded=true
+                outlined=true
+                thin=true
+                classes="is-rtl"
+                disabled=true
+                test_attr="toggle"
+            />
+        }
+        .to_html();
+        assert!(
+            html.contains(
+                r#"class="switch is-success is-small is-rounded is-outlined is-thin is-rtl""#
+            ),
+            "expected modifier classes in: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"id="custom""#) && html.contains("disabled"),
+            "expected custom id and disabled in: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"data-testid="toggle""#),
+            "expected test id on wrapper in: {}",
+            html
+        );
+    }
+}
@@ -1,13 +1,21 @@
+use std::time::Duration;
+
 use leptos::callback::Callback;
+use leptos::ev::KeyboardEvent;
+use leptos::html;
 use leptos::prelude::Callable;
 use leptos::prelude::OnAttribute;
 use leptos::prelude::event_target_value;
 use leptos::prelude::{
-    ClassAttribute, CustomAttribute, ElementChild, Get, GetUntracked, GlobalAttributes, IntoAny,
-    IntoView, Signal, StyleAttribute, component, view,
+    AriaAttributes, ClassAttribute, CustomAttribute, ElementChild, Get, GetUntracked, GetValue,
+    GlobalAttributes, IntoAny, IntoView, NodeRef, NodeRefAttribute, Set, Signal, StoredValue,
+    StyleAttribute, Track, component, view,
 };
 
 use crate::elements::icon::Icon;
+use crate::form::completion::{CompletionProviderRef, Followed, GhostText, follow_ghost};
+use crate::form::context::use_form_context;
+use crate::form::field::{CharCount, counter_help, use_field_context};
 use crate::util::{Size, TestAttr};
 #[allow(unused_imports)]
 use leptos::prelude::Effect;
@@ -21,12 +29,53 @@ fn size_class(size: Size) -> &'static str {
     }
 }
 
+/// Rows needed to show `text` without scrolling (ignoring soft wraps), within `min..=max`.
+pub(crate) fn autosize_rows(text: &str, min: u32, max: u32) -> u32 {
+    let lines = text.split('\n').count() as u32;
+    lines.clamp(min, max.max(min))
+}
+
+/// Grows or shrinks `textarea` to fit its content (including soft wraps), within `min..=max`.
+fn fit_rows(textarea: &leptos::web_sys::HtmlTextAreaElement, min: u32, max: u32) {
+    let mut rows = min;
+    textarea.set_rows(rows);
+    while rows < max && textarea.scroll_height() > textarea.client_height() {
+        rows += 1;
+        textarea.set_rows(rows);
+    }
+}
+
+/// True when nothing is selected and the caret is after the last character of `text`.
+fn caret_at_end(textarea: &leptos::web_sys::HtmlTextAreaElement, text: &str) -> bool {
+    let end = Some(text.encode_utf16().count() as u32);
+    textarea.selection_start().ok().flatten() == end
+        && textarea.selection_end().ok().flatten() == end
+}
+
+/// Lays the ghost text out exactly under the textarea's own text.
+const MIRROR_STYLE: &str = "position:absolute;top:0;left:0;width:100%;height:100%;\
+    overflow:hidden;white-space:pre-wrap;overflow-wrap:break-word;color:transparent;\
+    pointer-events:none;resize:none";
+
 /// A multiline textarea component following Bulma styles.
 ///
 /// https://bulma.io/documentation/form/textarea/
 ///
 /// Controlled component: the value comes from a parent, changes are propagated via `update`.
 ///
+/// Inside a `Form`, the textarea gets `is-danger`/`is-success` from the validation state of
+/// the field with the same `name`.
+///
+/// With `autosize`, the textarea grows with its content between `min_rows` and `max_rows` and
+/// scrolls beyond that. With `maxlength`, a live `count/max` counter is shown in the enclosing
+/// `Field`'s help area (or under the textarea outside a `Field`); the counter and the textarea
+/// turn `is-danger` when the limit is exceeded.
+///
+/// With `is_genai` and a `completion` provider, the textarea asks the provider for a
+/// continuation when the user pauses typing at the end of the text and shows it as grey ghost
+/// text. Tab accepts it, Escape dismisses it, and typing it out keeps it in sync; `on_accept`
+/// and `on_reject` report the outcome.
+///
 #[component]
 pub fn TextArea(
     /// The `name` attribute for this form element.
@@ -60,6 +109,27 @@ pub fn TextArea(
     #[prop(optional, into)]
     fixed_size: Signal<bool>,
 
+    /// Grow and shrink with the content between `min_rows` and `max_rows`.
+    #[prop(optional)]
+    autosize: bool,
+
+    /// Fewest rows in autosize mode. Defaults to `rows`, or 2.
+    #[prop(optional)]
+    min_rows: Option<u32>,
+
+    /// Most rows in autosize mode before the textarea scrolls. Defaults to 10.
+    #[prop(optional)]
+    max_rows: Option<u32>,
+
+    /// Character limit; shows a live `count/max` counter.
+    #[prop(optional)]
+    maxlength: Option<u32>,
+
+    /// Also set the native `maxlength` attribute so the browser stops input at the limit.
+    /// Otherwise the limit is only signalled by the counter.
+    #[prop(optional)]
+    enforce_maxlength: bool,
+
     /// Display a loading spinner within this component.
     #[prop(optional, into)]
     loading: Signal<bool>,
@@ -80,6 +150,22 @@ pub fn TextArea(
     #[prop(optional, into)]
     is_genai: Signal<bool>,
 
+    /// In GenAI mode, suggests continuations that are shown as ghost text after the caret.
+    #[prop(optional, into)]
+    completion: Option<CompletionProviderRef>,
+
+    /// Wait this long after the last keystroke before asking `completion` (default 300).
+    #[prop(optional)]
+    completion_debounce_ms: Option<u32>,
+
+    /// Called with the suggestion when the user accepts it (Tab, or by typing all of it).
+    #[prop(optional)]
+    on_accept: Option<Callback<String>>,
+
+    /// Called with the suggestion when the user dismisses it (Escape, or by typing something else).
+    #[prop(optional)]
+    on_reject: Option<Callback<String>>,
+
     /// Optional test attribute (renders as data-* attribute) on the root element:
     /// - when `is_genai=true`, on the wrapping <div>
     /// - otherwise, on the <textarea> itself.
@@ -89,6 +175,25 @@ pub fn TextArea(
     #[prop(optional, into)]
     test_attr: Option<TestAttr>,
 ) -> impl IntoView {
+    let form = use_form_context();
+
+    let counter = maxlength.map(|max| {
+        Signal::derive(move || CharCount {
+            count: value.get().chars().count(),
+            max: max as usize,
+        })
+    });
+    // Inside a Field the counter goes to its help area; otherwise it is rendered here.
+    let own_counter = match (counter, use_field_context()) {
+        (Some(counter), Some(field)) => {
+            field.set_counter(Some(counter.get_untracked()));
+            Effect::new(move |_| field.set_counter(Some(counter.get())));
+            None
+        }
+        (counter, _) => counter,
+    };
+    let exceeded = move || counter.is_some_and(|counter| counter.get().exceeded());
+
     let class = {
         let classes = classes.clone();
         let loading = loading.clone();
@@ -110,9 +215,14 @@ pub fn TextArea(
             if r#static.get() {
                 parts.push("is-static".to_string());
             }
-            if fixed_size.get() {
+            if fixed_size.get() || autosize {
                 parts.push("has-fixed-size".to_string());
             }
+            if exceeded() {
+                parts.push("is-danger".to_string());
+            } else if let Some(color) = form.and_then(|form| form.validation_class(&name.get())) {
+                parts.push(color.to_string());
+            }
 
             parts.join(" ")
         }
@@ -130,14 +240,161 @@ pub fn TextArea(
     let placeholder_value = placeholder.get_untracked();
     let is_disabled = disabled.get_untracked();
     let is_readonly = readonly.get_untracked();
-    let rows_value = rows.unwrap_or(0).to_string();
-
     // Snapshot initial value once; we will apply it on mount via DOM API.
     // This avoids using `value=` in the view macro (not supported for <textarea> in Leptos 0.8).
     let initial_value = value.get_untracked();
 
+    let min_rows = min_rows.or(rows).unwrap_or(2);
+    let max_rows = max_rows.unwrap_or(10).max(min_rows);
+    let rows_value = if autosize {
+        autosize_rows(&initial_value, min_rows, max_rows).to_string()
+    } else {
+        rows.unwrap_or(0).to_string()
+    };
+    let maxlength_attr = maxlength
+        .filter(|_| enforce_maxlength)
+        .map(|max| max.to_string());
+
+    let textarea_ref = NodeRef::<html::Textarea>::new();
+    // Refit when the value changes from outside (e.g. the parent clears it).
+    Effect::new(move |_| {
+        value.track();
+        if autosize && let Some(textarea) = textarea_ref.get() {
+            fit_rows(&textarea, min_rows, max_rows);
+        }
+    });
+
+    let has_completion = completion.is_some();
+    let completion = StoredValue::new(completion);
+    let ghost_text = GhostText::new();
+    let completion_debounce =
+        Duration::from_millis(u64::from(completion_debounce_ms.unwrap_or(300)));
+
+    let request_completion = move |text: String| {
+        let Some(provider) = completion.get_value() else {
+            return;
+        };
+        if !is_genai.get_untracked() || text.trim().is_empty() {
+            return;
+        }
+        // Starting a request invalidates pending and in-flight ones.
+        let generation = ghost_text.begin();
+        leptos::prelude::set_timeout(
+            move || {
+                if ghost_text.is_current(generation) {
+                    leptos::task::spawn_local(ghost_text.run(provider, text, generation));
+                }
+            },
+            completion_debounce,
+        );
+    };
+
+    let on_input = move |ev| {
+        let text = event_target_value(&ev);
+        if autosize && let Some(textarea) = textarea_ref.get_untracked() {
+            fit_rows(&textarea, min_rows, max_rows);
+        }
+        let kept = match ghost_text.take().map(|ghost| follow_ghost(ghost, &text)) {
+            Some(Followed::Kept(ghost)) => {
+                ghost_text.ghost.set(Some(ghost));
+                true
+            }
+            Some(Followed::Completed(offered)) => {
+                if let Some(cb) = on_accept {
+                    cb.run(offered);
+                }
+                false
+            }
+            Some(Followed::Diverged(offered)) => {
+                if let Some(cb) = on_reject {
+                    cb.run(offered);
+                }
+                false
+            }
+            None => false,
+        };
+        let at_end = textarea_ref
+            .get_untracked()
+            .is_some_and(|textarea| caret_at_end(&textarea, &text));
+        update.run(text.clone());
+        if !kept && at_end {
+            request_completion(text);
+        }
+    };
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        let key = ev.key();
+        if !matches!(key.as_str(), "Tab" | "Escape") || ev.shift_key() {
+            return;
+        }
+        let Some(textarea) = textarea_ref.get_untracked() else {
+            return;
+        };
+        let shown = ghost_text
+            .ghost
+            .get_untracked()
+            .is_some_and(|ghost| ghost.prefix == textarea.value());
+        let Some(ghost) = ghost_text.take().filter(|_| shown) else {
+            return;
+        };
+        ev.prevent_default();
+        if key == "Tab" {
+            let text = format!("{}{}", ghost.prefix, ghost.text);
+            textarea.set_value(&text);
+            let end = text.encode_utf16().count() as u32;
+            let _ = textarea.set_selection_range(end, end);
+            if autosize {
+                fit_rows(&textarea, min_rows, max_rows);
+            }
+            update.run(text);
+            if let Some(cb) = on_accept {
+                cb.run(ghost.offered);
+            }
+        } else if let Some(cb) = on_reject {
+            cb.run(ghost.offered);
+        }
+    };
+
+    // The ghost text is drawn in a mirror of the content behind the (transparent) textarea.
+    let mirror_ref = NodeRef::<html::Div>::new();
+    let on_scroll = move |_| {
+        if let (Some(textarea), Some(mirror)) =
+            (textarea_ref.get_untracked(), mirror_ref.get_untracked())
+        {
+            mirror.set_scroll_top(textarea.scroll_top());
+        }
+    };
+    let ghost_after_value = move || ghost_text.visible_after(&value.get());
+    let mirror_view = move || {
+        has_completion.then(|| {
+            let class = match size {
+                Some(sz) => format!("textarea {}", size_class(sz)),
+                None => "textarea".to_string(),
+            };
+            view! {
+                <div class=class style=MIRROR_STYLE aria-hidden="true" node_ref=mirror_ref>
+                    <span>{move || value.get()}</span>
+                    <span class="has-text-grey-light">{ghost_after_value}</span>
+                </div>
+            }
+        })
+    };
+    let announcement = move || {
+        has_completion.then(|| {
+            view! {
+                <span class="is-sr-only" aria-live="polite">
+                    {move || {
+                        ghost_after_value()
+                            .map(|ghost| format!("Suggestion: {}. Press Tab to accept.", ghost))
+                    }}
+                </span>
+            }
+        })
+    };
+    let textarea_style = has_completion.then_some("position:relative;background-color:transparent");
+
     // Render an optional "GenAI ribbon" icon overlay if requested.
-    move || {
+    let textarea_view = move || {
         // Clone the attribute values into locals each render so inner closures can move/clone them
         let data_testid = data_testid_opt.clone();
         let data_cy = data_cy_opt.clone();
@@ -153,19 +410,24 @@ pub fn TextArea(
                     <Icon size=Size::Small classes="is-pulled-right ribbon">
                         <i class="fa-brands fa-openai"></i>
                     </Icon>
+                    {mirror_view}
                     <textarea
                         name=name_value.clone()
                         class=move || class()
+                        style=textarea_style
                         placeholder=placeholder_value.clone()
                         disabled=is_disabled
                         readonly=is_readonly
                         rows=rows_value.clone()
-                        on:input=move |ev| {
-                            update.run(event_target_value(&ev));
-                        }
+                        maxlength=maxlength_attr.clone()
+                        node_ref=textarea_ref
+                        on:input=on_input
+                        on:keydown=on_keydown
+                        on:scroll=on_scroll
                     >
                         {initial_value.clone()}
                     </textarea>
+                    {announcement}
                 </div>
             }
             .into_any()
@@ -178,17 +440,24 @@ pub fn TextArea(
                     disabled=is_disabled
                     readonly=is_readonly
                     rows=rows_value.clone()
+                    maxlength=maxlength_attr.clone()
+                    node_ref=textarea_ref
                     attr:data-testid=move || data_testid.clone()
                     attr:data-cy=move || data_cy.clone()
-                    on:input=move |ev| {
-                        update.run(event_target_value(&ev));
-                    }
+                    on:input=on_input
                 >
                     {initial_value.clone()}
                 </textarea>
             }
             .into_any()
         }
+    };
+
+    let counter_view = move || own_counter.map(|counter| counter_help(counter.get()));
+
+    view! {
+        {textarea_view}
+        {counter_view}
     }
 }
 
@@ -283,6 +552,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn autosize_rows_clamps_line_count() {
+        assert_eq!(autosize_rows("", 2, 6), 2);
+        assert_eq!(autosize_rows("a\nb\nc", 2, 6), 3);
+        assert_eq!(autosize_rows(&"x\n".repeat(20), 2, 6), 6);
+    }
+
+    #[test]
+    fn textarea_autosize_sets_rows_from_content() {
+        let html = view! {
+            <TextArea
+                name="n"
+                value="one\ntwo\nthree\nfour"
+                autosize=true
+                min_rows=2
+                max_rows=3
+                update=noop()
+            />
+        }
+        .to_html();
+        assert!(
+            html.contains(r#"rows="3""#) && html.contains("has-fixed-size"),
+            "expected rows capped at max_rows and fixed size; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn textarea_counter_outside_field() {
+        let html = view! {
+            <TextArea name="bio" value="hello" maxlength=10 update=noop() />
+        }
+        .to_html();
+        assert!(
+            html.contains(r#"<p class="help has-text-right">5/10</p>"#),
+            "expected counter under the textarea; got: {}",
+            html
+        );
+        assert!(
+            !html.contains("maxlength"),
+            "expected no native maxlength unless enforced; got: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn textarea_counter_in_field_help_turns_danger_when_exceeded() {
+        use crate::form::field::Field;
+
+        // Context lookups need a reactive owner.
+        let owner = leptos::prelude::Owner::new();
+        let html = owner.with(|| {
+            view! {
+                <Field label="Bio" help="Tell us about yourself">
+                    <TextArea
+                        name="bio"
+                        value="hello world"
+                        maxlength=5
+                        enforce_maxlength=true
+                        update=noop()
+                    />
+                </Field>
+            }
+            .to_html()
+        });
+        let help = html.find("Tell us about yourself").expect("help text");
+        let counter = html
+            .find(r#"<p class="help has-text-right is-danger">11/5</p>"#)
+            .expect("danger counter in the field help area");
+        assert!(
+            help < counter && html.matches("11/5").count() == 1,
+            "expected one counter after the help text; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"class="textarea is-danger""#) && html.contains(r#"maxlength="5""#),
+            "expected danger textarea with native maxlength; got: {}",
+            html
+        );
+    }
+
     #[test]
     fn textarea_genai_ribbon() {
         let html = view! { <TextArea name="g" value="" is_genai=true update=noop() /> }.to_html();
@@ -292,6 +642,50 @@ mod tests {
             html
         );
     }
+
+    #[test]
+    fn textarea_genai_with_completion_renders_ghost_mirror() {
+        use crate::form::completion::PhraseCompletions;
+
+        let html = view! {
+            <TextArea
+                name="reply"
+                value="Kind re"
+                is_genai=true
+                completion=PhraseCompletions::new(["Kind regards"])
+                update=noop()
+            />
+        }
+        .to_html();
+        assert!(
+            html.contains(r#"aria-hidden="true""#)
+                && html.contains("<span>Kind re</span>")
+                && html.contains(r#"<span class="has-text-grey-light"><!></span>"#),
+            "expected an empty ghost mirror behind the textarea; got: {}",
+            html
+        );
+        assert!(
+            html.contains(r#"style="position:relative;background-color:transparent;""#)
+                && html.contains(r#"<span aria-live="polite" class="is-sr-only">"#),
+            "expected a transparent textarea and a live region; got: {}",
+            html
+        );
+
+        let html = view! {
+            <TextArea
+                name="reply"
+                value=""
+                completion=PhraseCompletions::new(["Kind regards"])
+                update=noop()
+            />
+        }
+        .to_html();
+        assert!(
+            !html.contains("aria-hidden") && !html.contains("transparent"),
+            "expected no ghost text outside GenAI mode; got: {}",
+            html
+        );
+    }
 }
 
 #[cfg(all(test, target_arch = "wasm32"))]
@@ -0,0 +1,258 @@
+/*!
+Declarative validation for LBC forms.
+
+A [`Validator`] checks a field's string value and returns an error message when it is
+invalid. Validators are attached to a `Field` together with a [`ValidateOn`] trigger:
+
+```ignore
+<Field label="Email" name="email" validate_on=ValidateOn::Blur
+    validators=vec![required(), email().with_message("Please enter a valid email")]>
+    <Control><Input name="email" value=email update=set_email /></Control>
+</Field>
+```
+
+Errors are stored in the surrounding `FormContext`, shown as the `Field` help text, and the
+bound `Input`/`TextArea`/`Select` receive `is-danger` (or `is-success` once valid).
+
+All built-ins except [`required`] accept empty values, so optional fields are only checked
+when the user has entered something.
+*/
+
+use std::sync::Arc;
+
+use regex::Regex;
+
+/// When a field's validators run.
+///
+/// Every trigger also validates on submit. Once a field shows an error it is re-validated on
+/// each input, so the message clears as soon as the value is fixed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidateOn {
+    /// Validate on every input event.
+    Input,
+    /// Validate when focus leaves the field.
+    #[default]
+    Blur,
+    /// Validate only when the form is submitted.
+    Submit,
+}
+
+/// Checks a single field value.
+pub trait Validator: Send + Sync + 'static {
+    /// Returns `Err(message)` when the value is invalid.
+    fn validate(&self, value: &str) -> Result<(), String>;
+}
+
+impl<F> Validator for F
+where
+    F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+{
+    fn validate(&self, value: &str) -> Result<(), String> {
+        self(value)
+    }
+}
+
+/// A shared, type-erased validator as accepted by `Field`'s `validators` prop.
+#[derive(Clone)]
+pub struct ValidatorRef(Arc<dyn Validator>);
+
+impl ValidatorRef {
+    pub fn new<V: Validator>(validator: V) -> Self {
+        Self(Arc::new(validator))
+    }
+
+    /// Replace the error message produced by this validator.
+    pub fn with_message(self, message: impl Into<String>) -> Self {
+        let message = message.into();
+        let inner = self.0;
+        Self::new(move |value: &str| inner.validate(value).map_err(|_| message.clone()))
+    }
+}
+
+impl Validator for ValidatorRef {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        self.0.validate(value)
+    }
+}
+
+impl std::fmt::Debug for ValidatorRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ValidatorRef")
+    }
+}
+
+/// Runs validators in order and returns the first error.
+pub fn run_validators(validators: &[ValidatorRef], value: &str) -> Result<(), String> {
+    validators
+        .iter()
+        .try_for_each(|validator| validator.validate(value))
+}
+
+/// The value must contain non-whitespace characters.
+pub fn required() -> ValidatorRef {
+    ValidatorRef::new(|value: &str| {
+        if value.trim().is_empty() {
+            Err("This field is required.".to_string())
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// The value must have at least `min` characters.
+pub fn min_length(min: usize) -> ValidatorRef {
+    ValidatorRef::new(move |value: &str| {
+        let len = value.chars().count();
+        if len == 0 || len >= min {
+            Ok(())
+        } else {
+            Err(format!("Must be at least {} characters.", min))
+        }
+    })
+}
+
+/// The value must have at most `max` characters.
+pub fn max_length(max: usize) -> ValidatorRef {
+    ValidatorRef::new(move |value: &str| {
+        if value.chars().count() <= max {
+            Ok(())
+        } else {
+            Err(format!("Must be at most {} characters.", max))
+        }
+    })
+}
+
+/// The value must look like an email address (`local@domain.tld`, no whitespace).
+pub fn email() -> ValidatorRef {
+    ValidatorRef::new(|value: &str| {
+        let value = value.trim();
+        if value.is_empty() {
+            return Ok(());
+        }
+        let valid = match value.split_once('@') {
+            Some((local, domain)) => {
+                !local.is_empty()
+                    && !domain.contains('@')
+                    && !value.chars().any(char::is_whitespace)
+                    && domain
+                        .split_once('.')
+                        .is_some_and(|(host, tld)| !host.is_empty() && !tld.is_empty())
+                    && !domain.ends_with('.')
+            }
+            None => false,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err("Please enter a valid email address.".to_string())
+        }
+    })
+}
+
+/// The value must match the regular expression.
+///
+/// The pattern is used as given; anchor it with `^...$` to match the whole value.
+pub fn pattern(regex: Regex) -> ValidatorRef {
+    ValidatorRef::new(move |value: &str| {
+        if value.is_empty() || regex.is_match(value) {
+            Ok(())
+        } else {
+            Err("Please match the requested format.".to_string())
+        }
+    })
+}
+
+/// The value must parse as a number within the optional bounds (inclusive).
+pub fn range(min: Option<f64>, max: Option<f64>) -> ValidatorRef {
+    ValidatorRef::new(move |value: &str| {
+        let value = value.trim();
+        if value.is_empty() {
+            return Ok(());
+        }
+        let Ok(number) = value.parse::<f64>() else {
+            return Err("Please enter a number.".to_string());
+        };
+        match (min, max) {
+            (Some(min), _) if number < min => Err(format!("Must be at least {}.", min)),
+            (_, Some(max)) if number > max => Err(format!("Must be at most {}.", max)),
+            _ => Ok(()),
+        }
+    })
+}
+
+/// A validator from a closure.
+pub fn custom<F>(check: F) -> ValidatorRef
+where
+    F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+{
+    ValidatorRef::new(check)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn required_rejects_blank_values() {
+        assert!(required().validate("").is_err());
+        assert!(required().validate("   ").is_err());
+        assert!(required().validate("x").is_ok());
+    }
+
+    #[test]
+    fn length_validators_count_characters() {
+        assert!(min_length(3).validate("ab").is_err());
+        assert!(min_length(3).validate("äbc").is_ok());
+        assert!(min_length(3).validate("").is_ok());
+        assert!(max_length(2).validate("abc").is_err());
+        assert!(max_length(2).validate("ab").is_ok());
+    }
+
+    #[test]
+    fn email_accepts_common_addresses() {
+        assert!(email().validate("ada@example.com").is_ok());
+        assert!(email().validate("").is_ok());
+        assert!(email().validate("ada@example").is_err());
+        assert!(email().validate("ada example@x.io").is_err());
+        assert!(email().validate("@example.com").is_err());
+        assert!(email().validate("a@b@c.io").is_err());
+    }
+
+    #[test]
+    fn pattern_and_range() {
+        let zip = pattern(Regex::new(r"^\d{5}$").unwrap());
+        assert!(zip.validate("12345").is_ok());
+        assert!(zip.validate("1234a").is_err());
+
+        let age = range(Some(18.0), Some(99.0));
+        assert!(age.validate("42").is_ok());
+        assert_eq!(age.validate("17"), Err("Must be at least 18.".to_string()));
+        assert_eq!(
+            age.validate("abc"),
+            Err("Please enter a number.".to_string())
+        );
+    }
+
+    #[test]
+    fn custom_messages_and_chaining() {
+        let validators = vec![
+            required().with_message("Name please"),
+            custom(|value: &str| {
+                if value.starts_with(char::is_uppercase) {
+                    Ok(())
+                } else {
+                    Err("Capitalize it".to_string())
+                }
+            }),
+        ];
+        assert_eq!(
+            run_validators(&validators, ""),
+            Err("Name please".to_string())
+        );
+        assert_eq!(
+            run_validators(&validators, "ada"),
+            Err("Capitalize it".to_string())
+        );
+        assert_eq!(run_validators(&validators, "Ada"), Ok(()));
+    }
+}
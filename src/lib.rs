@@ -9,6 +9,9 @@
 //! See each module and component for specific usage details and links
 //! to the corresponding Bulma documentation.
 
+// Lets code generated by `lbc_derive` refer to `::lbc` inside this crate as well.
+extern crate self as lbc;
+
 pub mod components;
 pub mod elements;
 pub mod form;
@@ -58,11 +61,12 @@ pub mod prelude {
     //! Import this to bring the most frequently used components into scope.
     pub use crate::components::{
         AccordionItem, Accordions, Alignment, Breadcrumb, BreadcrumbSeparator, BreadcrumbSize,
-        Calendar, Card, CardContent, CardFooter, CardHeader, CardImage, Dropdown, Menu, MenuLabel,
-        MenuList, Message, MessageBody, MessageHeader, Modal, ModalCard, ModalControllerContext,
-        ModalControllerProvider, Navbar, NavbarDivider, NavbarDropdown, NavbarFixed, NavbarItem,
-        NavbarMenuContext, Pagination, PaginationEllipsis, PaginationItem, PaginationItemType,
-        Panel, PanelBlock, PanelTabs, Tabs,
+        Calendar, Card, CardContent, CardFooter, CardHeader, CardImage, DatePicker, DateRange,
+        DateRangePicker, Dropdown, Menu, MenuLabel, MenuList, Message, MessageBody, MessageHeader,
+        Modal, ModalCard, ModalControllerContext, ModalControllerProvider, Navbar, NavbarDivider,
+        NavbarDropdown, NavbarFixed, NavbarItem, NavbarMenuContext, Pagination, PaginationEllipsis,
+        PaginationItem, PaginationItemType, Panel, PanelBlock, PanelTabs, RangePreset, Steps, Tabs,
+        Wizard, WizardStep,
     };
     pub use crate::elements::block::Block;
     pub use crate::elements::r#box::Box;